@@ -0,0 +1,160 @@
+//! Minimal ANSI escape handling for captured log lines.
+//! Understands the common SGR color/bold codes and strips everything else.
+
+/// Text attributes carried by an SGR sequence.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct AnsiStyle {
+    /// Palette index 0-7 for normal colors, 8-15 for bright colors.
+    pub fg: Option<u8>,
+    pub bold: bool,
+}
+
+/// Split a line into styled text segments, dropping every escape sequence.
+pub(crate) fn ansi_to_segments(line: &str) -> Vec<(AnsiStyle, String)> {
+    let mut segments: Vec<(AnsiStyle, String)> = Vec::new();
+    let mut style = AnsiStyle::default();
+    let mut current = String::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\u{1b}' {
+            current.push(ch);
+            continue;
+        }
+
+        match chars.peek().copied() {
+            Some('[') => {
+                chars.next();
+                let mut params = String::new();
+                let mut final_byte = None;
+                for next in chars.by_ref() {
+                    if ('@'..='~').contains(&next) {
+                        final_byte = Some(next);
+                        break;
+                    }
+                    params.push(next);
+                }
+                if final_byte == Some('m') {
+                    let next_style = apply_sgr(style, &params);
+                    if next_style != style {
+                        if !current.is_empty() {
+                            segments.push((style, std::mem::take(&mut current)));
+                        }
+                        style = next_style;
+                    }
+                }
+            }
+            Some(']') => {
+                chars.next();
+                let mut prev = '\0';
+                for next in chars.by_ref() {
+                    if next == '\u{7}' || (prev == '\u{1b}' && next == '\\') {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !current.is_empty() {
+        segments.push((style, current));
+    }
+
+    segments
+}
+
+/// Remove escape sequences, keeping only the visible text.
+pub(crate) fn strip_ansi(line: &str) -> String {
+    if !line.contains('\u{1b}') {
+        return line.to_string();
+    }
+
+    ansi_to_segments(line)
+        .into_iter()
+        .map(|(_, text)| text)
+        .collect()
+}
+
+fn apply_sgr(mut style: AnsiStyle, params: &str) -> AnsiStyle {
+    let codes: Vec<u16> = params
+        .split(';')
+        .map(|code| code.parse().unwrap_or(0))
+        .collect();
+
+    let mut index = 0;
+    while index < codes.len() {
+        match codes[index] {
+            0 => style = AnsiStyle::default(),
+            1 => style.bold = true,
+            22 => style.bold = false,
+            code @ 30..=37 => style.fg = Some((code - 30) as u8),
+            39 => style.fg = None,
+            code @ 90..=97 => style.fg = Some((code - 90 + 8) as u8),
+            38 | 48 => {
+                // Extended colors: 5;n (256-color) or 2;r;g;b (truecolor).
+                let is_foreground = codes[index] == 38;
+                match codes.get(index + 1) {
+                    Some(5) => {
+                        if is_foreground {
+                            style.fg = codes
+                                .get(index + 2)
+                                .and_then(|&n| u8::try_from(n).ok())
+                                .filter(|n| *n < 16);
+                        }
+                        index += 2;
+                    }
+                    Some(2) => index += 4,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ansi_to_segments, strip_ansi, AnsiStyle};
+
+    #[test]
+    fn splits_colored_segments() {
+        let segments = ansi_to_segments("\u{1b}[32mready\u{1b}[0m in \u{1b}[1;91m406\u{1b}[22m ms");
+        assert_eq!(
+            segments,
+            vec![
+                (
+                    AnsiStyle {
+                        fg: Some(2),
+                        bold: false
+                    },
+                    "ready".to_string()
+                ),
+                (AnsiStyle::default(), " in ".to_string()),
+                (
+                    AnsiStyle {
+                        fg: Some(9),
+                        bold: true
+                    },
+                    "406".to_string()
+                ),
+                (
+                    AnsiStyle {
+                        fg: Some(9),
+                        bold: false
+                    },
+                    " ms".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn strips_unknown_sequences() {
+        assert_eq!(strip_ansi("\u{1b}[2K\u{1b}[38;2;1;2;3mdone"), "done");
+    }
+}
@@ -1,16 +1,52 @@
 //! Configuration management for the process manager.
 //! Handles loading and saving the processes.json file.
 
-use serde::{Deserialize, Serialize};
+use chrono::{Datelike, NaiveDateTime, TimeDelta, Timelike};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use uuid::Uuid;
 
 pub const DEFAULT_REMOTE_CONTROL_PORT: u16 = 47_821;
 pub const DEFAULT_LOG_ROTATION_COUNT: usize = 10;
 pub const DEFAULT_PROCESS_ERROR_FLASH_SECONDS: u64 = 5;
 pub const DEFAULT_STARTUP_DELAY_SECONDS: u64 = 0;
+pub const DEFAULT_STOP_TIMEOUT_SECONDS: u64 = 5;
+pub const DEFAULT_HOOK_TIMEOUT_SECONDS: u64 = 60;
+pub const DEFAULT_MAX_RESTART_ATTEMPTS: u32 = 0;
+pub const DEFAULT_MAX_LOG_LINES: usize = 1000;
+pub const DEFAULT_MAX_OUTPUT_LINES_PER_SECOND: usize = 10_000;
+pub const DEFAULT_DOCKER_TAIL: u32 = 100;
+pub const DEFAULT_DOCKER_POLL_INTERVAL_MS: u64 = 2000;
 pub const WEEKLY_HOUR_COUNT: usize = 7 * 24;
+/// Sidebar heading for entries without a group.
+pub const DEFAULT_GROUP_NAME: &str = "General";
+
+static CONFIG_PATH_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Use `path` instead of `processes.json` next to the executable. Only the first call wins.
+pub fn set_config_path_override(path: PathBuf) {
+    let _ = CONFIG_PATH_OVERRIDE.set(path);
+}
+
+/// Config path requested on the command line (`--config <path>` or `--config=<path>`).
+pub fn config_path_from_args(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--config" {
+            return args
+                .next()
+                .filter(|path| !path.is_empty())
+                .map(PathBuf::from);
+        }
+        if let Some(path) = arg.strip_prefix("--config=") {
+            return (!path.is_empty()).then(|| PathBuf::from(path));
+        }
+    }
+    None
+}
 
 /// Type of process being managed
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -19,6 +55,15 @@ pub enum ProcessType {
     Process,
     /// A Docker container
     Docker,
+    /// A service from a docker compose file
+    Compose,
+}
+
+impl ProcessType {
+    /// Docker and Compose entries are driven through the docker CLI and polled for status.
+    pub fn is_container(&self) -> bool {
+        matches!(self, ProcessType::Docker | ProcessType::Compose)
+    }
 }
 
 impl Default for ProcessType {
@@ -32,6 +77,7 @@ impl std::fmt::Display for ProcessType {
         match self {
             ProcessType::Process => write!(f, "Process"),
             ProcessType::Docker => write!(f, "Docker"),
+            ProcessType::Compose => write!(f, "Compose"),
         }
     }
 }
@@ -70,9 +116,64 @@ impl ManagedRestartSchedule {
     }
 }
 
+/// Managed restart policy for exits nobody asked for. Requested stops never restart.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RestartPolicy {
+    #[default]
+    Never,
+    /// Only after a non-zero (or unknown) exit code.
+    OnFailure,
+    Always,
+}
+
+impl RestartPolicy {
+    pub fn is_enabled(self) -> bool {
+        self != Self::Never
+    }
+
+    /// Whether an unrequested exit with `exit_code` should trigger a managed restart.
+    pub fn restarts_after(self, exit_code: Option<i32>) -> bool {
+        match self {
+            Self::Never => false,
+            Self::OnFailure => exit_code != Some(0),
+            Self::Always => true,
+        }
+    }
+}
+
+impl std::fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Never => write!(f, "Never"),
+            Self::OnFailure => write!(f, "On failure"),
+            Self::Always => write!(f, "Always"),
+        }
+    }
+}
+
+/// Accepts a policy name or the legacy `auto_restart` bool (true -> Always, false -> Never).
+fn deserialize_restart_policy<'de, D>(deserializer: D) -> Result<RestartPolicy, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawRestartPolicy {
+        Legacy(bool),
+        Policy(RestartPolicy),
+    }
+
+    Ok(match RawRestartPolicy::deserialize(deserializer)? {
+        RawRestartPolicy::Legacy(true) => RestartPolicy::Always,
+        RawRestartPolicy::Legacy(false) => RestartPolicy::Never,
+        RawRestartPolicy::Policy(policy) => policy,
+    })
+}
+
 /// Human-readable scheduled-run cadence.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ScheduledRunMode {
+    EveryNMinutes,
     Hourly,
     EveryNHours,
     Daily,
@@ -88,6 +189,7 @@ impl Default for ScheduledRunMode {
 impl std::fmt::Display for ScheduledRunMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::EveryNMinutes => write!(f, "Every N minutes"),
             Self::Hourly => write!(f, "Hourly"),
             Self::EveryNHours => write!(f, "Every N hours"),
             Self::Daily => write!(f, "Daily"),
@@ -109,6 +211,9 @@ pub struct ScheduledRun {
     /// Interval used by EveryNHours mode.
     #[serde(default = "default_scheduled_run_interval_hours")]
     pub interval_hours: u8,
+    /// Interval used by EveryNMinutes mode, counted from local midnight.
+    #[serde(default = "default_scheduled_run_interval_minutes")]
+    pub interval_minutes: u8,
     /// Seven day flags, Monday through Sunday.
     #[serde(default = "default_weekdays")]
     pub weekdays: Vec<bool>,
@@ -121,6 +226,7 @@ impl Default for ScheduledRun {
             mode: ScheduledRunMode::Daily,
             hour: default_scheduled_run_hour(),
             interval_hours: default_scheduled_run_interval_hours(),
+            interval_minutes: default_scheduled_run_interval_minutes(),
             weekdays: default_weekdays(),
         }
     }
@@ -128,12 +234,19 @@ impl Default for ScheduledRun {
 
 impl ScheduledRun {
     pub fn due_at(&self, day_index: usize, hour: u32, minute: u32) -> bool {
-        if !self.enabled || minute != 0 {
+        if !self.enabled {
+            return false;
+        }
+        if self.mode == ScheduledRunMode::EveryNMinutes {
+            let interval = self.interval_minutes.clamp(1, 60) as u32;
+            return (hour * 60 + minute).is_multiple_of(interval);
+        }
+        if minute != 0 {
             return false;
         }
 
         match self.mode {
-            ScheduledRunMode::Hourly => true,
+            ScheduledRunMode::EveryNMinutes | ScheduledRunMode::Hourly => true,
             ScheduledRunMode::EveryNHours => {
                 let interval = self.interval_hours.clamp(1, 24) as u32;
                 hour % interval == 0
@@ -145,20 +258,138 @@ impl ScheduledRun {
             }
         }
     }
+
+    /// First trigger strictly after `now`, looking at most one week ahead. Minute intervals
+    /// are checked minute by minute over a day, the other modes hour by hour.
+    pub fn next_run_after(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        if !self.enabled {
+            return None;
+        }
+
+        let (step, steps, start) = if self.mode == ScheduledRunMode::EveryNMinutes {
+            (
+                TimeDelta::minutes(1),
+                24 * 60,
+                now.date().and_hms_opt(now.hour(), now.minute(), 0)?,
+            )
+        } else {
+            (
+                TimeDelta::hours(1),
+                WEEKLY_HOUR_COUNT,
+                now.date().and_hms_opt(now.hour(), 0, 0)?,
+            )
+        };
+        let mut candidate = start.checked_add_signed(step)?;
+        for _ in 0..steps {
+            let day_index = candidate.weekday().num_days_from_monday() as usize;
+            if self.due_at(day_index, candidate.hour(), candidate.minute()) {
+                return Some(candidate);
+            }
+            candidate = candidate.checked_add_signed(step)?;
+        }
+        None
+    }
+}
+
+/// Optional HTTP probe that tells a launched process apart from one that is actually serving.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HealthCheck {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Plain `http://host:port/path` URL polled while the process is running.
+    #[serde(default)]
+    pub url: String,
+    #[serde(default = "default_health_expected_status")]
+    pub expected_status: u16,
+    #[serde(default = "default_health_interval_secs")]
+    pub interval_secs: u64,
+    #[serde(default = "default_health_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Kill a running process after this many failed checks in a row so the restart policy
+    /// brings it back. 0 turns the watchdog off.
+    #[serde(default)]
+    pub restart_after_failures: u32,
+}
+
+impl Default for HealthCheck {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: String::new(),
+            expected_status: default_health_expected_status(),
+            interval_secs: default_health_interval_secs(),
+            timeout_secs: default_health_timeout_secs(),
+            restart_after_failures: 0,
+        }
+    }
 }
 
 /// Configuration for a single managed process
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ProcessConfig {
-    /// Unique identifier
+    /// Unique identifier. Entries written without one get a fresh id when loaded.
+    #[serde(default)]
     pub id: String,
     /// Display name
     pub name: String,
-    /// Command to run (for Process) or container name (for Docker)
+    /// Optional sidebar heading, e.g. "Frontend". Ungrouped entries are listed under "General".
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Optional `#RRGGBB` label drawn as a ring around the sidebar status dot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Command to run (for Process), container name (for Docker), or service name (for Compose)
     pub command: String,
+    /// Compose file path (only used for Compose type)
+    #[serde(default)]
+    pub compose_file: String,
+    /// `docker run` arguments, image last, used to create a missing Docker container as
+    /// `docker run -d --name <command> <args>`. Empty means the container must already exist.
+    #[serde(default)]
+    pub docker_run_args: String,
+    /// Backlog lines fetched when attaching to Docker or Compose logs. `None` fetches all of them.
+    #[serde(default = "default_docker_tail")]
+    pub docker_tail: Option<u32>,
+    /// Only fetch Docker or Compose logs newer than this, e.g. `5m` or an RFC 3339 timestamp.
+    #[serde(default)]
+    pub docker_since: String,
+    /// Explicit arguments. When non-empty, `command` is used verbatim as the program path.
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Run the command line through `sh -c` / `cmd /C` so pipes and redirects work.
+    #[serde(default)]
+    pub use_shell: bool,
     /// Working directory (only used for Process type)
     #[serde(default)]
     pub working_directory: String,
+    /// Extra environment variables applied on top of the inherited environment
+    #[serde(default)]
+    pub env: Vec<(String, String)>,
+    /// Pass the manager's own environment to the process. When off, it starts from a clean
+    /// environment with only a minimal `PATH` plus `env_file` and `env`.
+    #[serde(default = "default_inherit_env")]
+    pub inherit_env: bool,
+    /// Optional dotenv file, relative to the working directory. Explicit `env` entries win.
+    #[serde(default)]
+    pub env_file: String,
+    /// TCP port the process listens on. Checked before launch so a stale holder is reported.
+    #[serde(default)]
+    pub expected_port: Option<u16>,
+    /// Kill whatever already holds `expected_port` before launching.
+    #[serde(default)]
+    pub free_port_before_start: bool,
+    /// Unix account to launch as, via `sudo -n -u`. Blank runs as the manager's own user.
+    #[serde(default)]
+    pub run_as: String,
+    /// Command run to completion before launch; a failure aborts the start.
+    #[serde(default)]
+    pub pre_start: String,
+    /// Command run after the process has stopped or exited.
+    #[serde(default)]
+    pub post_stop: String,
+    /// Seconds a hook may run before it is killed and counted as failed.
+    #[serde(default = "default_hook_timeout_secs")]
+    pub hook_timeout_secs: u64,
     /// Type of process
     #[serde(default)]
     pub process_type: ProcessType,
@@ -168,15 +399,39 @@ pub struct ProcessConfig {
     /// Seconds to wait before honoring any start request for this process.
     #[serde(default = "default_startup_delay_seconds")]
     pub startup_delay_seconds: u64,
-    /// Whether to auto-restart when the process exits unexpectedly
+    /// Seconds to wait for a graceful exit before the process is force-killed.
+    #[serde(default = "default_stop_timeout_secs")]
+    pub stop_timeout_secs: u64,
+    /// Ids of entries that must be running before stack-wide starts launch this one.
     #[serde(default)]
-    pub auto_restart: bool,
+    pub depends_on: Vec<String>,
+    /// When managed restart brings the process back after it exits on its own.
+    /// Older configs store this as the `auto_restart` bool.
+    #[serde(
+        default,
+        alias = "auto_restart",
+        deserialize_with = "deserialize_restart_policy"
+    )]
+    pub restart_policy: RestartPolicy,
+    /// Consecutive managed restarts allowed before giving up. 0 retries forever.
+    #[serde(default = "default_max_restart_attempts")]
+    pub max_restart_attempts: u32,
+    /// Extra launch attempts, a second apart, when starting fails outright (spawn error, or
+    /// a Docker daemon that is not answering yet). Separate from managed restarts.
+    #[serde(default)]
+    pub start_retries: u32,
     /// Optional active-hours gate for managed restart.
     #[serde(default)]
     pub restart_schedule: ManagedRestartSchedule,
     /// Optional scheduled start trigger.
     #[serde(default)]
     pub scheduled_run: ScheduledRun,
+    /// Optional HTTP health check polled while running.
+    #[serde(default)]
+    pub health_check: HealthCheck,
+    /// Files or folders, relative to the working directory, whose changes restart the process.
+    #[serde(default)]
+    pub watch_paths: Vec<String>,
     /// Whether Start All should start this process
     #[serde(default = "default_global_control_enabled")]
     pub respond_to_start_all: bool,
@@ -186,6 +441,9 @@ pub struct ProcessConfig {
     /// Whether Restart All should restart this process
     #[serde(default = "default_global_control_enabled")]
     pub respond_to_restart_all: bool,
+    /// Wipe the log buffer on each start. When off, a `[--- restarted ---]` line marks the new run.
+    #[serde(default = "default_clear_logs_on_start")]
+    pub clear_logs_on_start: bool,
     /// Whether to persist process logs to disk
     #[serde(default)]
     pub log_to_disk: bool,
@@ -204,31 +462,195 @@ impl ProcessConfig {
         Self {
             id: Uuid::new_v4().to_string(),
             name,
+            group: None,
+            color: None,
             command,
+            compose_file: String::new(),
+            docker_run_args: String::new(),
+            docker_tail: default_docker_tail(),
+            docker_since: String::new(),
+            args: Vec::new(),
+            use_shell: false,
             working_directory,
+            env: Vec::new(),
+            env_file: String::new(),
+            inherit_env: true,
+            expected_port: None,
+            free_port_before_start: false,
+            run_as: String::new(),
+            pre_start: String::new(),
+            post_stop: String::new(),
+            hook_timeout_secs: default_hook_timeout_secs(),
             process_type,
             auto_start: false,
             startup_delay_seconds: default_startup_delay_seconds(),
-            auto_restart: false,
+            stop_timeout_secs: default_stop_timeout_secs(),
+            depends_on: Vec::new(),
+            restart_policy: RestartPolicy::Never,
+            max_restart_attempts: default_max_restart_attempts(),
+            start_retries: 0,
             restart_schedule: ManagedRestartSchedule::default(),
             scheduled_run: ScheduledRun::default(),
+            health_check: HealthCheck::default(),
+            watch_paths: Vec::new(),
             respond_to_start_all: true,
             respond_to_stop_all: true,
             respond_to_restart_all: true,
+            clear_logs_on_start: default_clear_logs_on_start(),
             log_to_disk: false,
             log_rotation_count: default_log_rotation_count(),
         }
     }
 
+    /// Sidebar group heading, falling back to [`DEFAULT_GROUP_NAME`].
+    pub fn group_name(&self) -> &str {
+        self.group
+            .as_deref()
+            .map(str::trim)
+            .filter(|group| !group.is_empty())
+            .unwrap_or(DEFAULT_GROUP_NAME)
+    }
+
+    /// Command line as shown to the user, with explicit arguments appended.
+    pub fn command_line(&self) -> String {
+        let mut line = self.command.clone();
+        for arg in &self.args {
+            line.push(' ');
+            if arg.is_empty() || arg.contains(char::is_whitespace) {
+                line.push('"');
+                line.push_str(arg);
+                line.push('"');
+            } else {
+                line.push_str(arg);
+            }
+        }
+        line
+    }
+
     pub fn normalize(&mut self) {
+        if self.id.trim().is_empty() {
+            self.id = Uuid::new_v4().to_string();
+        }
         normalize_weekly_hours(&mut self.restart_schedule.hours);
         normalize_weekdays(&mut self.scheduled_run.weekdays);
         self.scheduled_run.hour = self.scheduled_run.hour.min(23);
         self.scheduled_run.interval_hours = self.scheduled_run.interval_hours.clamp(1, 24);
+        self.scheduled_run.interval_minutes = self.scheduled_run.interval_minutes.clamp(1, 60);
         if self.log_rotation_count == 0 {
             self.log_rotation_count = default_log_rotation_count();
         }
+        normalize_env_vars(&mut self.env);
+        self.env_file = self.env_file.trim().to_string();
+        self.run_as = self.run_as.trim().to_string();
+        self.pre_start = self.pre_start.trim().to_string();
+        self.post_stop = self.post_stop.trim().to_string();
+        self.group = self
+            .group
+            .as_deref()
+            .map(str::trim)
+            .filter(|group| !group.is_empty())
+            .map(str::to_string);
+        self.color = self
+            .color
+            .as_deref()
+            .map(str::trim)
+            .filter(|color| !color.is_empty())
+            .map(str::to_string);
+        self.compose_file = self.compose_file.trim().to_string();
+        self.docker_run_args = self.docker_run_args.trim().to_string();
+        self.docker_since = self.docker_since.trim().to_string();
+        self.watch_paths.retain(|path| !path.trim().is_empty());
+        for path in &mut self.watch_paths {
+            *path = path.trim().to_string();
+        }
+        self.health_check.url = self.health_check.url.trim().to_string();
+        self.health_check.interval_secs = self.health_check.interval_secs.max(1);
+        self.health_check.timeout_secs = self.health_check.timeout_secs.max(1);
+        let own_id = self.id.clone();
+        let mut seen = std::collections::HashSet::new();
+        self.depends_on.retain(|dependency| {
+            !dependency.trim().is_empty()
+                && *dependency != own_id
+                && seen.insert(dependency.clone())
+        });
+    }
+}
+
+/// Write through a temp file in the same folder and rename it over `path`, so a crash mid-write
+/// leaves either the old file or the new one. `fs::rename` replaces an existing target on
+/// Windows too (MoveFileEx with MOVEFILE_REPLACE_EXISTING).
+fn write_file_atomically(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let temp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, std::process::id()));
+
+    let result = (|| {
+        let mut file = fs::File::create(&temp_path)?;
+        file.write_all(content)?;
+        file.sync_all()?;
+        drop(file);
+        fs::rename(&temp_path, path)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
     }
+    result
+}
+
+/// Whether a file can be created in `dir`, checked by creating and removing a probe file.
+fn dir_is_writable(dir: &Path) -> bool {
+    let probe = dir.join(format!(".write-test-{}.tmp", std::process::id()));
+    match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+    {
+        Ok(_) => {
+            let _ = fs::remove_file(&probe);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// `processes.json` in the per-user config folder: `%APPDATA%\process-manager` on Windows,
+/// `$XDG_CONFIG_HOME/process-manager` or `~/.config/process-manager` elsewhere.
+fn user_config_path() -> Option<PathBuf> {
+    #[cfg(windows)]
+    let base = std::env::var_os("APPDATA")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from);
+    #[cfg(not(windows))]
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .filter(|value| !value.is_empty())
+                .map(|home| PathBuf::from(home).join(".config"))
+        });
+    base.map(|base| base.join("process-manager").join("processes.json"))
+}
+
+/// Move a config file aside as `<name>.bak-<timestamp>`, returning the new path.
+fn backup_config_file(path: &Path) -> Result<PathBuf, String> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "processes.json".to_string());
+    let backup = path.with_file_name(format!(
+        "{}.bak-{}",
+        file_name,
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    fs::rename(path, &backup).map_err(|err| err.to_string())?;
+    Ok(backup)
+}
+
+fn default_docker_tail() -> Option<u32> {
+    Some(DEFAULT_DOCKER_TAIL)
 }
 
 fn default_log_rotation_count() -> usize {
@@ -239,10 +661,30 @@ fn default_global_control_enabled() -> bool {
     true
 }
 
+fn default_clear_logs_on_start() -> bool {
+    true
+}
+
+fn default_inherit_env() -> bool {
+    true
+}
+
 fn default_startup_delay_seconds() -> u64 {
     DEFAULT_STARTUP_DELAY_SECONDS
 }
 
+fn default_stop_timeout_secs() -> u64 {
+    DEFAULT_STOP_TIMEOUT_SECONDS
+}
+
+fn default_hook_timeout_secs() -> u64 {
+    DEFAULT_HOOK_TIMEOUT_SECONDS
+}
+
+fn default_max_restart_attempts() -> u32 {
+    DEFAULT_MAX_RESTART_ATTEMPTS
+}
+
 pub fn default_weekly_hours() -> Vec<bool> {
     vec![false; WEEKLY_HOUR_COUNT]
 }
@@ -259,6 +701,22 @@ fn default_scheduled_run_interval_hours() -> u8 {
     1
 }
 
+fn default_scheduled_run_interval_minutes() -> u8 {
+    15
+}
+
+fn default_health_expected_status() -> u16 {
+    200
+}
+
+fn default_health_interval_secs() -> u64 {
+    10
+}
+
+fn default_health_timeout_secs() -> u64 {
+    2
+}
+
 fn normalize_weekly_hours(hours: &mut Vec<bool>) {
     if hours.len() < WEEKLY_HOUR_COUNT {
         hours.resize(WEEKLY_HOUR_COUNT, false);
@@ -275,6 +733,15 @@ fn normalize_weekdays(days: &mut Vec<bool>) {
     }
 }
 
+/// Trim surrounding whitespace from keys and values and drop entries without a key.
+pub fn normalize_env_vars(env: &mut Vec<(String, String)>) {
+    for (key, value) in env.iter_mut() {
+        *key = key.trim().to_string();
+        *value = value.trim().to_string();
+    }
+    env.retain(|(key, _)| !key.is_empty());
+}
+
 pub fn weekly_hour_index(day_index: usize, hour: u32) -> Option<usize> {
     if day_index >= 7 || hour >= 24 {
         return None;
@@ -313,6 +780,85 @@ fn default_remote_control_port() -> u16 {
     DEFAULT_REMOTE_CONTROL_PORT
 }
 
+/// Color scheme for the UI.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+}
+
+/// Window geometry remembered between sessions, stored in `window.json` next to the config file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct WindowState {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    #[serde(default)]
+    pub maximized: bool,
+}
+
+impl WindowState {
+    fn path() -> PathBuf {
+        AppConfig::config_path().with_file_name("window.json")
+    }
+
+    /// Saved geometry, if any. Missing, unreadable, or nonsensical files are ignored.
+    pub fn load() -> Option<Self> {
+        let content = fs::read_to_string(Self::path()).ok()?;
+        let state: Self = serde_json::from_str(&content).ok()?;
+        let finite = [state.x, state.y, state.width, state.height]
+            .iter()
+            .all(|value| value.is_finite());
+        (finite && state.width > 0.0 && state.height > 0.0).then_some(state)
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize window state: {}", e))?;
+        fs::write(Self::path(), content).map_err(|e| format!("Failed to write window state: {}", e))
+    }
+}
+
+/// Regex patterns that mark log lines as errors or warnings, on top of the built-in keywords.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LogHighlightRules {
+    /// Keep the built-in keyword detection (`error`, `fatal`, `panic`, `warn`, ...).
+    #[serde(default = "default_builtin_keywords")]
+    pub builtin_keywords: bool,
+    #[serde(default)]
+    pub error_patterns: Vec<String>,
+    #[serde(default)]
+    pub warning_patterns: Vec<String>,
+    /// Custom line colors, checked before the error/warning/system coloring.
+    #[serde(default)]
+    pub color_rules: Vec<LogColorRule>,
+}
+
+impl Default for LogHighlightRules {
+    fn default() -> Self {
+        Self {
+            builtin_keywords: default_builtin_keywords(),
+            error_patterns: Vec::new(),
+            warning_patterns: Vec::new(),
+            color_rules: Vec::new(),
+        }
+    }
+}
+
+/// Paints log lines matching `pattern` in `color` (`#RRGGBB` or `#RGB`). Only the display
+/// color changes; error detection still follows the error patterns.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LogColorRule {
+    pub pattern: String,
+    pub color: String,
+}
+
+fn default_builtin_keywords() -> bool {
+    true
+}
+
 /// Root configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
@@ -328,6 +874,71 @@ pub struct AppConfig {
     /// How long the Processes sidebar softly flashes after a new error arrives. Set to 0 to disable.
     #[serde(default = "default_process_error_flash_seconds")]
     pub process_error_flash_seconds: u64,
+    /// Prefix captured output lines with their local arrival time.
+    #[serde(default)]
+    pub show_timestamps: bool,
+    /// In-memory log lines kept per process. Older lines are dropped first.
+    #[serde(default = "default_max_log_lines")]
+    pub max_log_lines: usize,
+    /// Output lines per second a process may log before further lines are dropped and
+    /// counted in a `[N lines suppressed]` marker. Set to 0 to disable the limit.
+    #[serde(default = "default_max_output_lines_per_second")]
+    pub max_output_lines_per_second: usize,
+    /// Seconds without output after which a running entry's "last output" turns amber. 0 = never.
+    #[serde(default = "default_silent_output_warning_secs")]
+    pub silent_output_warning_secs: u64,
+    /// Seconds without output after which it turns red. 0 = never.
+    #[serde(default = "default_silent_output_alert_secs")]
+    pub silent_output_alert_secs: u64,
+    /// Wrap long log lines. When off, the log view scrolls horizontally instead.
+    #[serde(default = "default_wrap_log_lines")]
+    pub wrap_log_lines: bool,
+    /// Which log lines are highlighted and counted as errors or warnings.
+    #[serde(default)]
+    pub log_highlighting: LogHighlightRules,
+    /// UI color scheme, switchable live from the header.
+    #[serde(default)]
+    pub theme: Theme,
+    /// Pause between launches during Start All. 0 starts everything at once.
+    #[serde(default)]
+    pub start_all_delay_ms: u64,
+    /// Also `docker stop` Docker and Compose entries when the app closes.
+    #[serde(default)]
+    pub stop_docker_on_exit: bool,
+    /// Base spacing of Docker and Compose status polls. Polls speed up after status changes
+    /// and slow down once containers have been stable for a while.
+    #[serde(default = "default_docker_poll_interval_ms")]
+    pub docker_poll_interval_ms: u64,
+    /// Ask before Start All, Stop All, or Restart All runs.
+    #[serde(default)]
+    pub confirm_bulk_actions: bool,
+    /// Ask before closing the window while entries it would stop are running.
+    #[serde(default)]
+    pub confirm_exit_when_running: bool,
+    /// Show each process's latest log line under its name in the sidebar.
+    #[serde(default)]
+    pub sidebar_log_preview: bool,
+    /// Keep the compact status strip above other windows while it is open.
+    #[serde(default)]
+    pub strip_always_on_top: bool,
+    #[serde(default)]
+    pub processes: Vec<ProcessConfig>,
+    /// Every stack profile in dropdown order. The active one lives in `stack_name`/`processes`;
+    /// its slot here only keeps the name. Empty for single-stack configs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<StackProfile>,
+    /// Index of the active entry in `profiles`.
+    #[serde(default)]
+    pub active_profile: usize,
+    /// Process shown in the detail view when the app was last closed. Only restores the view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_process: Option<String>,
+}
+
+/// A named set of processes that can be swapped in as the active stack.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StackProfile {
+    pub name: String,
     #[serde(default)]
     pub processes: Vec<ProcessConfig>,
 }
@@ -336,6 +947,30 @@ fn default_stack_name() -> String {
     "My Stack".to_string()
 }
 
+fn default_max_log_lines() -> usize {
+    DEFAULT_MAX_LOG_LINES
+}
+
+fn default_max_output_lines_per_second() -> usize {
+    DEFAULT_MAX_OUTPUT_LINES_PER_SECOND
+}
+
+fn default_silent_output_warning_secs() -> u64 {
+    300
+}
+
+fn default_silent_output_alert_secs() -> u64 {
+    900
+}
+
+fn default_docker_poll_interval_ms() -> u64 {
+    DEFAULT_DOCKER_POLL_INTERVAL_MS
+}
+
+fn default_wrap_log_lines() -> bool {
+    true
+}
+
 fn default_log_directory() -> String {
     ".".to_string()
 }
@@ -351,14 +986,39 @@ impl Default for AppConfig {
             remote_control: RemoteControlConfig::default(),
             log_directory: default_log_directory(),
             process_error_flash_seconds: default_process_error_flash_seconds(),
+            show_timestamps: false,
+            max_log_lines: DEFAULT_MAX_LOG_LINES,
+            max_output_lines_per_second: DEFAULT_MAX_OUTPUT_LINES_PER_SECOND,
+            silent_output_warning_secs: default_silent_output_warning_secs(),
+            silent_output_alert_secs: default_silent_output_alert_secs(),
+            wrap_log_lines: default_wrap_log_lines(),
+            log_highlighting: LogHighlightRules::default(),
+            theme: Theme::Dark,
+            start_all_delay_ms: 0,
+            stop_docker_on_exit: false,
+            docker_poll_interval_ms: default_docker_poll_interval_ms(),
+            confirm_bulk_actions: false,
+            confirm_exit_when_running: false,
+            sidebar_log_preview: false,
+            strip_always_on_top: false,
             processes: Vec::new(),
+            profiles: Vec::new(),
+            active_profile: 0,
+            selected_process: None,
         }
     }
 }
 
 impl AppConfig {
-    /// Get the path to the config file (next to the executable)
+    /// Get the path to the config file (the override if set, otherwise next to the executable)
     pub fn config_path() -> PathBuf {
+        if let Some(path) = CONFIG_PATH_OVERRIDE.get() {
+            return path.clone();
+        }
+        Self::exe_config_path()
+    }
+
+    fn exe_config_path() -> PathBuf {
         let exe_path = std::env::current_exe().unwrap_or_else(|_| PathBuf::from("."));
         let exe_dir = exe_path
             .parent()
@@ -366,15 +1026,101 @@ impl AppConfig {
         exe_dir.join("processes.json")
     }
 
-    /// Load config from file, creating default if not found or if parsing fails.
-    pub fn load() -> Self {
-        match Self::load_from_disk() {
-            Ok(config) => {
-                let _ = config.save();
-                return config;
+    /// Switch to the per-user config file when no override is set and the executable's folder
+    /// cannot be written (e.g. an install under Program Files or /Applications), so saves are
+    /// not silently lost. A config already next to the executable is copied over the first time.
+    /// Returns the warning to show the user.
+    fn use_user_config_if_exe_dir_unwritable() -> Option<String> {
+        if CONFIG_PATH_OVERRIDE.get().is_some() {
+            return None;
+        }
+        let exe_path = Self::exe_config_path();
+        let exe_dir = exe_path.parent()?;
+        if dir_is_writable(exe_dir) {
+            return None;
+        }
+
+        let Some(user_path) = user_config_path() else {
+            return Some(format!(
+                "{} is not writable and no per-user config folder was found, so changes will not be saved.",
+                exe_dir.display()
+            ));
+        };
+        if let Some(parent) = user_path.parent() {
+            if let Err(err) = fs::create_dir_all(parent) {
+                return Some(format!(
+                    "{} is not writable and {} could not be created ({}), so changes will not be saved.",
+                    exe_dir.display(),
+                    parent.display(),
+                    err
+                ));
             }
-            Err(err) => {
-                eprintln!("Failed to load config from disk: {}", err);
+        }
+        if !user_path.exists() && exe_path.exists() {
+            if let Err(err) = fs::copy(&exe_path, &user_path) {
+                eprintln!("Failed to copy config to {}: {}", user_path.display(), err);
+            }
+        }
+
+        eprintln!(
+            "Config folder {} is not writable; using {}",
+            exe_dir.display(),
+            user_path.display()
+        );
+        set_config_path_override(user_path.clone());
+        Some(format!(
+            "{} is not writable, so the config is saved to {} instead.",
+            exe_dir.display(),
+            user_path.display()
+        ))
+    }
+
+    /// Load config from file, creating default if not found or if parsing fails.
+    ///
+    /// A file that exists but cannot be loaded is renamed to `<name>.bak-<timestamp>` before
+    /// the default is written. The returned message says so, for display to the user.
+    pub fn load() -> (Self, Option<String>) {
+        let location_warning = Self::use_user_config_if_exe_dir_unwritable();
+        let (config, warning) = Self::load_from_config_path();
+        let warning = match (location_warning, warning) {
+            (Some(location), Some(warning)) => Some(format!("{} {}", location, warning)),
+            (location, warning) => location.or(warning),
+        };
+        (config, warning)
+    }
+
+    fn load_from_config_path() -> (Self, Option<String>) {
+        let path = Self::config_path();
+        let mut warning = None;
+        if path.exists() {
+            match Self::load_from_path(&path) {
+                Ok(config) => {
+                    let _ = config.save();
+                    return (config, None);
+                }
+                Err(err) => {
+                    eprintln!("Failed to load config from disk: {}", err);
+                    match backup_config_file(&path) {
+                        Ok(backup) => {
+                            warning = Some(format!(
+                                "{} The original was moved to {}.",
+                                err,
+                                backup.display()
+                            ));
+                        }
+                        Err(backup_err) => {
+                            // Never overwrite a file we could not set aside.
+                            eprintln!("Failed to back up config: {}", backup_err);
+                            let mut config = Self::default();
+                            config.normalize();
+                            let message = format!(
+                                "{} It could not be backed up ({}); starting with an empty stack.",
+                                err, backup_err
+                            );
+                            return (config, Some(message));
+                        }
+                    }
+                }
             }
         }
 
@@ -382,7 +1128,7 @@ impl AppConfig {
         let mut config = Self::default();
         config.normalize();
         let _ = config.save(); // Try to save default
-        config
+        (config, warning)
     }
 
     /// Load config from disk without mutating state or creating fallback values.
@@ -390,17 +1136,68 @@ impl AppConfig {
         let path = Self::config_path();
 
         if !path.exists() {
-            return Err("processes.json was not found.".to_string());
+            return Err(format!("{} was not found.", path.display()));
         }
 
-        let content = fs::read_to_string(&path)
-            .map_err(|err| format!("Failed to read config: {}", err))?;
+        Self::load_from_path(&path)
+    }
+
+    /// Read and validate a config file from an arbitrary path, e.g. an imported stack.
+    pub fn load_from_path(path: &Path) -> Result<Self, String> {
+        let content =
+            fs::read_to_string(path).map_err(|err| format!("Failed to read config: {}", err))?;
         let mut config = serde_json::from_str::<Self>(&content)
             .map_err(|err| format!("Failed to parse config: {}", err))?;
         config.normalize();
         Ok(config)
     }
 
+    /// Parse config text edited in the app. Parse errors quote the offending line, and
+    /// duplicate process ids are rejected because runtime state is keyed by id. Duplicate
+    /// names (ignoring case) are rejected too, since scripts can address entries by name.
+    pub fn from_json_text(content: &str) -> Result<Self, String> {
+        let mut config = serde_json::from_str::<Self>(content).map_err(|err| {
+            match content.lines().nth(err.line().saturating_sub(1)) {
+                Some(line) if err.line() > 0 => format!("{}\n{}", err, line.trim()),
+                _ => err.to_string(),
+            }
+        })?;
+        config.normalize();
+
+        let mut ids = std::collections::HashSet::new();
+        if let Some(process) = config
+            .processes
+            .iter()
+            .find(|process| !ids.insert(process.id.as_str()))
+        {
+            return Err(format!(
+                "Process id '{}' is used by more than one entry.",
+                process.id
+            ));
+        }
+
+        let mut names = std::collections::HashSet::new();
+        if let Some(process) = config
+            .processes
+            .iter()
+            .find(|process| !names.insert(process.name.trim().to_lowercase()))
+        {
+            return Err(format!(
+                "Process name '{}' is used by more than one entry.",
+                process.name.trim()
+            ));
+        }
+        Ok(config)
+    }
+
+    /// The config as it is written to disk.
+    pub fn to_json_text(&self) -> Result<String, String> {
+        let mut normalized = self.clone();
+        normalized.normalize();
+        serde_json::to_string_pretty(&normalized)
+            .map_err(|e| format!("Failed to serialize config: {}", e))
+    }
+
     /// Normalize loaded or edited config so older process files round-trip into the current schema.
     pub fn normalize(&mut self) {
         if self.log_directory.trim().is_empty() {
@@ -409,24 +1206,138 @@ impl AppConfig {
         if self.remote_control.port == 0 {
             self.remote_control.port = default_remote_control_port();
         }
+        if self.max_log_lines == 0 {
+            self.max_log_lines = default_max_log_lines();
+        }
+        for patterns in [
+            &mut self.log_highlighting.error_patterns,
+            &mut self.log_highlighting.warning_patterns,
+        ] {
+            patterns.retain(|pattern| !pattern.trim().is_empty());
+            for pattern in patterns.iter_mut() {
+                *pattern = pattern.trim().to_string();
+            }
+        }
+        self.log_highlighting
+            .color_rules
+            .retain(|rule| !rule.pattern.trim().is_empty());
+        for rule in &mut self.log_highlighting.color_rules {
+            rule.pattern = rule.pattern.trim().to_string();
+            rule.color = rule.color.trim().to_string();
+        }
         for process in &mut self.processes {
             process.normalize();
         }
+        if self.profiles.is_empty() {
+            self.active_profile = 0;
+        } else {
+            self.active_profile = self.active_profile.min(self.profiles.len() - 1);
+            let active = &mut self.profiles[self.active_profile];
+            active.name = self.stack_name.clone();
+            active.processes.clear();
+            for profile in &mut self.profiles {
+                for process in &mut profile.processes {
+                    process.normalize();
+                }
+            }
+        }
+    }
+
+    /// Names of every stack profile in order; single-stack configs report just `stack_name`.
+    pub fn profile_names(&self) -> Vec<String> {
+        if self.profiles.is_empty() {
+            return vec![self.stack_name.clone()];
+        }
+
+        self.profiles
+            .iter()
+            .enumerate()
+            .map(|(index, profile)| {
+                if index == self.active_profile {
+                    self.stack_name.clone()
+                } else {
+                    profile.name.clone()
+                }
+            })
+            .collect()
+    }
+
+    /// Make `index` the active profile, parking the current stack in its slot.
+    pub fn switch_profile(&mut self, index: usize) -> Result<(), String> {
+        self.ensure_profiles();
+        if index >= self.profiles.len() {
+            return Err(format!("Stack profile {} does not exist.", index + 1));
+        }
+        if index == self.active_profile {
+            return Ok(());
+        }
+
+        self.profiles[self.active_profile] = StackProfile {
+            name: self.stack_name.clone(),
+            processes: std::mem::take(&mut self.processes),
+        };
+        let next = &mut self.profiles[index];
+        self.stack_name = next.name.clone();
+        self.processes = std::mem::take(&mut next.processes);
+        self.active_profile = index;
+        Ok(())
+    }
+
+    /// Append an empty profile and return its index.
+    pub fn add_profile(&mut self, name: String) -> usize {
+        self.ensure_profiles();
+        self.profiles.push(StackProfile {
+            name,
+            processes: Vec::new(),
+        });
+        self.profiles.len() - 1
+    }
+
+    /// Single-stack configs gain a profile list on first use, holding the current stack.
+    fn ensure_profiles(&mut self) {
+        if self.profiles.is_empty() {
+            self.profiles.push(StackProfile {
+                name: self.stack_name.clone(),
+                processes: Vec::new(),
+            });
+            self.active_profile = 0;
+        }
     }
 
     /// Save config to file
     pub fn save(&self) -> Result<(), String> {
-        let path = Self::config_path();
-        let mut normalized = self.clone();
-        normalized.normalize();
-        let content = serde_json::to_string_pretty(&normalized)
-            .map_err(|e| format!("Failed to serialize config: {}", e))?;
+        self.save_to_path(&Self::config_path())
+    }
+
+    /// Save config to an arbitrary path, e.g. when exporting the stack.
+    pub fn save_to_path(&self, path: &Path) -> Result<(), String> {
+        let content = self.to_json_text()?;
 
-        fs::write(&path, content).map_err(|e| format!("Failed to write config: {}", e))?;
+        write_file_atomically(path, content.as_bytes())
+            .map_err(|e| format!("Failed to write config: {}", e))?;
 
         Ok(())
     }
 
+    /// Give every process a fresh id, keeping `depends_on` references intact.
+    pub fn regenerate_process_ids(&mut self) {
+        let remapped: std::collections::HashMap<String, String> = self
+            .processes
+            .iter_mut()
+            .map(|process| {
+                let new_id = Uuid::new_v4().to_string();
+                (std::mem::replace(&mut process.id, new_id.clone()), new_id)
+            })
+            .collect();
+        for process in &mut self.processes {
+            for dependency in &mut process.depends_on {
+                if let Some(new_id) = remapped.get(dependency) {
+                    *dependency = new_id.clone();
+                }
+            }
+        }
+    }
+
     /// Add a new process configuration
     pub fn add_process(&mut self, mut config: ProcessConfig) {
         config.normalize();
@@ -436,6 +1347,9 @@ impl AppConfig {
     /// Remove a process by ID
     pub fn remove_process(&mut self, id: &str) {
         self.processes.retain(|p| p.id != id);
+        for process in &mut self.processes {
+            process.depends_on.retain(|dependency| dependency != id);
+        }
     }
 
     /// Get a process by ID
@@ -443,6 +1357,26 @@ impl AppConfig {
         self.processes.iter().find(|p| p.id == id)
     }
 
+    /// Whether another process already uses this name, ignoring case and surrounding whitespace.
+    pub fn name_exists(&self, name: &str, excluding: Option<&str>) -> bool {
+        let name = name.trim().to_lowercase();
+        self.processes.iter().any(|process| {
+            Some(process.id.as_str()) != excluding && process.name.trim().to_lowercase() == name
+        })
+    }
+
+    /// `name` if no process uses it yet, otherwise the first free "Name (2)", "Name (3)", ...
+    pub fn unique_process_name(&self, name: &str) -> String {
+        let name = name.trim();
+        if !self.name_exists(name, None) {
+            return name.to_string();
+        }
+        (2..)
+            .map(|suffix| format!("{} ({})", name, suffix))
+            .find(|candidate| !self.name_exists(candidate, None))
+            .unwrap()
+    }
+
     /// Update a process configuration
     #[allow(dead_code)]
     pub fn update_process(&mut self, id: &str, mut updated: ProcessConfig) {
@@ -452,29 +1386,49 @@ impl AppConfig {
         }
     }
 
-    /// Move a process one slot earlier in the list.
+    /// Give every entry the same managed restart policy. Returns how many entries changed.
+    pub fn set_all_restart_policy(&mut self, policy: RestartPolicy) -> usize {
+        let mut changed = 0;
+        for process in &mut self.processes {
+            if process.restart_policy != policy {
+                process.restart_policy = policy;
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Move a process one slot earlier within its group.
     pub fn move_process_up(&mut self, id: &str) -> bool {
         let Some(index) = self.processes.iter().position(|process| process.id == id) else {
             return false;
         };
-        if index == 0 {
+        let group = self.processes[index].group_name();
+        let Some(target) = self.processes[..index]
+            .iter()
+            .rposition(|process| process.group_name() == group)
+        else {
             return false;
-        }
+        };
 
-        self.processes.swap(index, index - 1);
+        self.processes.swap(index, target);
         true
     }
 
-    /// Move a process one slot later in the list.
+    /// Move a process one slot later within its group.
     pub fn move_process_down(&mut self, id: &str) -> bool {
         let Some(index) = self.processes.iter().position(|process| process.id == id) else {
             return false;
         };
-        if index + 1 >= self.processes.len() {
+        let group = self.processes[index].group_name();
+        let Some(offset) = self.processes[index + 1..]
+            .iter()
+            .position(|process| process.group_name() == group)
+        else {
             return false;
-        }
+        };
 
-        self.processes.swap(index, index + 1);
+        self.processes.swap(index, index + 1 + offset);
         true
     }
 
@@ -497,6 +1451,279 @@ impl AppConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn set_all_restart_policy_counts_changed_entries() {
+        let mut config = AppConfig::default();
+        for (name, policy) in [
+            ("API", RestartPolicy::Never),
+            ("Web", RestartPolicy::Always),
+            ("Worker", RestartPolicy::OnFailure),
+        ] {
+            let mut process = ProcessConfig::new(
+                name.to_string(),
+                "true".to_string(),
+                String::new(),
+                ProcessType::Process,
+            );
+            process.restart_policy = policy;
+            config.add_process(process);
+        }
+
+        assert_eq!(config.set_all_restart_policy(RestartPolicy::Always), 2);
+        assert!(config
+            .processes
+            .iter()
+            .all(|process| process.restart_policy == RestartPolicy::Always));
+        assert_eq!(config.set_all_restart_policy(RestartPolicy::Always), 0);
+    }
+
+    #[test]
+    fn regenerated_ids_keep_dependencies_linked() {
+        let mut config = AppConfig::default();
+        let db = ProcessConfig::new(
+            "DB".to_string(),
+            "postgres".to_string(),
+            String::new(),
+            ProcessType::Docker,
+        );
+        let mut api = ProcessConfig::new(
+            "API".to_string(),
+            "cargo run".to_string(),
+            String::new(),
+            ProcessType::Process,
+        );
+        api.depends_on = vec![db.id.clone()];
+        let old_db_id = db.id.clone();
+        config.processes = vec![db, api];
+
+        config.regenerate_process_ids();
+
+        assert_ne!(config.processes[0].id, old_db_id);
+        assert_eq!(
+            config.processes[1].depends_on,
+            vec![config.processes[0].id.clone()]
+        );
+    }
+
+    #[test]
+    fn name_collisions_ignore_case_and_the_edited_entry() {
+        let mut config = AppConfig::default();
+        let backend = ProcessConfig::new(
+            "Backend".to_string(),
+            "cargo run".to_string(),
+            String::new(),
+            ProcessType::Process,
+        );
+        let backend_id = backend.id.clone();
+        config.processes = vec![backend];
+
+        assert!(config.name_exists(" backend ", None));
+        assert!(!config.name_exists("Backend", Some(&backend_id)));
+        assert!(!config.name_exists("Frontend", None));
+    }
+
+    #[test]
+    fn unique_process_names_count_up_past_taken_suffixes() {
+        let mut config = AppConfig::default();
+        for name in ["Backend", "backend (2)"] {
+            config.add_process(ProcessConfig::new(
+                name.to_string(),
+                "cargo run".to_string(),
+                String::new(),
+                ProcessType::Process,
+            ));
+        }
+
+        assert_eq!(config.unique_process_name("Frontend"), "Frontend");
+        assert_eq!(config.unique_process_name(" Backend "), "Backend (3)");
+    }
+
+    #[test]
+    fn config_path_argument_accepts_both_forms() {
+        let args = |raw: &[&str]| raw.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            config_path_from_args(args(&["--config", "./frontend.json"])),
+            Some(PathBuf::from("./frontend.json"))
+        );
+        assert_eq!(
+            config_path_from_args(args(&["--config=backend.json"])),
+            Some(PathBuf::from("backend.json"))
+        );
+        assert_eq!(config_path_from_args(args(&["--config"])), None);
+        assert_eq!(config_path_from_args(args(&[])), None);
+    }
+
+    #[test]
+    fn switching_profiles_parks_the_active_stack() {
+        let mut config: AppConfig = serde_json::from_str(
+            r#"{ "stack_name": "Dev", "processes": [{ "id": "api", "name": "API", "command": "cargo run" }] }"#,
+        )
+        .expect("legacy config should parse");
+        config.normalize();
+        assert_eq!(config.profile_names(), vec!["Dev".to_string()]);
+
+        let staging = config.add_profile("Staging".to_string());
+        config
+            .switch_profile(staging)
+            .expect("profile should exist");
+        assert_eq!(config.stack_name, "Staging");
+        assert!(config.processes.is_empty());
+
+        config.switch_profile(0).expect("profile should exist");
+        assert_eq!(config.stack_name, "Dev");
+        assert_eq!(config.processes[0].id, "api");
+        assert_eq!(
+            config.profile_names(),
+            vec!["Dev".to_string(), "Staging".to_string()]
+        );
+        assert!(config.switch_profile(5).is_err());
+    }
+
+    #[test]
+    fn next_scheduled_run_skips_to_the_matching_hour() {
+        let now = chrono::NaiveDate::from_ymd_opt(2026, 3, 2) // a Monday
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let mut run = ScheduledRun {
+            enabled: true,
+            hour: 9,
+            ..ScheduledRun::default()
+        };
+        assert_eq!(
+            run.next_run_after(now),
+            Some(now.date().succ_opt().unwrap().and_hms_opt(9, 0, 0).unwrap())
+        );
+
+        run.mode = ScheduledRunMode::SelectedWeekdays;
+        run.weekdays = vec![false, false, false, false, true, false, false];
+        assert_eq!(
+            run.next_run_after(now).map(|next| next.weekday()),
+            Some(chrono::Weekday::Fri)
+        );
+
+        run.weekdays = vec![false; 7];
+        assert_eq!(run.next_run_after(now), None);
+
+        run.mode = ScheduledRunMode::EveryNMinutes;
+        run.interval_minutes = 15;
+        assert_eq!(
+            run.next_run_after(now),
+            Some(now.date().and_hms_opt(9, 45, 0).unwrap())
+        );
+        assert!(run.due_at(0, 10, 0) && !run.due_at(0, 10, 20));
+        run.enabled = false;
+        run.mode = ScheduledRunMode::Hourly;
+        assert_eq!(run.next_run_after(now), None);
+    }
+
+    #[test]
+    fn saves_replace_the_file_without_leaving_temp_files() {
+        let dir = std::env::temp_dir().join(format!("pm-save-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("processes.json");
+        fs::write(&path, "old").unwrap();
+
+        let config = AppConfig {
+            stack_name: "Saved".to_string(),
+            ..AppConfig::default()
+        };
+        config.save_to_path(&path).expect("save should succeed");
+        let reloaded = AppConfig::load_from_path(&path).expect("saved config should load");
+        let entries = fs::read_dir(&dir).unwrap().count();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(reloaded.stack_name, "Saved");
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn writability_probe_cleans_up_after_itself() {
+        let dir = std::env::temp_dir().join(format!("pm-probe-{}", Uuid::new_v4()));
+        assert!(!dir_is_writable(&dir));
+
+        fs::create_dir_all(&dir).unwrap();
+        let writable = dir_is_writable(&dir);
+        let entries = fs::read_dir(&dir).unwrap().count();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(writable);
+        assert_eq!(entries, 0);
+    }
+
+    #[test]
+    fn broken_config_is_moved_aside() {
+        let path = std::env::temp_dir().join(format!("pm-config-{}.json", Uuid::new_v4()));
+        fs::write(&path, "{ \"stack_name\": \"Dev\", }").unwrap();
+        assert!(AppConfig::load_from_path(&path).is_err());
+
+        let backup = backup_config_file(&path).expect("backup should succeed");
+        let content = fs::read_to_string(&backup).unwrap();
+        let _ = fs::remove_file(&backup);
+
+        assert!(!path.exists());
+        assert!(backup
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .contains(".json.bak-"));
+        assert_eq!(content, "{ \"stack_name\": \"Dev\", }");
+    }
+
+    #[test]
+    fn legacy_auto_restart_maps_to_restart_policy() {
+        let config: AppConfig = serde_json::from_str(
+            r#"{ "stack_name": "Dev", "processes": [
+                { "id": "a", "name": "A", "command": "a", "auto_restart": true },
+                { "id": "b", "name": "B", "command": "b", "auto_restart": false },
+                { "id": "c", "name": "C", "command": "c", "restart_policy": "OnFailure" },
+                { "id": "d", "name": "D", "command": "d" }
+            ] }"#,
+        )
+        .expect("config should parse");
+
+        let policies: Vec<RestartPolicy> = config
+            .processes
+            .iter()
+            .map(|process| process.restart_policy)
+            .collect();
+        assert_eq!(
+            policies,
+            vec![
+                RestartPolicy::Always,
+                RestartPolicy::Never,
+                RestartPolicy::OnFailure,
+                RestartPolicy::Never
+            ]
+        );
+        let value = serde_json::to_value(&config.processes[0]).unwrap();
+        assert_eq!(value["restart_policy"], "Always");
+        assert!(value.get("auto_restart").is_none());
+        assert!(!RestartPolicy::OnFailure.restarts_after(Some(0)));
+        assert!(RestartPolicy::OnFailure.restarts_after(Some(1)));
+    }
+
+    #[test]
+    fn moves_stay_within_the_process_group() {
+        let mut config: AppConfig = serde_json::from_str(
+            r#"{ "stack_name": "Dev", "processes": [
+                { "id": "web", "name": "Web", "command": "npm run dev", "group": "Frontend" },
+                { "id": "db", "name": "DB", "command": "postgres" },
+                { "id": "docs", "name": "Docs", "command": "mdbook serve", "group": " Frontend " }
+            ] }"#,
+        )
+        .expect("config should parse");
+        config.normalize();
+
+        assert_eq!(config.processes[1].group_name(), DEFAULT_GROUP_NAME);
+        assert_eq!(config.processes[2].group.as_deref(), Some("Frontend"));
+        assert!(config.move_process_up("docs"));
+        let order: Vec<&str> = config.processes.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(order, vec!["docs", "db", "web"]);
+        assert!(!config.move_process_up("db"));
+        assert!(!config.move_process_down("web"));
+    }
+
     #[test]
     fn missing_startup_delay_defaults_to_zero_and_serializes() {
         let raw = r#"{
@@ -514,8 +1741,17 @@ mod tests {
         config.normalize();
 
         assert_eq!(config.processes[0].startup_delay_seconds, 0);
+        assert_eq!(config.processes[0].max_restart_attempts, 0);
+        assert_eq!(
+            config.processes[0].stop_timeout_secs,
+            DEFAULT_STOP_TIMEOUT_SECONDS
+        );
         let value = serde_json::to_value(&config).expect("config should serialize");
         assert_eq!(value["processes"][0]["startup_delay_seconds"], 0);
+        assert_eq!(
+            value["processes"][0]["stop_timeout_secs"],
+            DEFAULT_STOP_TIMEOUT_SECONDS
+        );
     }
 
     #[test]
@@ -531,6 +1767,10 @@ mod tests {
         process.scheduled_run.hour = 99;
         process.scheduled_run.interval_hours = 0;
         process.log_rotation_count = 0;
+        process.env = vec![
+            ("  NODE_ENV ".to_string(), " development mode ".to_string()),
+            ("   ".to_string(), "ignored".to_string()),
+        ];
 
         process.normalize();
 
@@ -539,5 +1779,49 @@ mod tests {
         assert_eq!(process.scheduled_run.hour, 23);
         assert_eq!(process.scheduled_run.interval_hours, 1);
         assert_eq!(process.log_rotation_count, DEFAULT_LOG_ROTATION_COUNT);
+        assert_eq!(
+            process.env,
+            vec![("NODE_ENV".to_string(), "development mode".to_string())]
+        );
+    }
+
+    #[test]
+    fn edited_json_text_reports_the_bad_line_and_duplicate_ids() {
+        let mut config = AppConfig::default();
+        config.processes.push(ProcessConfig::new(
+            "API".to_string(),
+            "cargo run".to_string(),
+            String::new(),
+            ProcessType::Process,
+        ));
+        config.normalize();
+        let text = config.to_json_text().unwrap();
+        assert_eq!(
+            AppConfig::from_json_text(&text).unwrap().processes,
+            config.processes
+        );
+
+        let broken = text.replacen("\"name\": \"API\",", "\"name\": API,", 1);
+        let err = AppConfig::from_json_text(&broken).unwrap_err();
+        assert!(err.ends_with("\n\"name\": API,"), "{}", err);
+
+        let mut duplicated = config.clone();
+        duplicated.processes.push(config.processes[0].clone());
+        let err = AppConfig::from_json_text(&duplicated.to_json_text().unwrap()).unwrap_err();
+        assert!(err.contains("used by more than one entry"));
+
+        let without_id = r#"{ "processes": [{ "name": "Web", "command": "npm start" }] }"#;
+        let parsed = AppConfig::from_json_text(without_id).unwrap();
+        assert!(!parsed.processes[0].id.is_empty());
+    }
+
+    #[test]
+    fn edited_json_text_rejects_duplicate_names() {
+        let text = r#"{ "processes": [
+            { "name": "Web", "command": "npm start" },
+            { "name": " web ", "command": "npm run dev" }
+        ] }"#;
+        let err = AppConfig::from_json_text(text).unwrap_err();
+        assert_eq!(err, "Process name 'web' is used by more than one entry.");
     }
 }
@@ -0,0 +1,164 @@
+//! Windowless front end for `--headless`: runs the configured stack and streams every
+//! process's output to stdout, prefixed with its name, until Ctrl+C.
+
+use std::collections::HashMap;
+use std::io::{IsTerminal, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::ansi::strip_ansi;
+use crate::config::{AppConfig, ProcessType};
+use crate::process_manager::ProcessManager;
+use crate::rest_api::RestServerController;
+use crate::ui::DOCKER_EXIT_STOP_TIMEOUT;
+
+const LOG_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Whether `--headless` was passed on the command line.
+pub fn requested(args: impl IntoIterator<Item = String>) -> bool {
+    args.into_iter().any(|arg| arg == "--headless")
+}
+
+/// Start the `auto_start` entries (and the local API, if enabled), print their output, and
+/// shut down like the window does on close once Ctrl+C arrives.
+pub fn run() -> Result<(), String> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("Failed to start the async runtime: {}", e))?;
+
+    let (config, load_warning) = AppConfig::load();
+    if let Some(warning) = load_warning {
+        eprintln!("[WARN] {}", warning);
+    }
+    let manager = Arc::new(ProcessManager::new());
+    if let Err(err) = manager.apply_settings(&config) {
+        eprintln!("[WARN] Using default log highlighting: {}", err);
+    }
+    manager.init_from_config(&config.processes);
+    manager.start_background_tasks();
+
+    let rest_controller = Arc::new(RestServerController::new(manager.clone()));
+    {
+        let _guard = runtime.enter();
+        rest_controller.apply_config(config.stack_name.clone(), config.remote_control.clone());
+    }
+    manager.start_auto_start_processes();
+
+    let auto_start_count = config
+        .processes
+        .iter()
+        .filter(|process| process.auto_start)
+        .count();
+    println!(
+        "{}: starting {} auto-start entries of {}. Press Ctrl+C to stop.",
+        config.stack_name,
+        auto_start_count,
+        config.processes.len()
+    );
+
+    let mut printer = LogPrinter::new(&config);
+    runtime.block_on(async {
+        let mut ctrl_c = std::pin::pin!(tokio::signal::ctrl_c());
+        loop {
+            printer.print_new_lines(&manager);
+            tokio::select! {
+                _ = &mut ctrl_c => break,
+                _ = tokio::time::sleep(LOG_POLL_INTERVAL) => {}
+            }
+        }
+    });
+
+    println!("Stopping...");
+    rest_controller.shutdown();
+    if config.stop_docker_on_exit {
+        let pending = manager.stop_docker_entries(DOCKER_EXIT_STOP_TIMEOUT);
+        if !pending.is_empty() {
+            eprintln!(
+                "[WARN] Containers still stopping at exit: {}",
+                pending.join(", ")
+            );
+        }
+    }
+    // Give processes their graceful stop before the force-kill in `stop_non_docker`.
+    let ids: Vec<String> = config
+        .processes
+        .iter()
+        .filter(|process| process.process_type == ProcessType::Process)
+        .map(|process| process.id.clone())
+        .collect();
+    for id in &ids {
+        manager.stop_process(id);
+    }
+    if !manager.wait_for_processes_to_stop(&ids, manager.stop_wait_timeout(&ids)) {
+        eprintln!("[WARN] Some processes did not stop in time and were killed.");
+    }
+    manager.stop_non_docker();
+    printer.print_new_lines(&manager);
+    Ok(())
+}
+
+/// Follows every process's log buffer and writes new settled lines as `name | line`.
+struct LogPrinter {
+    /// Process id and display name, in config order.
+    processes: Vec<(String, String)>,
+    /// Buffer id and next unprinted sequence number per process.
+    cursors: HashMap<String, (u64, u64)>,
+    name_width: usize,
+    /// Keep ANSI colors for terminals, strip them when stdout is a file or pipe.
+    strip_colors: bool,
+}
+
+impl LogPrinter {
+    fn new(config: &AppConfig) -> Self {
+        let processes: Vec<(String, String)> = config
+            .processes
+            .iter()
+            .map(|process| (process.id.clone(), process.name.clone()))
+            .collect();
+        let name_width = processes
+            .iter()
+            .map(|(_, name)| name.chars().count())
+            .max()
+            .unwrap_or(0);
+        Self {
+            processes,
+            cursors: HashMap::new(),
+            name_width,
+            strip_colors: !std::io::stdout().is_terminal(),
+        }
+    }
+
+    /// A line still being redrawn by `\r` is held back until it settles, so progress bars
+    /// print once instead of once per poll.
+    fn print_new_lines(&mut self, manager: &ProcessManager) {
+        let stdout = std::io::stdout();
+        let mut out = stdout.lock();
+        for (id, name) in &self.processes {
+            let cursor = self.cursors.get(id).copied();
+            let Some(delta) = manager.get_logs_since(id, cursor.map_or(0, |(_, next)| next)) else {
+                continue;
+            };
+            let start = match cursor {
+                Some((buffer_id, next))
+                    if buffer_id == delta.buffer_id && next >= delta.first_sequence =>
+                {
+                    next
+                }
+                _ => delta.first_sequence,
+            };
+            let settled = delta.next_sequence.saturating_sub(start) as usize;
+            for entry in delta.entries.iter().take(settled) {
+                let line = if self.strip_colors {
+                    strip_ansi(&entry.text)
+                } else {
+                    entry.text.clone()
+                };
+                let _ = writeln!(out, "{:width$} | {}", name, line, width = self.name_width);
+            }
+            self.cursors
+                .insert(id.clone(), (delta.buffer_id, delta.next_sequence));
+        }
+        let _ = out.flush();
+    }
+}
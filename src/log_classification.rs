@@ -1,3 +1,171 @@
+use regex::{Regex, RegexSet};
+
+use crate::config::LogHighlightRules;
+
+/// Highlight rules compiled once per settings change. The default is the built-in keywords.
+#[derive(Debug)]
+pub(crate) struct CompiledHighlightRules {
+    builtin_keywords: bool,
+    error: RegexSet,
+    warning: RegexSet,
+    colors: Vec<(Regex, [u8; 3])>,
+}
+
+impl Default for CompiledHighlightRules {
+    fn default() -> Self {
+        Self {
+            builtin_keywords: true,
+            error: RegexSet::empty(),
+            warning: RegexSet::empty(),
+            colors: Vec::new(),
+        }
+    }
+}
+
+impl CompiledHighlightRules {
+    pub(crate) fn compile(rules: &LogHighlightRules) -> Result<Self, String> {
+        let error = RegexSet::new(&rules.error_patterns)
+            .map_err(|err| format!("Invalid error pattern: {}", err))?;
+        let warning = RegexSet::new(&rules.warning_patterns)
+            .map_err(|err| format!("Invalid warning pattern: {}", err))?;
+        let colors = rules
+            .color_rules
+            .iter()
+            .map(|rule| {
+                let regex = Regex::new(&rule.pattern)
+                    .map_err(|err| format!("Invalid line color pattern: {}", err))?;
+                let color = parse_hex_color(&rule.color).ok_or_else(|| {
+                    format!(
+                        "Invalid line color '{}' for '{}'. Use #RRGGBB.",
+                        rule.color, rule.pattern
+                    )
+                })?;
+                Ok((regex, color))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(Self {
+            builtin_keywords: rules.builtin_keywords,
+            error,
+            warning,
+            colors,
+        })
+    }
+
+    fn line_color(&self, content: &str) -> Option<[u8; 3]> {
+        self.colors
+            .iter()
+            .find(|(regex, _)| regex.is_match(content))
+            .map(|(_, color)| *color)
+    }
+
+    fn is_error(&self, content: &str) -> bool {
+        (self.builtin_keywords && contains_error_indicator(content)) || self.error.is_match(content)
+    }
+
+    fn is_warning(&self, content: &str) -> bool {
+        (self.builtin_keywords && content.to_ascii_lowercase().contains("warn"))
+            || self.warning.is_match(content)
+    }
+}
+
+/// Coarse category of a runtime log line, shared by the renderer and log filters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LogLineKind {
+    System,
+    Error,
+    Warning,
+    Stderr,
+    Stdout,
+}
+
+pub(crate) fn classify_log_line(line: &str, rules: &CompiledHighlightRules) -> LogLineKind {
+    let trimmed = strip_log_timestamp(line.trim());
+    if trimmed.starts_with('[') && trimmed.ends_with(']') {
+        return LogLineKind::System;
+    }
+
+    let content = strip_stderr_prefix(trimmed);
+    if rules.is_error(content) {
+        return LogLineKind::Error;
+    }
+
+    if rules.is_warning(content) {
+        return LogLineKind::Warning;
+    }
+
+    if is_stderr_line(trimmed) {
+        LogLineKind::Stderr
+    } else {
+        LogLineKind::Stdout
+    }
+}
+
+/// Color of the first custom line color rule matching `line`, if any.
+pub(crate) fn custom_line_color(line: &str, rules: &CompiledHighlightRules) -> Option<[u8; 3]> {
+    rules.line_color(strip_log_timestamp(line.trim()))
+}
+
+/// Parse `#RRGGBB` or `#RGB` into RGB bytes.
+pub(crate) fn parse_hex_color(text: &str) -> Option<[u8; 3]> {
+    let hex = text.trim().strip_prefix('#')?;
+    if !hex.is_ascii() {
+        return None;
+    }
+    let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+    match hex.len() {
+        6 => Some([
+            channel(&hex[0..2])?,
+            channel(&hex[2..4])?,
+            channel(&hex[4..6])?,
+        ]),
+        3 => {
+            let mut rgb = [0; 3];
+            for (index, digit) in hex.chars().enumerate() {
+                rgb[index] = channel(&digit.to_string())? * 17;
+            }
+            Some(rgb)
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn line_has_error(line: &str, rules: &CompiledHighlightRules) -> bool {
+    rules.is_error(strip_stderr_prefix(strip_log_timestamp(line.trim())))
+}
+
+pub(crate) fn is_stderr_line(line: &str) -> bool {
+    strip_log_timestamp(line.trim_start()).starts_with("[stderr]")
+}
+
+/// Drop a leading `[HH:MM:SS.mmm] ` arrival timestamp, if present.
+pub(crate) fn strip_log_timestamp(line: &str) -> &str {
+    let bytes = line.as_bytes();
+    let is_timestamp = bytes.len() >= 15
+        && bytes[0] == b'['
+        && bytes[13] == b']'
+        && bytes[14] == b' '
+        && bytes[1..13]
+            .iter()
+            .enumerate()
+            .all(|(index, byte)| match index {
+                2 | 5 => *byte == b':',
+                8 => *byte == b'.',
+                _ => byte.is_ascii_digit(),
+            });
+
+    if is_timestamp {
+        &line[15..]
+    } else {
+        line
+    }
+}
+
+fn strip_stderr_prefix(line: &str) -> &str {
+    line.strip_prefix("[stderr]")
+        .map(str::trim_start)
+        .unwrap_or(line)
+}
+
 pub(crate) fn contains_error_indicator(content: &str) -> bool {
     let lower = content.to_ascii_lowercase();
 
@@ -23,7 +191,11 @@ fn contains_singular_error(lower: &str) -> bool {
             search_start = end;
             continue;
         }
-        if is_token_start(lower, start) && is_token_end(lower, end) {
+        if is_token_start(lower, start)
+            && is_token_end(lower, end)
+            && !is_compound_word(lower, end)
+            && !follows_negation(lower, start)
+        {
             return true;
         }
         search_start = end;
@@ -38,6 +210,8 @@ fn contains_plural_errors_indicator(lower: &str) -> bool {
         let end = start + "errors".len();
         if is_token_start(lower, start)
             && is_token_end(lower, end)
+            && !is_compound_word(lower, end)
+            && !follows_negation(lower, start)
             && plural_errors_is_significant(&lower[end..])
         {
             return true;
@@ -65,6 +239,24 @@ fn plural_errors_is_significant(rest: &str) -> bool {
         || value.starts_with("null"))
 }
 
+/// `error-reporting`, `errors-total`: a hyphenated name rather than a report of a failure.
+fn is_compound_word(lower: &str, end: usize) -> bool {
+    lower[end..]
+        .strip_prefix('-')
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_alphanumeric())
+}
+
+/// `0 errors`, `no errors`, `without error`: the line says nothing went wrong.
+fn follows_negation(lower: &str, start: usize) -> bool {
+    let previous_word = lower[..start]
+        .trim_end()
+        .rsplit(|c: char| c.is_whitespace() || c == '(' || c == ',')
+        .next()
+        .unwrap_or("");
+    matches!(previous_word, "0" | "no" | "zero" | "without")
+}
+
 fn contains_token(lower: &str, token: &str) -> bool {
     let mut search_start = 0;
     while let Some(relative) = lower[search_start..].find(token) {
@@ -94,7 +286,11 @@ fn is_token_end(value: &str, index: usize) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::contains_error_indicator;
+    use super::{
+        classify_log_line, contains_error_indicator, parse_hex_color, CompiledHighlightRules,
+        LogLineKind,
+    };
+    use crate::config::{LogColorRule, LogHighlightRules};
 
     #[test]
     fn ignores_empty_error_aggregate_fields() {
@@ -108,10 +304,104 @@ mod tests {
         assert!(!contains_error_indicator("INFO report error_count: 0"));
     }
 
+    #[test]
+    fn ignores_negated_counts_and_hyphenated_names() {
+        assert!(!contains_error_indicator(
+            "Compiled successfully with 0 errors"
+        ));
+        assert!(!contains_error_indicator("webpack: no errors, 2 warnings"));
+        assert!(!contains_error_indicator("finished without error"));
+        assert!(!contains_error_indicator(
+            "GET https://example.com/error-reporting 200"
+        ));
+        assert!(contains_error_indicator("Found 3 errors"));
+        assert!(contains_error_indicator("error: no such file"));
+    }
+
     #[test]
     fn detects_real_error_indicators() {
         assert!(contains_error_indicator("ERROR failed to bind port"));
         assert!(contains_error_indicator("worker fatal exception"));
         assert!(contains_error_indicator("report errors: ['failed row']"));
     }
+
+    #[test]
+    fn classifies_log_line_kinds() {
+        let rules = CompiledHighlightRules::default();
+        assert_eq!(
+            classify_log_line("[Process started]", &rules),
+            LogLineKind::System
+        );
+        assert_eq!(
+            classify_log_line("[stderr] ERROR bind failed", &rules),
+            LogLineKind::Error
+        );
+        assert_eq!(
+            classify_log_line("WARN slow request", &rules),
+            LogLineKind::Warning
+        );
+        assert_eq!(
+            classify_log_line("[stderr] compiling", &rules),
+            LogLineKind::Stderr
+        );
+        assert_eq!(classify_log_line("listening", &rules), LogLineKind::Stdout);
+        assert_eq!(
+            classify_log_line("[14:03:22.145] [stderr] compiling", &rules),
+            LogLineKind::Stderr
+        );
+        assert_eq!(
+            classify_log_line("[14:03:22.145] [stdout reader error: closed]", &rules),
+            LogLineKind::System
+        );
+    }
+
+    #[test]
+    fn custom_patterns_extend_or_replace_builtin_keywords() {
+        let rules = CompiledHighlightRules::compile(&LogHighlightRules {
+            builtin_keywords: false,
+            error_patterns: vec!["level=(error|fatal)".to_string()],
+            warning_patterns: vec![r"^WARNING\b".to_string()],
+            color_rules: Vec::new(),
+        })
+        .unwrap();
+        assert!(rules.is_error("ts=1 level=error msg=boom"));
+        assert!(!rules.is_error("ERROR plain keyword"));
+        assert!(rules.is_warning("WARNING: disk almost full"));
+        assert!(!rules.is_warning("warn lowercase"));
+
+        let invalid = LogHighlightRules {
+            error_patterns: vec!["(".to_string()],
+            ..LogHighlightRules::default()
+        };
+        assert!(CompiledHighlightRules::compile(&invalid).is_err());
+    }
+
+    #[test]
+    fn first_matching_color_rule_wins() {
+        let rule = |pattern: &str, color: &str| LogColorRule {
+            pattern: pattern.to_string(),
+            color: color.to_string(),
+        };
+        let rules = CompiledHighlightRules::compile(&LogHighlightRules {
+            color_rules: vec![rule(r"\b500\b", "#ef4444"), rule("GET /api", "#2c5")],
+            ..LogHighlightRules::default()
+        })
+        .unwrap();
+        assert_eq!(
+            rules.line_color("GET /api/users 200"),
+            Some([0x22, 0xcc, 0x55])
+        );
+        assert_eq!(
+            rules.line_color("GET /api/users 500"),
+            Some([0xef, 0x44, 0x44])
+        );
+        assert_eq!(rules.line_color("POST /login 200"), None);
+
+        assert_eq!(parse_hex_color("#GG0000"), None);
+        let invalid = LogHighlightRules {
+            color_rules: vec![rule("GET", "green")],
+            ..LogHighlightRules::default()
+        };
+        assert!(CompiledHighlightRules::compile(&invalid).is_err());
+    }
 }
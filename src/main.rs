@@ -1,11 +1,30 @@
 #![cfg_attr(target_os = "windows", windows_subsystem = "windows")]
 
+mod ansi;
 mod config;
+mod headless;
 mod log_classification;
 mod process_manager;
 mod rest_api;
 mod ui;
 
 fn main() -> eframe::Result<()> {
+    let override_path = config::config_path_from_args(std::env::args().skip(1)).or_else(|| {
+        std::env::var_os("PM_CONFIG")
+            .filter(|value| !value.is_empty())
+            .map(std::path::PathBuf::from)
+    });
+    if let Some(path) = override_path {
+        config::set_config_path_override(path);
+    }
+
+    if headless::requested(std::env::args().skip(1)) {
+        if let Err(err) = headless::run() {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     ui::run()
 }
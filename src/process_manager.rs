@@ -1,31 +1,113 @@
 //! Process management logic for starting, stopping, and monitoring processes.
 
-use std::collections::HashMap;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
 use std::fs::{self, File, OpenOptions};
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::net::{TcpStream, ToSocketAddrs};
 use std::path::{Path, PathBuf};
-use std::process::{Child, Command, Stdio};
+use std::process::{Child, Command, ExitStatus, Stdio};
 use std::sync::{
-    atomic::{AtomicBool, AtomicU64, Ordering},
-    Arc, Mutex,
+    atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    Arc, Mutex, RwLock,
 };
 use std::thread;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
 use chrono::{Datelike, Timelike};
 use serde::Serialize;
 use tokio::sync::watch;
 
-use crate::config::{ProcessConfig, ProcessType};
-use crate::log_classification::contains_error_indicator;
+use crate::ansi::strip_ansi;
+use crate::config::{
+    AppConfig, LogHighlightRules, ProcessConfig, ProcessType, RestartPolicy,
+    DEFAULT_DOCKER_POLL_INTERVAL_MS, DEFAULT_MAX_LOG_LINES, DEFAULT_MAX_OUTPUT_LINES_PER_SECOND,
+    DEFAULT_STOP_TIMEOUT_SECONDS,
+};
+use crate::log_classification::{
+    classify_log_line, custom_line_color, is_stderr_line, line_has_error, CompiledHighlightRules,
+    LogLineKind,
+};
 
-const IN_MEMORY_LOG_LIMIT: usize = 1000;
+const RESTORED_LOG_LINE_LIMIT: usize = 200;
+/// Most bytes read back from the end of a previous session's log, however long its lines are.
+const RESTORED_LOG_BYTE_LIMIT: u64 = 1024 * 1024;
 const PROCESS_LOG_FOLDER_NAME: &str = "Process Manager logs";
 const RESOURCE_SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
 const CPU_TIME_UNITS_PER_SECOND: f64 = 10_000_000.0;
+const STOP_SETTLE_MARGIN: Duration = Duration::from_secs(2);
+const MANAGED_RESTART_BASE_DELAY: Duration = Duration::from_secs(1);
+const MANAGED_RESTART_MAX_DELAY: Duration = Duration::from_secs(30);
+const MANAGED_RESTART_STABLE_UPTIME: Duration = Duration::from_secs(30);
+const DEPENDENCY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const HEALTH_CHECK_TICK: Duration = Duration::from_secs(1);
+const DOCKER_PROBE_INTERVAL: Duration = Duration::from_secs(5);
+const PORT_CHECK_CONNECT_TIMEOUT: Duration = Duration::from_millis(200);
+const PORT_RELEASE_TIMEOUT: Duration = Duration::from_secs(2);
+const DOCKER_POLL_TICK: Duration = Duration::from_millis(250);
+const DOCKER_POLL_MIN_INTERVAL: Duration = Duration::from_millis(250);
+const DOCKER_POLL_FAST_INTERVAL: Duration = Duration::from_millis(500);
+const DOCKER_POLL_BOOST_WINDOW: Duration = Duration::from_secs(10);
+const DOCKER_POLL_IDLE_AFTER: Duration = Duration::from_secs(60);
+const DOCKER_POLL_IDLE_FACTOR: u32 = 4;
+const DOCKER_LOG_REATTACH_BASE_DELAY: Duration = Duration::from_secs(1);
+const DOCKER_LOG_REATTACH_MAX_DELAY: Duration = Duration::from_secs(30);
+const MAX_LIFECYCLE_EVENTS: usize = 1000;
+const START_RETRY_DELAY: Duration = Duration::from_secs(1);
+/// Total matching lines `search_all_logs` returns across all processes.
+pub const LOG_SEARCH_RESULT_LIMIT: usize = 500;
+const LOG_EVENT_FLUSH_INTERVAL: Duration = Duration::from_millis(25);
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+/// Folder names watch mode never scans, so build output and installs cannot loop restarts.
+const WATCH_IGNORED_DIRS: [&str; 3] = ["node_modules", "target", ".git"];
 
 type SharedLogFile = Arc<Mutex<File>>;
 
+type SharedLogSettings = Arc<LogSettings>;
+
+/// Log handling a manager shares with its entries' log buffers and output readers.
+#[derive(Debug)]
+struct LogSettings {
+    /// Whether captured output lines get a local arrival-time prefix. Mirrors
+    /// `AppConfig::show_timestamps`.
+    show_timestamps: AtomicBool,
+    /// In-memory log lines kept per process. Mirrors `AppConfig::max_log_lines`.
+    max_lines: AtomicUsize,
+    /// Output lines per second a process may log; 0 means unlimited. Mirrors
+    /// `AppConfig::max_output_lines_per_second`.
+    max_output_lines_per_second: AtomicUsize,
+    /// Rules that classify and color buffered lines. Mirrors `AppConfig::log_highlighting`.
+    highlight_rules: RwLock<Arc<CompiledHighlightRules>>,
+    /// Set by log readers instead of notifying per line; flushed as one change event per tick.
+    event_pending: AtomicBool,
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self {
+            show_timestamps: AtomicBool::new(false),
+            max_lines: AtomicUsize::new(DEFAULT_MAX_LOG_LINES),
+            max_output_lines_per_second: AtomicUsize::new(DEFAULT_MAX_OUTPUT_LINES_PER_SECOND),
+            highlight_rules: RwLock::new(Arc::default()),
+            event_pending: AtomicBool::new(false),
+        }
+    }
+}
+
+impl LogSettings {
+    fn max_lines(&self) -> usize {
+        self.max_lines.load(Ordering::Relaxed)
+    }
+
+    fn max_output_lines_per_second(&self) -> usize {
+        self.max_output_lines_per_second.load(Ordering::Relaxed)
+    }
+
+    fn highlight_rules(&self) -> Arc<CompiledHighlightRules> {
+        self.highlight_rules.read().unwrap().clone()
+    }
+}
+
 /// Status of a managed process
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ProcessStatus {
@@ -48,17 +130,166 @@ impl std::fmt::Display for ProcessStatus {
     }
 }
 
+/// Signals the process header offers for running process entries.
+#[cfg(unix)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSignal {
+    Hup,
+    Term,
+    Int,
+    Usr1,
+}
+
+#[cfg(unix)]
+impl ProcessSignal {
+    pub const ALL: [Self; 4] = [Self::Hup, Self::Term, Self::Int, Self::Usr1];
+
+    fn number(self) -> libc::c_int {
+        match self {
+            Self::Hup => libc::SIGHUP,
+            Self::Term => libc::SIGTERM,
+            Self::Int => libc::SIGINT,
+            Self::Usr1 => libc::SIGUSR1,
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Hup => "Reload configuration (most daemons)",
+            Self::Term => "Ask the process to exit",
+            Self::Int => "Interrupt, like Ctrl+C",
+            Self::Usr1 => "Application-defined, often log reopening",
+        }
+    }
+}
+
+#[cfg(unix)]
+impl std::fmt::Display for ProcessSignal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hup => write!(f, "SIGHUP"),
+            Self::Term => write!(f, "SIGTERM"),
+            Self::Int => write!(f, "SIGINT"),
+            Self::Usr1 => write!(f, "SIGUSR1"),
+        }
+    }
+}
+
+/// Result of the optional HTTP health check. `Unknown` until a running instance is probed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum HealthStatus {
+    #[default]
+    Unknown,
+    Healthy,
+    Unhealthy,
+}
+
+/// Whether the Docker daemon answered the last probe. `Unknown` while the stack has no
+/// container entries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum DockerStatus {
+    #[default]
+    Unknown,
+    Available,
+    Unavailable,
+}
+
+/// What the manager did to, or observed about, a process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleEventKind {
+    Starting,
+    Started,
+    StopRequested,
+    Stopped,
+    /// The process exited on its own, with its exit code when one was reported.
+    Exited(Option<i32>),
+    RestartScheduled {
+        attempt: u32,
+    },
+    Failed(String),
+}
+
+impl std::fmt::Display for LifecycleEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LifecycleEventKind::Starting => write!(f, "starting"),
+            LifecycleEventKind::Started => write!(f, "started"),
+            LifecycleEventKind::StopRequested => write!(f, "stop requested"),
+            LifecycleEventKind::Stopped => write!(f, "stopped"),
+            LifecycleEventKind::Exited(Some(code)) => write!(f, "exited({})", code),
+            LifecycleEventKind::Exited(None) => write!(f, "exited"),
+            LifecycleEventKind::RestartScheduled { attempt } => {
+                write!(f, "restart (attempt {})", attempt)
+            }
+            LifecycleEventKind::Failed(message) => write!(f, "error: {}", message),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LifecycleEvent {
+    pub at: chrono::DateTime<chrono::Local>,
+    pub process_id: String,
+    pub process_name: String,
+    pub kind: LifecycleEventKind,
+}
+
+/// Ring buffer of lifecycle events shared by every process in a manager, so the
+/// interleaving across the whole stack is preserved.
+#[derive(Debug, Clone, Default)]
+struct LifecycleLog(Arc<Mutex<VecDeque<LifecycleEvent>>>);
+
+impl LifecycleLog {
+    fn push(&self, event: LifecycleEvent) {
+        let mut events = self.0.lock().unwrap();
+        events.push_back(event);
+        while events.len() > MAX_LIFECYCLE_EVENTS {
+            events.pop_front();
+        }
+    }
+
+    fn snapshot(&self) -> Vec<LifecycleEvent> {
+        self.0.lock().unwrap().iter().cloned().collect()
+    }
+
+    fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+}
+
 /// Runtime state for a single process
 pub struct ProcessState {
     pub config: ProcessConfig,
     pub status: ProcessStatus,
-    pub logs: Vec<String>,
+    pub logs: LogBuffer,
     pub disk_log: Option<SharedLogFile>,
+    /// Disk log text of the open output line, written once something settles it.
+    open_disk_line: Option<String>,
     pub child: Option<Child>,
     pub resource_usage: ProcessResourceUsage,
     pub suppress_restart_once: bool,
+    /// Consecutive managed restarts since the process last stayed up long enough.
+    pub restart_attempts: u32,
+    /// When the current instance reached Running.
+    pub started_at: Option<Instant>,
+    /// Managed restarts since the last manual start.
+    pub restart_count: u32,
+    /// PID of the spawned child while it is alive.
+    pub pid: Option<u32>,
+    /// Exit code of the last unexpected exit; signals map to 128 + signal number.
+    pub last_exit_code: Option<i32>,
+    pub health: HealthStatus,
+    health_checked_at: Option<Instant>,
+    health_probe_in_flight: bool,
+    /// Failed health checks in a row for the current instance.
+    health_failures: u32,
+    /// Set when the health watchdog killed the process, so its exit reads as a watchdog restart.
+    unhealthy_restart: bool,
     resource_sample: Option<ResourceSample>,
     start_generation: u64,
+    lifecycle: LifecycleLog,
+    /// Shared with the manager, see `ProcessManager::set_managed_restarts_paused`.
+    managed_restarts_paused: Arc<AtomicBool>,
     #[cfg(windows)]
     pub job: Option<JobHandle>,
 }
@@ -68,17 +299,245 @@ impl ProcessState {
         Self {
             config,
             status: ProcessStatus::Stopped,
-            logs: Vec::new(),
+            logs: LogBuffer::new(),
             disk_log: None,
+            open_disk_line: None,
             child: None,
             resource_usage: ProcessResourceUsage::default(),
             suppress_restart_once: false,
+            restart_attempts: 0,
+            started_at: None,
+            restart_count: 0,
+            pid: None,
+            last_exit_code: None,
+            health: HealthStatus::Unknown,
+            health_checked_at: None,
+            health_probe_in_flight: false,
+            health_failures: 0,
+            unhealthy_restart: false,
             resource_sample: None,
             start_generation: 0,
+            lifecycle: LifecycleLog::default(),
+            managed_restarts_paused: Arc::new(AtomicBool::new(false)),
             #[cfg(windows)]
             job: None,
         }
     }
+
+    /// Change the status, noting the transition in the lifecycle log.
+    pub fn set_status(&mut self, status: ProcessStatus) {
+        if self.status == status {
+            return;
+        }
+        let kind = match &status {
+            ProcessStatus::Stopped => LifecycleEventKind::Stopped,
+            ProcessStatus::Running => LifecycleEventKind::Started,
+            ProcessStatus::Starting => LifecycleEventKind::Starting,
+            ProcessStatus::Stopping => LifecycleEventKind::StopRequested,
+            ProcessStatus::Error(message) => LifecycleEventKind::Failed(message.clone()),
+        };
+        self.status = status;
+        self.record_lifecycle(kind);
+    }
+
+    fn record_lifecycle(&self, kind: LifecycleEventKind) {
+        self.lifecycle.push(LifecycleEvent {
+            at: chrono::Local::now(),
+            process_id: self.config.id.clone(),
+            process_name: self.config.name.clone(),
+            kind,
+        });
+    }
+}
+
+static NEXT_LOG_BUFFER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Where a buffered log line came from. Output keeps its `[stderr]` text prefix for disk logs
+/// and API readers; views filter on the source instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSource {
+    Stdout,
+    Stderr,
+    /// Lines the manager writes itself, such as `[Started with PID 42]`.
+    Manager,
+}
+
+/// One buffered log line, classified once when it arrives instead of on every render.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub text: String,
+    pub source: LogSource,
+    /// When the line was received.
+    pub at: SystemTime,
+    pub kind: LogLineKind,
+    /// Color from the first matching line color rule.
+    pub color: Option<[u8; 3]>,
+}
+
+impl LogEntry {
+    fn new(source: LogSource, text: String, rules: &CompiledHighlightRules) -> Self {
+        let mut entry = Self {
+            text,
+            source,
+            at: SystemTime::now(),
+            kind: LogLineKind::Stdout,
+            color: None,
+        };
+        entry.classify(rules);
+        entry
+    }
+
+    fn classify(&mut self, rules: &CompiledHighlightRules) {
+        let plain = strip_ansi(&self.text);
+        self.kind = classify_log_line(&plain, rules);
+        self.color = custom_line_color(&plain, rules);
+    }
+}
+
+/// In-memory output for one process. Lines carry a sequence number that survives trimming,
+/// so readers can fetch only what was appended since their last look.
+#[derive(Debug)]
+pub struct LogBuffer {
+    id: u64,
+    lines: VecDeque<LogEntry>,
+    next_sequence: u64,
+    /// Set while the newest line ended with a lone `\r`; holds whether it came from stderr.
+    open_line: Option<bool>,
+    /// The owning manager's settings; a standalone buffer gets the defaults.
+    settings: SharedLogSettings,
+}
+
+/// A line from `ProcessManager::combined_logs`, tagged with the process that wrote it.
+#[derive(Debug, Clone)]
+pub struct CombinedLogEntry {
+    pub process_id: String,
+    pub process_name: String,
+    pub entry: LogEntry,
+}
+
+/// Lines appended since a cursor, as returned by `ProcessManager::get_logs_since`.
+#[derive(Debug, Clone, Default)]
+pub struct LogDelta {
+    /// Identifies the buffer. A different id means the reader has to start over.
+    pub buffer_id: u64,
+    /// Sequence number of the oldest line still buffered.
+    pub first_sequence: u64,
+    /// Cursor to pass on the next call. Stops before a line that can still be redrawn, so the
+    /// next call hands it over again with its current text.
+    pub next_sequence: u64,
+    /// Buffered lines from the cursor on, or the whole buffer if the cursor is out of range.
+    pub entries: Vec<LogEntry>,
+}
+
+impl LogBuffer {
+    fn new() -> Self {
+        Self {
+            id: NEXT_LOG_BUFFER_ID.fetch_add(1, Ordering::Relaxed),
+            lines: VecDeque::new(),
+            next_sequence: 0,
+            open_line: None,
+            settings: SharedLogSettings::default(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    fn iter(&self) -> impl DoubleEndedIterator<Item = &String> {
+        self.lines.iter().map(|entry| &entry.text)
+    }
+
+    /// Add a line written by the manager.
+    fn push_back(&mut self, line: String) {
+        self.push_entry(LogSource::Manager, line);
+    }
+
+    fn push_entry(&mut self, source: LogSource, text: String) {
+        let rules = self.settings.highlight_rules();
+        self.lines.push_back(LogEntry::new(source, text, &rules));
+        self.next_sequence += 1;
+        self.open_line = None;
+    }
+
+    /// Add a line of process output. A line ended by a lone `\r` stays open and the same
+    /// stream's next line replaces it, the way a terminal redraws a progress bar. A blank
+    /// line only closes an open one, so `\r\n` endings leave the text in place.
+    fn push_output(&mut self, line: String, is_stderr: bool, open: bool) {
+        let redraw = self.open_line == Some(is_stderr);
+        if line.is_empty() {
+            if redraw && !open {
+                self.open_line = None;
+            }
+            return;
+        }
+        let source = if is_stderr {
+            LogSource::Stderr
+        } else {
+            LogSource::Stdout
+        };
+        match self.lines.back_mut() {
+            Some(last) if redraw => {
+                *last = LogEntry::new(source, line, &self.settings.highlight_rules())
+            }
+            _ => self.push_entry(source, line),
+        }
+        self.open_line = open.then_some(is_stderr);
+    }
+
+    /// Drop every line. Sequence numbers keep counting so readers notice the gap.
+    fn clear(&mut self) {
+        self.lines.clear();
+        self.open_line = None;
+    }
+
+    fn trim(&mut self, max_lines: usize) {
+        while self.lines.len() > max_lines {
+            self.lines.pop_front();
+        }
+    }
+
+    fn tail(&self, limit: usize) -> impl Iterator<Item = &String> {
+        self.lines
+            .range(self.lines.len().saturating_sub(limit)..)
+            .map(|entry| &entry.text)
+    }
+
+    fn first_sequence(&self) -> u64 {
+        self.next_sequence - self.lines.len() as u64
+    }
+
+    fn since(&self, cursor: u64) -> LogDelta {
+        let first_sequence = self.first_sequence();
+        let start = if (first_sequence..=self.next_sequence).contains(&cursor) {
+            cursor
+        } else {
+            first_sequence
+        };
+        let next_sequence = if self.open_line.is_some() && !self.lines.is_empty() {
+            self.next_sequence - 1
+        } else {
+            self.next_sequence
+        };
+        LogDelta {
+            buffer_id: self.id,
+            first_sequence,
+            next_sequence,
+            entries: self
+                .lines
+                .range((start - first_sequence) as usize..)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Classify every line again, after the highlight rules changed.
+    fn reclassify(&mut self) {
+        let rules = self.settings.highlight_rules();
+        for entry in &mut self.lines {
+            entry.classify(&rules);
+        }
+    }
 }
 
 /// Serializable runtime snapshot for REST responses and other external consumers.
@@ -92,9 +551,15 @@ pub struct ProcessRuntimeSnapshot {
     pub pid: Option<u32>,
     pub cpu_percent: Option<f32>,
     pub memory_bytes: Option<u64>,
+    pub restart_count: u32,
+    pub uptime_seconds: Option<u64>,
+    pub last_exit_code: Option<i32>,
+    /// Set only for entries with an enabled health check.
+    pub health: Option<HealthStatus>,
     pub auto_start: bool,
     pub startup_delay_seconds: u64,
     pub auto_restart: bool,
+    pub restart_policy: RestartPolicy,
     pub respond_to_start_all: bool,
     pub respond_to_stop_all: bool,
     pub respond_to_restart_all: bool,
@@ -135,8 +600,30 @@ pub struct UiRuntimeSnapshot {
     pub counts: ProcessCounts,
     pub statuses: HashMap<String, ProcessStatus>,
     pub resource_usage: HashMap<String, ProcessResourceUsage>,
-    pub selected_logs: Vec<String>,
-    pub selected_log_count: usize,
+    /// Health of entries with an enabled health check.
+    pub health: HashMap<String, HealthStatus>,
+    /// When each running entry last started.
+    pub started_at: HashMap<String, Instant>,
+    /// Latest non-blank log line per entry, without ANSI codes. Only filled on request.
+    pub last_log_lines: HashMap<String, String>,
+    pub docker: DockerStatus,
+}
+
+/// Restart count and uptime origin for one process.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcessRuntimeInfo {
+    pub restart_count: u32,
+    /// Set only while the process is running.
+    pub started_at: Option<Instant>,
+    /// Set only while the process is running.
+    pub pid: Option<u32>,
+    pub last_exit_code: Option<i32>,
+}
+
+impl ProcessRuntimeInfo {
+    pub fn uptime(&self) -> Option<Duration> {
+        self.started_at.map(|started_at| started_at.elapsed())
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -155,7 +642,22 @@ pub struct ProcessManager {
     process_error_versions: Arc<Mutex<HashMap<String, u64>>>,
     background_started: AtomicBool,
     has_docker_entries: Arc<AtomicBool>,
+    docker_status: Arc<Mutex<DockerStatus>>,
     schedule_state: Arc<Mutex<HashMap<String, ProcessScheduleState>>>,
+    /// Sidebar order of process ids; stack-wide actions walk entries in this order.
+    process_order: Mutex<Vec<String>>,
+    /// Pause between launches during Start All. Mirrors `AppConfig::start_all_delay_ms`.
+    start_all_delay_ms: AtomicU64,
+    /// Mirrors `AppConfig::docker_poll_interval_ms`.
+    docker_poll_interval_ms: Arc<AtomicU64>,
+    /// Set while the window is minimized and unfocused; Docker status polling is skipped.
+    docker_polling_paused: Arc<AtomicBool>,
+    lifecycle: LifecycleLog,
+    log_settings: SharedLogSettings,
+    /// Holds every managed restart without touching the entries' saved policies.
+    managed_restarts_paused: Arc<AtomicBool>,
+    /// Bumped once buffered lines have been reclassified for new highlight rules.
+    log_classification_generation: AtomicU64,
 }
 
 impl Default for ProcessManager {
@@ -176,7 +678,16 @@ impl ProcessManager {
             process_error_versions: Arc::new(Mutex::new(HashMap::new())),
             background_started: AtomicBool::new(false),
             has_docker_entries: Arc::new(AtomicBool::new(false)),
+            docker_status: Arc::new(Mutex::new(DockerStatus::Unknown)),
             schedule_state: Arc::new(Mutex::new(HashMap::new())),
+            process_order: Mutex::new(Vec::new()),
+            start_all_delay_ms: AtomicU64::new(0),
+            docker_poll_interval_ms: Arc::new(AtomicU64::new(DEFAULT_DOCKER_POLL_INTERVAL_MS)),
+            docker_polling_paused: Arc::new(AtomicBool::new(false)),
+            lifecycle: LifecycleLog::default(),
+            log_settings: SharedLogSettings::default(),
+            managed_restarts_paused: Arc::new(AtomicBool::new(false)),
+            log_classification_generation: AtomicU64::new(0),
         }
     }
 
@@ -188,11 +699,124 @@ impl ProcessManager {
         self.process_error_versions.lock().unwrap().clone()
     }
 
+    /// Push the stack-wide settings from `config` into the manager. Invalid highlight rules
+    /// keep the previous rules and are reported after the other settings are applied.
+    pub fn apply_settings(&self, config: &AppConfig) -> Result<(), String> {
+        self.set_log_directory(config.log_directory.clone());
+        self.set_show_timestamps(config.show_timestamps);
+        self.set_max_log_lines(config.max_log_lines);
+        self.set_max_output_lines_per_second(config.max_output_lines_per_second);
+        self.set_start_all_delay_ms(config.start_all_delay_ms);
+        self.set_docker_poll_interval_ms(config.docker_poll_interval_ms);
+        self.set_log_highlighting(&config.log_highlighting)
+    }
+
     pub fn set_log_directory(&self, directory: impl Into<String>) {
         let mut log_directory = self.log_directory.lock().unwrap();
         *log_directory = directory.into();
     }
 
+    pub fn set_show_timestamps(&self, enabled: bool) {
+        self.log_settings
+            .show_timestamps
+            .store(enabled, Ordering::Relaxed);
+    }
+
+    /// Pause or resume managed restarts for every entry. Restarts already waiting out their
+    /// backoff are cancelled while paused, so crashed processes stay down for inspection.
+    pub fn set_managed_restarts_paused(&self, paused: bool) {
+        self.managed_restarts_paused
+            .store(paused, Ordering::Relaxed);
+    }
+
+    pub fn managed_restarts_paused(&self) -> bool {
+        self.managed_restarts_paused.load(Ordering::Relaxed)
+    }
+
+    /// Give every entry the same restart policy. Running processes honor it when they exit.
+    pub fn set_all_restart_policy(&self, policy: RestartPolicy) {
+        let mut processes = self.processes.lock().unwrap();
+        for state in processes.values_mut() {
+            state.config.restart_policy = policy;
+        }
+    }
+
+    /// Swap the rules that decide which log lines count as errors or warnings.
+    /// Invalid patterns keep the previous rules.
+    pub fn set_log_highlighting(&self, rules: &LogHighlightRules) -> Result<(), String> {
+        let compiled = CompiledHighlightRules::compile(rules)?;
+        *self.log_settings.highlight_rules.write().unwrap() = Arc::new(compiled);
+        for state in self.processes.lock().unwrap().values_mut() {
+            state.logs.reclassify();
+        }
+        self.log_classification_generation
+            .fetch_add(1, Ordering::Relaxed);
+        self.notify();
+        Ok(())
+    }
+
+    /// The rules buffered lines are currently classified with.
+    pub(crate) fn highlight_rules(&self) -> Arc<CompiledHighlightRules> {
+        self.log_settings.highlight_rules()
+    }
+
+    /// Changes whenever buffered lines were reclassified; readers caching entries refetch then.
+    pub fn log_classification_generation(&self) -> u64 {
+        self.log_classification_generation.load(Ordering::Relaxed)
+    }
+
+    /// Change how many output lines per second each process may log (0 disables the limit).
+    pub fn set_max_output_lines_per_second(&self, max_lines: usize) {
+        self.log_settings
+            .max_output_lines_per_second
+            .store(max_lines, Ordering::Relaxed);
+    }
+
+    /// Change the per-process log cap, trimming buffers that are already over it.
+    pub fn set_max_log_lines(&self, max_lines: usize) {
+        let max_lines = max_lines.max(1);
+        self.log_settings
+            .max_lines
+            .store(max_lines, Ordering::Relaxed);
+        let mut processes = self.processes.lock().unwrap();
+        for state in processes.values_mut() {
+            state.logs.trim(max_lines);
+        }
+    }
+
+    pub fn set_start_all_delay_ms(&self, delay_ms: u64) {
+        self.start_all_delay_ms.store(delay_ms, Ordering::Relaxed);
+    }
+
+    pub fn set_docker_poll_interval_ms(&self, interval_ms: u64) {
+        self.docker_poll_interval_ms
+            .store(interval_ms, Ordering::Relaxed);
+    }
+
+    /// Skip Docker status polls while nobody can see them; the next poll runs right after resuming.
+    pub fn set_docker_polling_paused(&self, paused: bool) {
+        self.docker_polling_paused.store(paused, Ordering::Relaxed);
+    }
+
+    /// Lifecycle events across all processes, oldest first.
+    pub fn lifecycle_events(&self) -> Vec<LifecycleEvent> {
+        self.lifecycle.snapshot()
+    }
+
+    pub fn clear_lifecycle_events(&self) {
+        self.lifecycle.clear();
+        self.notify();
+    }
+
+    /// Fresh state wired to this manager's lifecycle log and settings.
+    fn new_state(&self, config: ProcessConfig) -> ProcessState {
+        let mut state = ProcessState::new(config);
+        state.lifecycle = self.lifecycle.clone();
+        state.logs.settings = self.log_settings.clone();
+        state.managed_restarts_paused = self.managed_restarts_paused.clone();
+        state
+    }
+
     fn notify(&self) {
         bump_event(&self.event_tx, &self.event_version);
     }
@@ -204,11 +828,11 @@ impl ProcessManager {
     fn update_docker_polling_flag_locked(&self, processes: &HashMap<String, ProcessState>) {
         let has_docker = processes
             .values()
-            .any(|state| state.config.process_type == ProcessType::Docker);
+            .any(|state| state.config.process_type.is_container());
         self.has_docker_entries.store(has_docker, Ordering::Relaxed);
     }
 
-    pub fn start_background_tasks(&self) {
+    pub fn start_background_tasks(self: &Arc<Self>) {
         if self.background_started.swap(true, Ordering::SeqCst) {
             return;
         }
@@ -219,36 +843,67 @@ impl ProcessManager {
         let error_version = self.error_version.clone();
         let process_error_versions = self.process_error_versions.clone();
         let has_docker_entries = self.has_docker_entries.clone();
+        let docker_status = self.docker_status.clone();
         let log_directory = self.log_directory.clone();
         let schedule_state = self.schedule_state.clone();
+        let docker_poll_interval_ms = self.docker_poll_interval_ms.clone();
+        let docker_polling_paused = self.docker_polling_paused.clone();
 
-        thread::spawn(move || loop {
-            if !has_docker_entries.load(Ordering::Relaxed) {
-                thread::sleep(std::time::Duration::from_secs(2));
-                continue;
-            }
+        // Ticks quickly but only polls once `docker_poll_delay` has passed; any status change
+        // on a container entry (including a start or stop request) counts as fresh activity.
+        thread::spawn(move || {
+            let mut last_statuses: HashMap<String, ProcessStatus> = HashMap::new();
+            let mut last_activity = Instant::now();
+            let mut last_poll: Option<Instant> = None;
+            loop {
+                if !has_docker_entries.load(Ordering::Relaxed) {
+                    thread::sleep(std::time::Duration::from_secs(2));
+                    continue;
+                }
 
-            thread::sleep(std::time::Duration::from_millis(750));
+                thread::sleep(DOCKER_POLL_TICK);
 
-            let docker_ids: Vec<String> = {
-                let processes = processes.lock().unwrap();
-                processes
-                    .iter()
-                    .filter(|(_, s)| s.config.process_type == ProcessType::Docker)
-                    .map(|(id, _)| id.clone())
-                    .collect()
-            };
+                if docker_polling_paused.load(Ordering::Relaxed) {
+                    last_poll = None;
+                    continue;
+                }
 
-            for id in docker_ids {
-                refresh_docker_status_inner(
-                    &id,
-                    &processes,
-                    &log_directory,
-                    &event_tx,
-                    &event_version,
-                    &error_version,
-                    &process_error_versions,
-                );
+                let statuses: HashMap<String, ProcessStatus> = {
+                    let processes = processes.lock().unwrap();
+                    processes
+                        .iter()
+                        .filter(|(_, s)| s.config.process_type.is_container())
+                        .map(|(id, s)| (id.clone(), s.status.clone()))
+                        .collect()
+                };
+                if statuses != last_statuses {
+                    last_activity = Instant::now();
+                    last_statuses = statuses;
+                }
+
+                let base = Duration::from_millis(docker_poll_interval_ms.load(Ordering::Relaxed));
+                let delay = docker_poll_delay(base, last_activity.elapsed());
+                if last_poll.is_some_and(|polled_at| polled_at.elapsed() < delay) {
+                    continue;
+                }
+                last_poll = Some(Instant::now());
+
+                // A down daemon makes every container look stopped; keep the last known state instead.
+                if *docker_status.lock().unwrap() == DockerStatus::Unavailable {
+                    continue;
+                }
+
+                for id in last_statuses.keys() {
+                    refresh_docker_status_inner(
+                        id,
+                        &processes,
+                        &log_directory,
+                        &event_tx,
+                        &event_version,
+                        &error_version,
+                        &process_error_versions,
+                    );
+                }
             }
         });
 
@@ -278,59 +933,177 @@ impl ProcessManager {
 
         thread::spawn(move || loop {
             thread::sleep(RESOURCE_SAMPLE_INTERVAL);
-            if refresh_resource_usage(&processes) {
+            let mut updated = refresh_resource_usage(&processes);
+            updated |= refresh_docker_resource_usage(&processes);
+            if updated {
+                bump_event(&event_tx, &event_version);
+            }
+        });
+
+        let processes = self.processes.clone();
+        let event_tx = self.event_tx.clone();
+        let event_version = self.event_version.clone();
+
+        thread::spawn(move || loop {
+            thread::sleep(HEALTH_CHECK_TICK);
+            run_health_checks(&processes, &event_tx, &event_version);
+        });
+
+        let event_tx = self.event_tx.clone();
+        let event_version = self.event_version.clone();
+        let log_settings = self.log_settings.clone();
+
+        thread::spawn(move || loop {
+            thread::sleep(LOG_EVENT_FLUSH_INTERVAL);
+            if log_settings.event_pending.swap(false, Ordering::Relaxed) {
+                bump_event(&event_tx, &event_version);
+            }
+        });
+
+        // Holds a weak handle so the watcher never keeps the manager alive on its own.
+        let manager = Arc::downgrade(self);
+        thread::spawn(move || {
+            let mut watches = HashMap::new();
+            loop {
+                thread::sleep(WATCH_POLL_INTERVAL);
+                let Some(manager) = manager.upgrade() else {
+                    return;
+                };
+                for id in manager.poll_watch_paths(&mut watches) {
+                    manager.restart_process(&id);
+                }
+            }
+        });
+
+        let has_docker_entries = self.has_docker_entries.clone();
+        let docker_status = self.docker_status.clone();
+        let event_tx = self.event_tx.clone();
+        let event_version = self.event_version.clone();
+
+        thread::spawn(move || loop {
+            let next = if has_docker_entries.load(Ordering::Relaxed) {
+                probe_docker_daemon()
+            } else {
+                DockerStatus::Unknown
+            };
+            let changed = {
+                let mut current = docker_status.lock().unwrap();
+                std::mem::replace(&mut *current, next) != next
+            };
+            if changed {
                 bump_event(&event_tx, &event_version);
             }
+            thread::sleep(if next == DockerStatus::Unknown {
+                Duration::from_secs(2)
+            } else {
+                DOCKER_PROBE_INTERVAL
+            });
         });
     }
 
+    /// Result of the latest Docker daemon probe.
+    pub fn docker_status(&self) -> DockerStatus {
+        *self.docker_status.lock().unwrap()
+    }
+
     /// Initialize process states from config
     pub fn init_from_config(&self, configs: &[ProcessConfig]) {
+        let log_directory = self.log_directory.lock().unwrap().clone();
         let mut processes = self.processes.lock().unwrap();
         let mut process_error_versions = self.process_error_versions.lock().unwrap();
         let mut schedule_state = self.schedule_state.lock().unwrap();
         for config in configs {
             if !processes.contains_key(&config.id) {
-                processes.insert(config.id.clone(), ProcessState::new(config.clone()));
+                let mut state = self.new_state(config.clone());
+                if config.log_to_disk {
+                    restore_previous_session_logs(&mut state, &log_directory);
+                }
+                processes.insert(config.id.clone(), state);
             }
             process_error_versions.entry(config.id.clone()).or_insert(0);
             schedule_state.entry(config.id.clone()).or_default();
         }
         process_error_versions.retain(|id, _| processes.contains_key(id));
         schedule_state.retain(|id, _| processes.contains_key(id));
+        *self.process_order.lock().unwrap() =
+            configs.iter().map(|config| config.id.clone()).collect();
         self.update_docker_polling_flag_locked(&processes);
     }
 
     /// Reload all process states from a new config set.
     pub fn reload_from_config(&self, configs: &[ProcessConfig]) {
         self.stop_all_forced();
+        self.replace_states(configs);
+    }
+
+    /// Bring the managed set in line with `configs` without restarting anything: entries no
+    /// longer listed are stopped and removed, new ones are added stopped, and the rest keep
+    /// their runtime state with the new config applied on their next start.
+    pub fn sync_with_config(&self, configs: &[ProcessConfig]) {
+        let removed: Vec<String> = self
+            .processes
+            .lock()
+            .unwrap()
+            .keys()
+            .filter(|id| !configs.iter().any(|config| &config.id == *id))
+            .cloned()
+            .collect();
+        for id in &removed {
+            self.remove_process(id);
+        }
+        for config in configs {
+            if !self.update_process_config(config.clone()) {
+                self.add_process(config.clone());
+            }
+        }
+        self.set_process_order(configs.iter().map(|config| config.id.clone()).collect());
+    }
+
+    /// Swap in another stack profile, then start its auto-start entries. The previous
+    /// profile's local processes are killed from a background thread first; containers are
+    /// left running, as on app shutdown.
+    pub fn switch_stack(self: &Arc<Self>, configs: &[ProcessConfig]) {
+        let mut previous = self.replace_states(configs);
+        let manager = Arc::clone(self);
+        thread::spawn(move || {
+            for state in previous.values_mut() {
+                force_stop_local_process(state);
+            }
+            manager.start_auto_start_processes();
+        });
+    }
 
+    /// Install fresh states for `configs`, returning the ones they replace.
+    fn replace_states(&self, configs: &[ProcessConfig]) -> HashMap<String, ProcessState> {
         let mut processes = self.processes.lock().unwrap();
         let mut process_error_versions = self.process_error_versions.lock().unwrap();
         let mut schedule_state = self.schedule_state.lock().unwrap();
 
-        processes.clear();
+        let previous = std::mem::take(&mut *processes);
         process_error_versions.clear();
         schedule_state.clear();
 
         for config in configs {
             let process_id = config.id.clone();
-            processes.insert(process_id.clone(), ProcessState::new(config.clone()));
+            processes.insert(process_id.clone(), self.new_state(config.clone()));
             process_error_versions
                 .entry(process_id.clone())
                 .or_insert(0);
             schedule_state.entry(process_id).or_default();
         }
+        *self.process_order.lock().unwrap() =
+            configs.iter().map(|config| config.id.clone()).collect();
 
         self.update_docker_polling_flag_locked(&processes);
         self.notify();
+        previous
     }
 
     /// Add a new process
     pub fn add_process(&self, config: ProcessConfig) {
         let mut processes = self.processes.lock().unwrap();
         let process_id = config.id.clone();
-        processes.insert(process_id.clone(), ProcessState::new(config));
+        processes.insert(process_id.clone(), self.new_state(config));
         self.process_error_versions
             .lock()
             .unwrap()
@@ -339,8 +1112,9 @@ impl ProcessManager {
         self.schedule_state
             .lock()
             .unwrap()
-            .entry(process_id)
+            .entry(process_id.clone())
             .or_default();
+        self.process_order.lock().unwrap().push(process_id);
         self.update_docker_polling_flag_locked(&processes);
         self.notify();
     }
@@ -363,6 +1137,10 @@ impl ProcessManager {
         self.stop_process(id);
         let mut processes = self.processes.lock().unwrap();
         processes.remove(id);
+        self.process_order
+            .lock()
+            .unwrap()
+            .retain(|ordered_id| ordered_id != id);
         self.process_error_versions.lock().unwrap().remove(id);
         self.schedule_state.lock().unwrap().remove(id);
         self.update_docker_polling_flag_locked(&processes);
@@ -380,31 +1158,10 @@ impl ProcessManager {
         let process_error_versions = self.process_error_versions.clone();
 
         // Get config and update status
-        let (config, start_generation) = {
-            let mut processes = processes_arc.lock().unwrap();
-            println!("[DEBUG] Got lock, looking for process id: {}", id);
-            if let Some(state) = processes.get_mut(id) {
-                println!(
-                    "[DEBUG] Found process: {}, status: {:?}",
-                    state.config.name, state.status
-                );
-                if !process_is_dormant(state) || state.status == ProcessStatus::Starting {
-                    println!("[DEBUG] Already running or starting, returning");
-                    return; // Already running
-                }
-                state.suppress_restart_once = false;
-                state.status = ProcessStatus::Starting;
-                state.logs.clear();
-                state.disk_log = None;
-                let _ = clear_resource_usage(state);
-                state.start_generation = state.start_generation.wrapping_add(1);
-                let start_generation = state.start_generation;
-                bump_event(&event_tx, &event_version);
-                (state.config.clone(), start_generation)
-            } else {
-                println!("[DEBUG] Process not found in manager!");
-                return;
-            }
+        let Some((config, start_generation)) =
+            begin_process_start(id, &processes_arc, &event_tx, &event_version)
+        else {
+            return;
         };
 
         let id_owned = id.to_string();
@@ -457,28 +1214,70 @@ impl ProcessManager {
             println!("[DEBUG] Thread spawned for command: {}", command);
             println!("[DEBUG] Working dir: '{}'", working_dir);
 
-            let (program, args) = match parse_command(&command) {
-                Ok((program, args)) => (program, args),
-                Err(e) => {
-                    let mut processes = processes_arc.lock().unwrap();
-                    if let Some(state) = processes.get_mut(&id_owned) {
-                        state.status = ProcessStatus::Error(e.clone());
+            if let Some(port) = config_clone.expected_port {
+                check_expected_port(
+                    &id_owned,
+                    port,
+                    config_clone.free_port_before_start,
+                    &processes_arc,
+                );
+            }
+
+            if let Err(e) = run_hook(&id_owned, ProcessHook::PreStart, &processes_arc) {
+                let mut processes = processes_arc.lock().unwrap();
+                if let Some(state) = processes.get_mut(&id_owned) {
+                    if start_request_is_current_state(state, start_generation) {
+                        state.set_status(ProcessStatus::Error(e));
                         let _ = clear_resource_usage(state);
-                        log_process_state_event(state, format!("[Failed to start: {}]", e));
                     }
-                    bump_error(&error_version, &process_error_versions, &id_owned);
-                    bump_event(&event_tx, &event_version);
-                    return;
                 }
-            };
+                drop(processes);
+                bump_error(&error_version, &process_error_versions, &id_owned);
+                bump_event(&event_tx, &event_version);
+                return;
+            }
+            // The hook can take a while; a stop or another start may have happened meanwhile.
+            if !config_clone.pre_start.is_empty()
+                && !start_request_is_current(&id_owned, start_generation, &processes_arc)
+            {
+                return;
+            }
+
+            let (process_env, env_file_error) = process_env_vars(&config_clone);
+            if let Some(err) = env_file_error {
+                let mut processes = processes_arc.lock().unwrap();
+                if let Some(state) = processes.get_mut(&id_owned) {
+                    log_process_state_event(
+                        state,
+                        format!("[Warning: {}. Continuing without it.]", err),
+                    );
+                }
+            }
 
-            // Build command (direct spawn; on Windows, .cmd/.bat are routed through cmd)
-            let (mut cmd, program_label) = match build_command(&program, &args, &working_dir) {
+            // Build command (direct spawn; on Windows, .cmd/.bat are routed through cmd).
+            // Shell mode hands the whole command line to the platform shell unparsed.
+            let built =
+                expand_config_placeholders(&config_clone, &process_env).and_then(|expanded| {
+                    let working_dir = &expanded.working_directory;
+                    check_working_directory(working_dir)
+                        .and_then(|()| check_run_as(&expanded.run_as))
+                        .and_then(|()| {
+                            if expanded.use_shell {
+                                Ok(build_shell_command(&expanded.command_line()))
+                            } else {
+                                program_and_args(&expanded).and_then(|(program, args)| {
+                                    build_command(&program, &args, working_dir)
+                                })
+                            }
+                        })
+                        .map(|(cmd, label)| (cmd, label, working_dir.clone()))
+                });
+            let (mut cmd, program_label, working_dir) = match built {
                 Ok(result) => result,
                 Err(e) => {
                     let mut processes = processes_arc.lock().unwrap();
                     if let Some(state) = processes.get_mut(&id_owned) {
-                        state.status = ProcessStatus::Error(e.clone());
+                        state.set_status(ProcessStatus::Error(e.clone()));
                         let _ = clear_resource_usage(state);
                         log_process_state_event(state, format!("[Failed to start: {}]", e));
                     }
@@ -488,17 +1287,34 @@ impl ProcessManager {
                 }
             };
 
+            if config_clone.inherit_env {
+                // Explicitly inherit the parent process's environment variables.
+                // This is critical on Windows when using CREATE_NO_WINDOW, as the
+                // spawned process may otherwise receive an incomplete PATH that
+                // doesn't include user-specific directories (e.g., where npm lives).
+                cmd.envs(std::env::vars());
+            } else {
+                cmd.env_clear();
+                cmd.envs(minimal_env_vars());
+            }
+
+            cmd.envs(process_env.iter().map(|(key, value)| (key, value)));
+
+            #[cfg(unix)]
+            if !config_clone.run_as.is_empty() {
+                cmd = wrap_run_as(
+                    &cmd,
+                    &config_clone.run_as,
+                    &process_env,
+                    config_clone.inherit_env,
+                );
+            }
+
             if !working_dir.is_empty() {
                 println!("[DEBUG] Setting current_dir to: {}", working_dir);
                 cmd.current_dir(&working_dir);
             }
 
-            // Explicitly inherit the parent process's environment variables.
-            // This is critical on Windows when using CREATE_NO_WINDOW, as the
-            // spawned process may otherwise receive an incomplete PATH that
-            // doesn't include user-specific directories (e.g., where npm lives).
-            cmd.envs(std::env::vars());
-
             cmd.stdout(Stdio::piped());
             cmd.stderr(Stdio::piped());
 
@@ -509,8 +1325,36 @@ impl ProcessManager {
                 cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
             }
 
+            // Own process group on Unix, so stopping reaches children of wrappers like npm or sh.
+            #[cfg(unix)]
+            {
+                use std::os::unix::process::CommandExt;
+                cmd.process_group(0);
+            }
+
             println!("[DEBUG] About to spawn command...");
-            match cmd.spawn() {
+            let mut retries = 0;
+            let spawned = loop {
+                match cmd.spawn() {
+                    Err(e) if retries < config_clone.start_retries => {
+                        retries += 1;
+                        if !wait_to_retry_start(
+                            &id_owned,
+                            start_generation,
+                            &e.to_string(),
+                            retries,
+                            config_clone.start_retries,
+                            &processes_arc,
+                            &event_tx,
+                            &event_version,
+                        ) {
+                            return;
+                        }
+                    }
+                    result => break result,
+                }
+            };
+            match spawned {
                 Ok(mut child) => {
                     #[cfg(windows)]
                     let mut job = match create_job() {
@@ -555,7 +1399,10 @@ impl ProcessManager {
                     {
                         let mut processes = processes_arc.lock().unwrap();
                         if let Some(state) = processes.get_mut(&id_owned) {
-                            state.status = ProcessStatus::Running;
+                            state.set_status(ProcessStatus::Running);
+                            state.started_at = Some(Instant::now());
+                            state.pid = Some(child.id());
+                            state.last_exit_code = None;
                             state.disk_log = disk_log.clone();
                             push_in_memory_log(
                                 &mut state.logs,
@@ -578,12 +1425,17 @@ impl ProcessManager {
                     }
                     bump_event(&event_tx, &event_version);
 
+                    let output_limiter: SharedRateLimiter =
+                        Arc::new(Mutex::new(OutputRateLimiter::new(Instant::now())));
+                    let log_settings = log_settings_for(&processes_arc, &id_owned);
+
                     // Stream stdout in background
                     if let Some(stdout) = stdout {
                         let processes_clone = processes_arc.clone();
                         let id_clone = id_owned.clone();
-                        let event_tx = event_tx.clone();
-                        let event_version = event_version.clone();
+                        let limiter = output_limiter.clone();
+                        let log_settings = log_settings.clone();
+                        let disk_log = disk_log.clone();
                         let error_version = error_version.clone();
                         let process_error_versions = process_error_versions.clone();
                         thread::spawn(move || {
@@ -591,7 +1443,7 @@ impl ProcessManager {
                             let mut buffer = Vec::new();
                             loop {
                                 buffer.clear();
-                                let read = match reader.read_until(b'\n', &mut buffer) {
+                                let read = match read_output_line(&mut reader, &mut buffer) {
                                     Ok(read) => read,
                                     Err(err) => {
                                         let (updated, has_error) = append_runtime_log(
@@ -608,7 +1460,7 @@ impl ProcessManager {
                                                     &id_clone,
                                                 );
                                             }
-                                            bump_event(&event_tx, &event_version);
+                                            mark_log_event(&log_settings);
                                         }
                                         break;
                                     }
@@ -616,15 +1468,29 @@ impl ProcessManager {
                                 if read == 0 {
                                     break;
                                 }
+                                let open = buffer.last() == Some(&b'\r');
                                 while matches!(buffer.last(), Some(b'\n' | b'\r')) {
                                     buffer.pop();
                                 }
-                                let line = String::from_utf8_lossy(&buffer).into_owned();
-                                if line.is_empty() {
+                                if !admit_output_line(
+                                    &processes_clone,
+                                    &id_clone,
+                                    &log_settings,
+                                    &limiter,
+                                    disk_log.as_ref(),
+                                    &buffer,
+                                    false,
+                                ) {
                                     continue;
                                 }
-                                let (updated, has_error) =
-                                    append_runtime_log(&processes_clone, &id_clone, line, false);
+                                let line = String::from_utf8_lossy(&buffer).into_owned();
+                                let (updated, has_error) = append_output_line(
+                                    &processes_clone,
+                                    &id_clone,
+                                    line,
+                                    false,
+                                    open,
+                                );
                                 if updated {
                                     if has_error {
                                         bump_error(
@@ -633,9 +1499,15 @@ impl ProcessManager {
                                             &id_clone,
                                         );
                                     }
-                                    bump_event(&event_tx, &event_version);
+                                    mark_log_event(&log_settings);
                                 }
                             }
+                            flush_suppressed_output(
+                                &processes_clone,
+                                &id_clone,
+                                &log_settings,
+                                &limiter,
+                            );
                         });
                     }
 
@@ -643,8 +1515,9 @@ impl ProcessManager {
                     if let Some(stderr) = stderr {
                         let processes_clone = processes_arc.clone();
                         let id_clone = id_owned.clone();
-                        let event_tx = event_tx.clone();
-                        let event_version = event_version.clone();
+                        let limiter = output_limiter;
+                        let log_settings = log_settings.clone();
+                        let disk_log = disk_log.clone();
                         let error_version = error_version.clone();
                         let process_error_versions = process_error_versions.clone();
                         thread::spawn(move || {
@@ -652,7 +1525,7 @@ impl ProcessManager {
                             let mut buffer = Vec::new();
                             loop {
                                 buffer.clear();
-                                let read = match reader.read_until(b'\n', &mut buffer) {
+                                let read = match read_output_line(&mut reader, &mut buffer) {
                                     Ok(read) => read,
                                     Err(err) => {
                                         let (updated, has_error) = append_runtime_log(
@@ -669,7 +1542,7 @@ impl ProcessManager {
                                                     &id_clone,
                                                 );
                                             }
-                                            bump_event(&event_tx, &event_version);
+                                            mark_log_event(&log_settings);
                                         }
                                         break;
                                     }
@@ -677,15 +1550,29 @@ impl ProcessManager {
                                 if read == 0 {
                                     break;
                                 }
+                                let open = buffer.last() == Some(&b'\r');
                                 while matches!(buffer.last(), Some(b'\n' | b'\r')) {
                                     buffer.pop();
                                 }
-                                let line = String::from_utf8_lossy(&buffer).into_owned();
-                                if line.is_empty() {
+                                if !admit_output_line(
+                                    &processes_clone,
+                                    &id_clone,
+                                    &log_settings,
+                                    &limiter,
+                                    disk_log.as_ref(),
+                                    &buffer,
+                                    true,
+                                ) {
                                     continue;
                                 }
-                                let (updated, has_error) =
-                                    append_runtime_log(&processes_clone, &id_clone, line, true);
+                                let line = String::from_utf8_lossy(&buffer).into_owned();
+                                let (updated, has_error) = append_output_line(
+                                    &processes_clone,
+                                    &id_clone,
+                                    line,
+                                    true,
+                                    open,
+                                );
                                 if updated {
                                     if has_error {
                                         bump_error(
@@ -694,9 +1581,15 @@ impl ProcessManager {
                                             &id_clone,
                                         );
                                     }
-                                    bump_event(&event_tx, &event_version);
+                                    mark_log_event(&log_settings);
                                 }
                             }
+                            flush_suppressed_output(
+                                &processes_clone,
+                                &id_clone,
+                                &log_settings,
+                                &limiter,
+                            );
                         });
                     }
 
@@ -712,8 +1605,9 @@ impl ProcessManager {
                             thread::sleep(std::time::Duration::from_millis(500));
                             let mut updated = false;
                             let mut had_error = false;
-                            let mut should_schedule_restart = false;
+                            let mut restart_plan = None;
                             let mut should_break = false;
+                            let mut exited = false;
                             {
                                 let mut processes = processes_monitor.lock().unwrap();
                                 if let Some(state) = processes.get_mut(&id_monitor) {
@@ -724,20 +1618,49 @@ impl ProcessManager {
                                                     state,
                                                     format!("[Process exited with: {}]", status),
                                                 );
-                                                if state.config.auto_restart
-                                                    && managed_restart_active_now(&state.config)
+                                                state.last_exit_code = exit_code(status);
+                                                if !state.suppress_restart_once {
+                                                    state.record_lifecycle(
+                                                        LifecycleEventKind::Exited(
+                                                            state.last_exit_code,
+                                                        ),
+                                                    );
+                                                }
+                                                let watchdog_kill =
+                                                    std::mem::take(&mut state.unhealthy_restart);
+                                                if (watchdog_kill
+                                                    || state
+                                                        .config
+                                                        .restart_policy
+                                                        .restarts_after(state.last_exit_code))
+                                                    && managed_restart_active_now(state)
                                                     && !state.suppress_restart_once
                                                 {
-                                                    log_process_state_event(
+                                                    restart_plan = plan_managed_restart(
                                                         state,
-                                                        "[Managed process went down. Restarting...]"
-                                                            .to_string(),
+                                                        if watchdog_kill {
+                                                            "Health watchdog restarted the unresponsive process."
+                                                        } else {
+                                                            "Managed process went down."
+                                                        },
                                                     );
-                                                    state.status = ProcessStatus::Starting;
-                                                    should_schedule_restart = true;
+                                                    had_error = restart_plan.is_none();
+                                                } else if state.suppress_restart_once {
+                                                    state.set_status(ProcessStatus::Stopped);
                                                 } else {
-                                                    state.status = ProcessStatus::Stopped;
+                                                    // Nobody asked it to stop: a non-zero code is a crash.
+                                                    state.set_status(
+                                                        exit_status_after_unrequested_exit(
+                                                            state.last_exit_code,
+                                                        ),
+                                                    );
+                                                    had_error = matches!(
+                                                        state.status,
+                                                        ProcessStatus::Error(_)
+                                                    );
                                                 }
+                                                state.started_at = None;
+                                                state.pid = None;
                                                 state.suppress_restart_once = false;
                                                 state.child = None;
                                                 state.disk_log = None;
@@ -748,12 +1671,17 @@ impl ProcessManager {
                                                 }
                                                 updated = true;
                                                 should_break = true;
+                                                exited = true;
                                             }
                                             Ok(None) => {
                                                 // Still running
                                             }
                                             Err(e) => {
-                                                state.status = ProcessStatus::Error(e.to_string());
+                                                state.set_status(ProcessStatus::Error(
+                                                    e.to_string(),
+                                                ));
+                                                state.started_at = None;
+                                                state.pid = None;
                                                 state.child = None;
                                                 state.disk_log = None;
                                                 let _ = clear_resource_usage(state);
@@ -783,9 +1711,18 @@ impl ProcessManager {
                                 }
                                 bump_event(&event_tx, &event_version);
                             }
-                            if should_schedule_restart {
+                            if exited {
+                                let _ = run_hook(
+                                    &id_monitor,
+                                    ProcessHook::PostStop,
+                                    &processes_monitor,
+                                );
+                            }
+                            if let Some((delay, restart_generation)) = restart_plan {
                                 schedule_managed_restart(
                                     id_monitor.clone(),
+                                    delay,
+                                    restart_generation,
                                     processes_monitor.clone(),
                                     log_directory.clone(),
                                     event_tx.clone(),
@@ -803,7 +1740,7 @@ impl ProcessManager {
                 Err(e) => {
                     let mut processes = processes_arc.lock().unwrap();
                     if let Some(state) = processes.get_mut(&id_owned) {
-                        state.status = ProcessStatus::Error(e.to_string());
+                        state.set_status(ProcessStatus::Error(e.to_string()));
                         let _ = clear_resource_usage(state);
                         log_process_state_event(state, format!("[Failed to start: {}]", e));
                     }
@@ -826,7 +1763,9 @@ impl ProcessManager {
         process_error_versions: Arc<Mutex<HashMap<String, u64>>>,
     ) {
         let id_owned = id.to_string();
-        let container_name = config.command.clone();
+        let Some(target) = DockerTarget::from_config(config) else {
+            return;
+        };
         let config_clone = config.clone();
 
         thread::spawn(move || {
@@ -841,6 +1780,22 @@ impl ProcessManager {
                 return;
             }
 
+            let ready =
+                create_container_if_missing(&id_owned, &target, &config_clone, &processes_arc)
+                    .and_then(|()| target.check_exists());
+            if let Err(message) = ready {
+                let mut processes = processes_arc.lock().unwrap();
+                if let Some(state) = processes.get_mut(&id_owned) {
+                    state.set_status(ProcessStatus::Error(message.clone()));
+                    let _ = clear_resource_usage(state);
+                    log_process_state_event(state, format!("[Failed to start: {}]", message));
+                }
+                drop(processes);
+                bump_error(&error_version, &process_error_versions, &id_owned);
+                bump_event(&event_tx, &event_version);
+                return;
+            }
+
             let disk_log = if config_clone.log_to_disk {
                 let base_directory = log_directory.lock().unwrap().clone();
                 match create_disk_log_session(&base_directory, &config_clone) {
@@ -857,26 +1812,47 @@ impl ProcessManager {
                 None
             };
 
-            // Start docker container
-            let mut cmd = Command::new("docker");
-            cmd.args(["start", &container_name]);
-
-            #[cfg(windows)]
-            {
-                use std::os::windows::process::CommandExt;
-                cmd.creation_flags(0x08000000);
-            }
-
-            match cmd.output() {
+            // Start docker container or compose service. A daemon that is not answering yet
+            // may just be starting up, so that case gets the entry's start retries.
+            let mut retries = 0;
+            let started = loop {
+                let result = target.start_command().output();
+                let daemon_error = match &result {
+                    Ok(output) if !output.status.success() => {
+                        docker_daemon_error(&String::from_utf8_lossy(&output.stderr))
+                    }
+                    _ => None,
+                };
+                match daemon_error {
+                    Some(message) if retries < config_clone.start_retries => {
+                        retries += 1;
+                        if !wait_to_retry_start(
+                            &id_owned,
+                            start_generation,
+                            &message,
+                            retries,
+                            config_clone.start_retries,
+                            &processes_arc,
+                            &event_tx,
+                            &event_version,
+                        ) {
+                            return;
+                        }
+                    }
+                    _ => break result,
+                }
+            };
+            match started {
                 Ok(output) => {
                     let mut processes = processes_arc.lock().unwrap();
                     if let Some(state) = processes.get_mut(&id_owned) {
                         state.disk_log = disk_log.clone();
                         if output.status.success() {
-                            state.status = ProcessStatus::Running;
+                            state.set_status(ProcessStatus::Running);
+                            state.started_at = Some(Instant::now());
                             log_process_state_event(
                                 state,
-                                format!("[Docker container '{}' started]", container_name),
+                                format!("[{} started]", target.describe()),
                             );
                             if config_clone.log_to_disk && state.disk_log.is_none() {
                                 push_in_memory_log(
@@ -887,11 +1863,13 @@ impl ProcessManager {
                             }
                         } else {
                             let stderr = String::from_utf8_lossy(&output.stderr);
-                            state.status = ProcessStatus::Error(stderr.to_string());
+                            let message =
+                                docker_daemon_error(&stderr).unwrap_or_else(|| stderr.to_string());
+                            state.set_status(ProcessStatus::Error(message.clone()));
                             let _ = clear_resource_usage(state);
                             log_process_state_event(
                                 state,
-                                format!("[Failed to start: {}]", stderr),
+                                format!("[Failed to start: {}]", message),
                             );
                             state.disk_log = None;
                             bump_error(&error_version, &process_error_versions, &id_owned);
@@ -900,11 +1878,15 @@ impl ProcessManager {
                     bump_event(&event_tx, &event_version);
                 }
                 Err(e) => {
+                    let message = docker_spawn_error(&e);
                     let mut processes = processes_arc.lock().unwrap();
                     if let Some(state) = processes.get_mut(&id_owned) {
-                        state.status = ProcessStatus::Error(e.to_string());
+                        state.set_status(ProcessStatus::Error(message.clone()));
                         let _ = clear_resource_usage(state);
-                        log_process_state_event(state, format!("[Failed to start docker: {}]", e));
+                        log_process_state_event(
+                            state,
+                            format!("[Failed to start docker: {}]", message),
+                        );
                         state.disk_log = None;
                     }
                     bump_error(&error_version, &process_error_versions, &id_owned);
@@ -915,117 +1897,194 @@ impl ProcessManager {
             // Start log streaming for docker
             Self::stream_docker_logs(
                 &id_owned,
-                &container_name,
+                &config_clone,
+                start_generation,
                 processes_arc,
-                event_tx,
-                event_version,
                 error_version,
                 process_error_versions,
             );
         });
     }
 
+    /// Follow `docker logs -f` for as long as the entry stays Running in this start generation.
+    /// When the stream ends underneath a running entry (container restart, daemon hiccup) it
+    /// reattaches after a backoff, fetching only output newer than the moment it detached.
     fn stream_docker_logs(
         id: &str,
-        container_name: &str,
+        config: &ProcessConfig,
+        start_generation: u64,
         processes_arc: Arc<Mutex<HashMap<String, ProcessState>>>,
-        event_tx: watch::Sender<u64>,
-        event_version: Arc<AtomicU64>,
         error_version: Arc<AtomicU64>,
         process_error_versions: Arc<Mutex<HashMap<String, u64>>>,
     ) {
         let id_owned = id.to_string();
-        let container = container_name.to_string();
+        let Some(target) = DockerTarget::from_config(config) else {
+            return;
+        };
+        let mut tail = config.docker_tail;
+        let mut since = config.docker_since.clone();
 
         thread::spawn(move || {
-            let mut cmd = Command::new("docker");
-            cmd.args(["logs", "-f", "--tail", "100", &container]);
-            cmd.stdout(Stdio::piped());
-            cmd.stderr(Stdio::piped());
-
-            #[cfg(windows)]
-            {
-                use std::os::windows::process::CommandExt;
-                cmd.creation_flags(0x08000000);
-            }
-
-            if let Ok(mut child) = cmd.spawn() {
-                if let Some(stdout) = child.stdout.take() {
-                    let mut reader = BufReader::new(stdout);
-                    let mut buffer = Vec::new();
-                    loop {
-                        buffer.clear();
-                        let read = match reader.read_until(b'\n', &mut buffer) {
-                            Ok(read) => read,
-                            Err(err) => {
-                                let (updated, has_error) = append_runtime_log(
-                                    &processes_arc,
-                                    &id_owned,
-                                    format!("[docker log reader error: {}]", err),
-                                    true,
-                                );
-                                if updated {
-                                    if has_error {
-                                        bump_error(
-                                            &error_version,
-                                            &process_error_versions,
-                                            &id_owned,
-                                        );
+            let log_settings = log_settings_for(&processes_arc, &id_owned);
+            let mut backoff = DOCKER_LOG_REATTACH_BASE_DELAY;
+            loop {
+                let mut cmd = target.logs_command(tail, &since);
+                cmd.stdout(Stdio::piped());
+                cmd.stderr(Stdio::piped());
+                let mut received_lines = false;
+
+                if let Ok(mut child) = cmd.spawn() {
+                    if let Some(stdout) = child.stdout.take() {
+                        let limiter: SharedRateLimiter =
+                            Arc::new(Mutex::new(OutputRateLimiter::new(Instant::now())));
+                        let disk_log = processes_arc
+                            .lock()
+                            .unwrap()
+                            .get(&id_owned)
+                            .and_then(|state| state.disk_log.clone());
+                        let mut reader = BufReader::new(stdout);
+                        let mut buffer = Vec::new();
+                        loop {
+                            buffer.clear();
+                            let read = match read_output_line(&mut reader, &mut buffer) {
+                                Ok(read) => read,
+                                Err(err) => {
+                                    let (updated, has_error) = append_runtime_log(
+                                        &processes_arc,
+                                        &id_owned,
+                                        format!("[docker log reader error: {}]", err),
+                                        true,
+                                    );
+                                    if updated {
+                                        if has_error {
+                                            bump_error(
+                                                &error_version,
+                                                &process_error_versions,
+                                                &id_owned,
+                                            );
+                                        }
+                                        mark_log_event(&log_settings);
                                     }
-                                    bump_event(&event_tx, &event_version);
+                                    break;
                                 }
+                            };
+                            if read == 0 {
                                 break;
                             }
-                        };
-                        if read == 0 {
-                            break;
-                        }
-                        while matches!(buffer.last(), Some(b'\n' | b'\r')) {
-                            buffer.pop();
-                        }
-                        let line = String::from_utf8_lossy(&buffer).into_owned();
-                        if line.is_empty() {
-                            continue;
-                        }
-                        let mut should_break = false;
-                        let (updated, has_error, disk_log, formatted) = {
-                            let mut updated = false;
-                            let mut has_error = false;
-                            let mut disk_log = None;
-                            let mut formatted = String::new();
-                            let mut processes = processes_arc.lock().unwrap();
-                            if let Some(state) = processes.get_mut(&id_owned) {
-                                if state.status != ProcessStatus::Running {
-                                    should_break = true;
+                            let open = buffer.last() == Some(&b'\r');
+                            while matches!(buffer.last(), Some(b'\n' | b'\r')) {
+                                buffer.pop();
+                            }
+                            if !admit_output_line(
+                                &processes_arc,
+                                &id_owned,
+                                &log_settings,
+                                &limiter,
+                                disk_log.as_ref(),
+                                &buffer,
+                                false,
+                            ) {
+                                continue;
+                            }
+                            let line = String::from_utf8_lossy(&buffer).into_owned();
+                            if line.is_empty() {
+                                // Settles a line left open by `\r`, as in a `\r\n` ending.
+                                let mut processes = processes_arc.lock().unwrap();
+                                if let Some(state) = processes.get_mut(&id_owned) {
+                                    let settled = take_settled_open_line(state, false, true, open);
+                                    state.logs.push_output(line, false, open);
+                                    let disk_log = state.disk_log.clone();
+                                    drop(processes);
+                                    if let (Some(file), Some(text)) = (disk_log, settled) {
+                                        write_disk_log_line(&file, &text);
+                                    }
+                                }
+                                continue;
+                            }
+                            let mut should_break = false;
+                            let (updated, has_error, disk_log, settled, formatted) = {
+                                let mut updated = false;
+                                let mut has_error = false;
+                                let mut disk_log = None;
+                                let mut settled = None;
+                                let mut formatted = None;
+                                let mut processes = processes_arc.lock().unwrap();
+                                if let Some(state) = processes.get_mut(&id_owned) {
+                                    received_lines = true;
+                                    if state.status != ProcessStatus::Running {
+                                        should_break = true;
+                                    } else {
+                                        let text = disk_output_text(&log_settings, &line, false);
+                                        has_error =
+                                            line_has_error(&text, &log_settings.highlight_rules());
+                                        settled = take_settled_open_line(state, false, false, open);
+                                        state.open_disk_line = open.then(|| text.clone());
+                                        formatted = (!open).then_some(text);
+                                        disk_log = state.disk_log.clone();
+                                        push_in_memory_output(
+                                            &mut state.logs,
+                                            with_log_timestamp(
+                                                &log_settings,
+                                                sanitize_runtime_log_line_keep_colors(&line),
+                                            ),
+                                            false,
+                                            open,
+                                        );
+                                        updated = true;
+                                    }
                                 } else {
-                                    formatted = line.clone();
-                                    has_error = line_has_error(&formatted);
-                                    disk_log = state.disk_log.clone();
-                                    push_in_memory_log(&mut state.logs, formatted.clone());
-                                    updated = true;
+                                    should_break = true;
+                                    updated = false;
+                                    has_error = false;
+                                }
+                                (updated, has_error, disk_log, settled, formatted)
+                            };
+                            if let Some(file) = disk_log {
+                                for text in settled.iter().chain(&formatted) {
+                                    write_disk_log_line(&file, text);
                                 }
-                            } else {
-                                should_break = true;
-                                updated = false;
-                                has_error = false;
                             }
-                            (updated, has_error, disk_log, formatted)
-                        };
-                        if let Some(file) = disk_log {
-                            write_disk_log_line(&file, &formatted);
-                        }
-                        if updated {
-                            if has_error {
-                                bump_error(&error_version, &process_error_versions, &id_owned);
+                            if updated {
+                                if has_error {
+                                    bump_error(&error_version, &process_error_versions, &id_owned);
+                                }
+                                mark_log_event(&log_settings);
+                            }
+                            if should_break {
+                                break;
                             }
-                            bump_event(&event_tx, &event_version);
-                        }
-                        if should_break {
-                            break;
                         }
+                        flush_suppressed_output(&processes_arc, &id_owned, &log_settings, &limiter);
                     }
+                    let _ = child.kill();
+                    let _ = child.wait();
                 }
-                let _ = child.kill();
+
+                let detached_at = chrono::Utc::now();
+                if received_lines {
+                    backoff = DOCKER_LOG_REATTACH_BASE_DELAY;
+                }
+                thread::sleep(backoff);
+                backoff = (backoff * 2).min(DOCKER_LOG_REATTACH_MAX_DELAY);
+
+                let mut processes = processes_arc.lock().unwrap();
+                let Some(state) = processes.get_mut(&id_owned) else {
+                    break;
+                };
+                if state.start_generation != start_generation
+                    || state.status != ProcessStatus::Running
+                {
+                    break;
+                }
+                log_process_state_event(
+                    state,
+                    "[Docker log stream ended. Reattaching...]".to_string(),
+                );
+                drop(processes);
+                mark_log_event(&log_settings);
+
+                tail = None;
+                since = detached_at.to_rfc3339_opts(chrono::SecondsFormat::Nanos, true);
             }
         });
     }
@@ -1041,167 +2100,391 @@ impl ProcessManager {
     }
 
     /// Restart a process
-    pub fn restart_process(&self, id: &str) {
-        self.stop_process(id);
-
-        // Wait for process to stop (max 5 seconds)
-        let start = std::time::Instant::now();
-        while start.elapsed().as_secs() < 5 {
-            thread::sleep(std::time::Duration::from_millis(100));
-            if let Some(status) = self.get_status(id) {
-                if status == ProcessStatus::Stopped || matches!(status, ProcessStatus::Error(_)) {
-                    break;
-                }
-            } else {
-                break;
+    ///
+    /// Runs on a background thread: the start only fires once the old instance is Stopped or
+    /// Error (or its stop timeout ran out), so it cannot race a slow shutdown for the port.
+    pub fn restart_process(self: &Arc<Self>, id: &str) {
+        let manager = Arc::clone(self);
+        let ids = vec![id.to_string()];
+        thread::spawn(move || {
+            let timeout = manager.stop_wait_timeout(&ids);
+            manager.stop_process(&ids[0]);
+            if !manager.wait_for_processes_to_stop(&ids, timeout) {
+                println!("[WARN] Restart timeout waiting for stop");
             }
-        }
-
-        self.start_process(id);
+            manager.start_process(&ids[0]);
+        });
     }
 
-    /// Start all processes
-    pub fn start_all(&self) {
-        let ids: Vec<String> = {
-            let processes = self.processes.lock().unwrap();
-            processes
-                .iter()
-                .filter(|(_, state)| state.config.respond_to_start_all)
-                .map(|(id, _)| id.clone())
-                .collect()
-        };
-        for id in ids {
-            self.start_process(&id);
-        }
+    /// Start all processes. The work runs on a background thread and returns immediately;
+    /// state changes reach the UI through the usual change notifications.
+    pub fn start_all(self: &Arc<Self>) {
+        let manager = Arc::clone(self);
+        thread::spawn(move || {
+            let ids = manager.ordered_ids_where(|state| state.config.respond_to_start_all);
+            let stagger = Duration::from_millis(manager.start_all_delay_ms.load(Ordering::Relaxed));
+            manager.start_in_dependency_order(ids, stagger);
+        });
     }
 
     /// Start only processes explicitly marked for auto-start on app launch
     pub fn start_auto_start_processes(&self) {
-        let ids: Vec<String> = {
-            let processes = self.processes.lock().unwrap();
-            processes
-                .iter()
-                .filter(|(_, state)| state.config.auto_start)
-                .map(|(id, _)| id.clone())
-                .collect()
-        };
-
-        for id in ids {
-            self.start_process(&id);
-        }
+        let ids = self.ordered_ids_where(|state| state.config.auto_start);
+        self.start_in_dependency_order(ids, Duration::ZERO);
     }
 
-    /// Stop all processes
-    pub fn stop_all(&self) {
-        let ids: Vec<String> = {
+    /// Start entries dependencies-first. Dependents (and every entry when `stagger` is
+    /// non-zero) are marked Starting right away and launched from a background thread
+    /// once everything they depend on is Running, pausing `stagger` between launches.
+    fn start_in_dependency_order(&self, ids: Vec<String>, stagger: Duration) {
+        let depends_on: HashMap<String, Vec<String>> = {
             let processes = self.processes.lock().unwrap();
-            processes
-                .iter()
-                .filter(|(_, state)| state.config.respond_to_stop_all)
-                .map(|(id, _)| id.clone())
+            ids.iter()
+                .filter_map(|id| {
+                    processes
+                        .get(id)
+                        .map(|state| (id.clone(), state.config.depends_on.clone()))
+                })
                 .collect()
         };
-        for id in ids {
-            self.stop_process(&id);
-        }
-    }
+        let (ordered, blocked) = dependency_start_order(&ids, &depends_on);
 
-    /// Stop all managed processes regardless of stack-control flags.
-    pub fn stop_all_forced(&self) {
-        let ids: Vec<String> = {
-            let processes = self.processes.lock().unwrap();
-            processes.keys().cloned().collect()
-        };
-        for id in &ids {
-            self.stop_process(id);
+        if !blocked.is_empty() {
+            {
+                let mut processes = self.processes.lock().unwrap();
+                let names = blocked
+                    .iter()
+                    .filter_map(|id| processes.get(id))
+                    .map(|state| state.config.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                for id in &blocked {
+                    if let Some(state) = processes.get_mut(id) {
+                        log_process_state_event(
+                            state,
+                            format!("[Error: dependency cycle between {}. Not started.]", names),
+                        );
+                    }
+                }
+            }
+            for id in &blocked {
+                bump_error(&self.error_version, &self.process_error_versions, id);
+            }
+            self.notify();
         }
 
-        let _ = self.wait_for_processes_to_stop(&ids, Duration::from_secs(5));
-    }
-
-    fn wait_for_processes_to_stop(&self, ids: &[String], timeout: Duration) -> bool {
-        let start = Instant::now();
-        loop {
-            let all_stopped = {
+        let mut waiting = Vec::new();
+        for id in ordered {
+            let pending: Vec<String> = {
                 let processes = self.processes.lock().unwrap();
-                ids.iter().all(|id| {
-                    processes.get(id).map_or(true, |state| {
-                        matches!(
-                            state.status,
-                            ProcessStatus::Stopped | ProcessStatus::Error(_)
-                        )
-                    })
-                })
+                depends_on
+                    .get(&id)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|dependency| processes.get(dependency))
+                    .filter(|state| state.status != ProcessStatus::Running)
+                    .map(|state| state.config.name.clone())
+                    .collect()
             };
-
-            if all_stopped {
-                return true;
+            if pending.is_empty() && stagger.is_zero() {
+                self.start_process(&id);
+                continue;
             }
 
-            if start.elapsed() >= timeout {
-                return false;
+            let Some((config, start_generation)) =
+                begin_process_start(&id, &self.processes, &self.event_tx, &self.event_version)
+            else {
+                continue;
+            };
+            if !pending.is_empty() {
+                let mut processes = self.processes.lock().unwrap();
+                if let Some(state) = processes.get_mut(&id) {
+                    log_process_state_event(state, format!("[Waiting for {}]", pending.join(", ")));
+                }
             }
-
-            thread::sleep(Duration::from_millis(100));
+            waiting.push((id, config, start_generation));
         }
-    }
-
-    /// Restart all processes
-    pub fn restart_all(&self) {
-        let ids: Vec<String> = {
-            let processes = self.processes.lock().unwrap();
-            processes
-                .iter()
-                .filter(|(_, state)| state.config.respond_to_restart_all)
-                .map(|(id, _)| id.clone())
-                .collect()
-        };
 
-        for id in &ids {
-            self.stop_process(id);
-        }
-
-        // Wait for all processes to stop (max 5 seconds)
-        if !self.wait_for_processes_to_stop(&ids, Duration::from_secs(5)) {
-            println!("[WARN] Restart all timeout waiting for stops");
+        if waiting.is_empty() {
+            return;
         }
+        self.notify();
 
-        for id in ids {
-            self.start_process(&id);
-        }
+        let processes = self.processes.clone();
+        let log_directory = self.log_directory.clone();
+        let event_tx = self.event_tx.clone();
+        let event_version = self.event_version.clone();
+        let error_version = self.error_version.clone();
+        let process_error_versions = self.process_error_versions.clone();
+        thread::spawn(move || {
+            for (id, config, start_generation) in waiting {
+                loop {
+                    let readiness = {
+                        let processes = processes.lock().unwrap();
+                        dependency_readiness(&processes, &id, start_generation, &config)
+                    };
+                    match readiness {
+                        DependencyReadiness::Waiting => {
+                            thread::sleep(DEPENDENCY_POLL_INTERVAL);
+                        }
+                        DependencyReadiness::Cancelled => break,
+                        DependencyReadiness::Ready => {
+                            launch_process(
+                                &id,
+                                &config,
+                                start_generation,
+                                processes.clone(),
+                                log_directory.clone(),
+                                event_tx.clone(),
+                                event_version.clone(),
+                                error_version.clone(),
+                                process_error_versions.clone(),
+                            );
+                            thread::sleep(stagger);
+                            break;
+                        }
+                        DependencyReadiness::Failed(dependency) => {
+                            {
+                                let mut processes = processes.lock().unwrap();
+                                if let Some(state) = processes.get_mut(&id) {
+                                    state.set_status(ProcessStatus::Stopped);
+                                    state.start_generation = state.start_generation.wrapping_add(1);
+                                    log_process_state_event(
+                                        state,
+                                        format!(
+                                            "[Skipped: dependency '{}' did not reach Running]",
+                                            dependency
+                                        ),
+                                    );
+                                }
+                            }
+                            bump_error(&error_version, &process_error_versions, &id);
+                            bump_event(&event_tx, &event_version);
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Stop all processes from a background thread, returning immediately.
+    pub fn stop_all(self: &Arc<Self>) {
+        let manager = Arc::clone(self);
+        thread::spawn(move || {
+            let ids = manager.ordered_ids_where(|state| state.config.respond_to_stop_all);
+            for id in ids {
+                manager.stop_process(&id);
+            }
+        });
+    }
+
+    /// Stop All, then block until every affected entry is Stopped or Error. Call it from a
+    /// background thread. Returns false if `timeout` elapsed first.
+    pub fn stop_all_blocking(&self, timeout: Duration) -> bool {
+        let ids = self.ordered_ids_where(|state| state.config.respond_to_stop_all);
+        for id in &ids {
+            self.stop_process(id);
+        }
+        self.wait_for_processes_to_stop(&ids, timeout)
+    }
+
+    /// Match the sidebar order after the config list was reordered.
+    pub fn set_process_order(&self, ids: Vec<String>) {
+        *self.process_order.lock().unwrap() = ids;
+    }
+
+    /// Ids matching `predicate`, in sidebar order. Entries missing from the order go last.
+    fn ordered_ids_where(&self, predicate: impl Fn(&ProcessState) -> bool) -> Vec<String> {
+        let processes = self.processes.lock().unwrap();
+        let order = self.process_order.lock().unwrap();
+        let mut ids: Vec<String> = order
+            .iter()
+            .filter(|id| processes.get(*id).is_some_and(&predicate))
+            .cloned()
+            .collect();
+        let mut unordered: Vec<String> = processes
+            .iter()
+            .filter(|(id, state)| !order.contains(id) && predicate(state))
+            .map(|(id, _)| id.clone())
+            .collect();
+        unordered.sort();
+        ids.extend(unordered);
+        ids
+    }
+
+    /// Stop all managed processes regardless of stack-control flags.
+    pub fn stop_all_forced(&self) {
+        let ids: Vec<String> = {
+            let processes = self.processes.lock().unwrap();
+            processes.keys().cloned().collect()
+        };
+        let timeout = self.stop_wait_timeout(&ids);
+        for id in &ids {
+            self.stop_process(id);
+        }
+
+        let _ = self.wait_for_processes_to_stop(&ids, timeout);
+    }
+
+    /// Upper bound on how long stop requests for these entries take to settle.
+    pub fn stop_wait_timeout(&self, ids: &[String]) -> Duration {
+        let processes = self.processes.lock().unwrap();
+        let seconds = ids
+            .iter()
+            .filter_map(|id| processes.get(id))
+            .map(|state| {
+                let hook_secs = if state.config.post_stop.is_empty() {
+                    0
+                } else {
+                    state.config.hook_timeout_secs.max(1)
+                };
+                state.config.stop_timeout_secs + hook_secs
+            })
+            .max()
+            .unwrap_or(DEFAULT_STOP_TIMEOUT_SECONDS);
+        Duration::from_secs(seconds) + STOP_SETTLE_MARGIN
+    }
+
+    /// Block until every id is Stopped, Error, or gone. Returns false if `timeout` elapsed first.
+    pub fn wait_for_processes_to_stop(&self, ids: &[String], timeout: Duration) -> bool {
+        let start = Instant::now();
+        loop {
+            let all_stopped = {
+                let processes = self.processes.lock().unwrap();
+                ids.iter().all(|id| {
+                    processes.get(id).map_or(true, |state| {
+                        matches!(
+                            state.status,
+                            ProcessStatus::Stopped | ProcessStatus::Error(_)
+                        )
+                    })
+                })
+            };
+
+            if all_stopped {
+                return true;
+            }
+
+            if start.elapsed() >= timeout {
+                return false;
+            }
+
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    /// Restart all processes on a background thread, starting only after every stop settled.
+    pub fn restart_all(self: &Arc<Self>) {
+        let manager = Arc::clone(self);
+        thread::spawn(move || {
+            let ids = manager.ordered_ids_where(|state| state.config.respond_to_restart_all);
+            manager.restart_ids(ids);
+        });
+    }
+
+    /// Start every member of a sidebar group (see [`ProcessConfig::group_name`]) from a
+    /// background thread, dependencies first. Stack-control flags do not apply here.
+    pub fn start_group(self: &Arc<Self>, group: &str) {
+        let manager = Arc::clone(self);
+        let group = group.to_string();
+        thread::spawn(move || {
+            let ids = manager.group_ids(&group);
+            manager.start_in_dependency_order(ids, Duration::ZERO);
+        });
+    }
+
+    /// Stop every member of a sidebar group from a background thread.
+    pub fn stop_group(self: &Arc<Self>, group: &str) {
+        let manager = Arc::clone(self);
+        let group = group.to_string();
+        thread::spawn(move || {
+            for id in manager.group_ids(&group) {
+                manager.stop_process(&id);
+            }
+        });
+    }
+
+    /// Restart every member of a sidebar group from a background thread.
+    pub fn restart_group(self: &Arc<Self>, group: &str) {
+        let manager = Arc::clone(self);
+        let group = group.to_string();
+        thread::spawn(move || {
+            let ids = manager.group_ids(&group);
+            manager.restart_ids(ids);
+        });
+    }
+
+    fn group_ids(&self, group: &str) -> Vec<String> {
+        self.ordered_ids_where(|state| state.config.group_name() == group)
+    }
+
+    /// Stop `ids`, wait for the stops to settle, then start them again. Blocks the caller.
+    fn restart_ids(&self, ids: Vec<String>) {
+        let timeout = self.stop_wait_timeout(&ids);
+        for id in &ids {
+            self.stop_process(id);
+        }
+
+        // Wait for all processes to stop, allowing for their graceful stop timeouts
+        if !self.wait_for_processes_to_stop(&ids, timeout) {
+            println!("[WARN] Restart timeout waiting for stops");
+        }
+
+        self.start_in_dependency_order(ids, Duration::ZERO);
+    }
+
+    /// `docker stop` every running Docker and Compose entry in parallel, waiting at most
+    /// `timeout`. Returns the names of entries that had not stopped in time.
+    pub fn stop_docker_entries(&self, timeout: Duration) -> Vec<String> {
+        let ids = self.ordered_ids_where(|state| {
+            state.config.process_type.is_container()
+                && matches!(
+                    state.status,
+                    ProcessStatus::Running | ProcessStatus::Starting | ProcessStatus::Stopping
+                )
+        });
+        for id in &ids {
+            self.stop_process(id);
+        }
+        if self.wait_for_processes_to_stop(&ids, timeout) {
+            return Vec::new();
+        }
+
+        let processes = self.processes.lock().unwrap();
+        ids.iter()
+            .filter_map(|id| processes.get(id))
+            .filter(|state| state.status == ProcessStatus::Stopping)
+            .map(|state| state.config.name.clone())
+            .collect()
     }
 
-    /// Stop all non-Docker processes (called on app shutdown)
+    /// Stop all non-Docker processes (called on app shutdown). Stops already in flight are
+    /// waited for, so their kill threads are not cut off when the app exits.
     pub fn stop_non_docker(&self) {
+        let mut in_flight = Vec::new();
         let mut processes = self.processes.lock().unwrap();
-        for state in processes.values_mut() {
-            if state.config.process_type == ProcessType::Process {
-                if let Some(ref mut child) = state.child {
-                    let pid = child.id();
-                    #[cfg(windows)]
-                    {
-                        if let Some(job) = state.job.take() {
-                            drop(job);
-                        } else {
-                            let _ = kill_process_tree(pid);
-                        }
-                    }
-                    #[cfg(not(windows))]
-                    {
-                        let _ = child.kill();
-                    }
-                    let _ = child.wait();
-                    state.child = None;
-                }
-                #[cfg(windows)]
-                {
-                    state.job = None;
-                }
-                state.status = ProcessStatus::Stopped;
-                let _ = clear_resource_usage(state);
+        for (id, state) in processes.iter_mut() {
+            if state.config.process_type == ProcessType::Process
+                && state.child.is_none()
+                && state.status == ProcessStatus::Stopping
+            {
+                in_flight.push(id.clone());
             }
+            force_stop_local_process(state);
         }
+        drop(processes);
         self.notify();
+
+        if !in_flight.is_empty() {
+            let timeout = self.stop_wait_timeout(&in_flight);
+            if !self.wait_for_processes_to_stop(&in_flight, timeout) {
+                eprintln!(
+                    "[WARN] {} process(es) were still stopping at shutdown",
+                    in_flight.len()
+                );
+            }
+        }
     }
 
     /// Get status of a process
@@ -1226,8 +2509,10 @@ impl ProcessManager {
             self.get_status(&process_id),
             Some(ProcessStatus::Running | ProcessStatus::Starting | ProcessStatus::Stopping)
         ) {
+            let ids = [process_id.clone()];
+            let timeout = self.stop_wait_timeout(&ids);
             self.stop_process(&process_id);
-            let _ = self.wait_for_processes_to_stop(&[process_id.clone()], Duration::from_secs(5));
+            let _ = self.wait_for_processes_to_stop(&ids, timeout);
         }
 
         let mut processes = self.processes.lock().unwrap();
@@ -1241,11 +2526,271 @@ impl ProcessManager {
         }
     }
 
+    /// Most recent log lines with color sequences stripped.
     pub fn get_recent_logs(&self, id: &str, limit: usize) -> Option<Vec<String>> {
         let processes = self.processes.lock().unwrap();
         processes.get(id).map(|state| {
-            let start = state.logs.len().saturating_sub(limit);
-            state.logs[start..].to_vec()
+            state
+                .logs
+                .tail(limit)
+                .map(|line| strip_ansi(line))
+                .collect()
+        })
+    }
+
+    /// Check an entry without starting it: the working directory exists and the program
+    /// resolves (noting when Windows runs it through `cmd`), or the container or compose
+    /// service exists. Returns a short summary of what was found.
+    pub fn validate_config(&self, config: &ProcessConfig) -> Result<String, String> {
+        if let Some(target) = DockerTarget::from_config(config) {
+            if config.process_type == ProcessType::Docker && !config.docker_run_args.is_empty() {
+                let run_args = parse_docker_run_args(&config.docker_run_args)?;
+                if !target.container_exists()? {
+                    return Ok(format!(
+                        "{} does not exist yet. Start will create it with: {}",
+                        target.describe(),
+                        docker_run_command_line(config.command.trim(), &run_args)
+                    ));
+                }
+            }
+            return target.validate();
+        }
+
+        check_working_directory(&config.working_directory)?;
+        let mut found = Vec::new();
+        if config.use_shell {
+            found.push("Shell command; the shell resolves the program at start.".to_string());
+        } else {
+            let (program, _) = program_and_args(config)?;
+            #[cfg(windows)]
+            {
+                let resolved = resolve_program(&program, &config.working_directory)?;
+                found.push(if resolved.is_cmd_script {
+                    format!(
+                        "Found {} (a .cmd/.bat script, run through cmd /C).",
+                        resolved.path
+                    )
+                } else {
+                    format!("Found {}.", resolved.path)
+                });
+            }
+            #[cfg(unix)]
+            {
+                let path = find_program(&program, &config.working_directory)?;
+                found.push(format!("Found {}.", path.display()));
+            }
+        }
+        if !config.working_directory.trim().is_empty() {
+            found.push("Working directory exists.".to_string());
+        }
+        if let (_, Some(err)) = process_env_vars(config) {
+            return Err(err);
+        }
+        if !config.run_as.trim().is_empty() {
+            check_run_as(config.run_as.trim())?;
+            found.push(format!("Can run as '{}'.", config.run_as.trim()));
+        }
+        Ok(found.join(" "))
+    }
+
+    /// Send `signal` to a running process entry's process group without stopping it. The entry
+    /// stays Running; if the signal makes it exit, that is handled like any other exit.
+    #[cfg(unix)]
+    pub fn send_signal(&self, id: &str, signal: ProcessSignal) -> Result<(), String> {
+        {
+            let mut processes = self.processes.lock().unwrap();
+            let state = processes
+                .get_mut(id)
+                .ok_or_else(|| "Process not found.".to_string())?;
+            if state.config.process_type != ProcessType::Process {
+                return Err("Signals can only be sent to process entries.".to_string());
+            }
+            let pid = match (&state.status, &state.child) {
+                (ProcessStatus::Running, Some(child)) => child.id(),
+                _ => return Err(format!("'{}' is not running.", state.config.name)),
+            };
+            signal_process_group(pid, signal.number(), &state.config.run_as).map_err(|err| {
+                format!(
+                    "Failed to send {} to '{}': {}",
+                    signal, state.config.name, err
+                )
+            })?;
+            log_process_state_event(state, format!("[Sent {}]", signal));
+        }
+        self.notify();
+        Ok(())
+    }
+
+    /// Run a one-off command inside a running container. Output is appended to the log as system lines.
+    pub fn docker_exec(&self, id: &str, command: &str) -> Result<(), String> {
+        let (program, rest) = parse_command(command)?;
+        let args: Vec<String> = std::iter::once(program).chain(rest).collect();
+
+        let target = {
+            let mut processes = self.processes.lock().unwrap();
+            let state = processes
+                .get_mut(id)
+                .ok_or_else(|| "Process not found.".to_string())?;
+            let target = DockerTarget::from_config(&state.config)
+                .ok_or_else(|| "Exec is only available for Docker entries.".to_string())?;
+            if state.status != ProcessStatus::Running {
+                return Err("Container is not running.".to_string());
+            }
+            log_process_state_event(state, format!("[Exec: {}]", command.trim()));
+            target
+        };
+        self.notify();
+
+        let id_owned = id.to_string();
+        let command_label = command.trim().to_string();
+        let processes_arc = self.processes.clone();
+        let event_tx = self.event_tx.clone();
+        let event_version = self.event_version.clone();
+        thread::spawn(move || {
+            let output = target.exec_command(&args).output();
+            let mut processes = processes_arc.lock().unwrap();
+            if let Some(state) = processes.get_mut(&id_owned) {
+                match output {
+                    Ok(out) => {
+                        let combined = [out.stdout.as_slice(), out.stderr.as_slice()]
+                            .iter()
+                            .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        for line in combined.lines().filter(|line| !line.trim().is_empty()) {
+                            log_process_state_event(
+                                state,
+                                format!("[exec: {}]", sanitize_runtime_log_line(line)),
+                            );
+                        }
+                        if out.status.success() {
+                            log_process_state_event(
+                                state,
+                                format!("[Exec '{}' finished]", command_label),
+                            );
+                        } else {
+                            log_process_state_event(
+                                state,
+                                format!(
+                                    "[Exec '{}' failed with {}]",
+                                    command_label,
+                                    out.status
+                                        .code()
+                                        .map(|code| format!("exit code {}", code))
+                                        .unwrap_or_else(|| "no exit code".to_string())
+                                ),
+                            );
+                        }
+                    }
+                    Err(err) => {
+                        log_process_state_event(
+                            state,
+                            format!("[Exec '{}' failed: {}]", command_label, err),
+                        );
+                    }
+                }
+            }
+            drop(processes);
+            bump_event(&event_tx, &event_version);
+        });
+
+        Ok(())
+    }
+
+    /// Empty the in-memory log buffer for a process. New output keeps appending.
+    pub fn clear_logs(&self, id: &str) {
+        let mut processes = self.processes.lock().unwrap();
+        if let Some(state) = processes.get_mut(id) {
+            state.logs.clear();
+        }
+        drop(processes);
+        self.notify();
+    }
+
+    /// Write the in-memory log buffer for a process to `path`, with a short header.
+    pub fn export_logs(&self, id: &str, path: &Path) -> Result<(), String> {
+        let (name, logs) = {
+            let processes = self.processes.lock().unwrap();
+            let state = processes
+                .get(id)
+                .ok_or_else(|| "Process not found.".to_string())?;
+            let logs: Vec<String> = state.logs.iter().map(|line| strip_ansi(line)).collect();
+            (state.config.name.clone(), logs)
+        };
+
+        let mut content = format!(
+            "# {}\n# Exported {}\n\n",
+            name,
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+        );
+        content.push_str(&logs.join("\n"));
+        if !logs.is_empty() {
+            content.push('\n');
+        }
+
+        fs::write(path, content)
+            .map_err(|err| format!("Failed to write logs to '{}': {}", path.display(), err))
+    }
+
+    /// Write every process's log buffer into `dir`, one file per process, plus a
+    /// `manifest.txt` listing current statuses and the lifecycle event log.
+    pub fn export_all_logs(&self, dir: &Path) -> Result<(), String> {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("Failed to create '{}': {}", dir.display(), err))?;
+
+        let mut manifest = format!(
+            "# Process logs\n# Exported {}\n\n## Processes\n\n",
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+        );
+        let mut used_names = std::collections::HashSet::new();
+        for snapshot in self.list_processes() {
+            let base = sanitize_path_component(&snapshot.name);
+            let mut file_name = format!("{}.log", base);
+            let mut suffix = 2;
+            while !used_names.insert(file_name.to_lowercase()) {
+                file_name = format!("{} ({}).log", base, suffix);
+                suffix += 1;
+            }
+            self.export_logs(&snapshot.id, &dir.join(&file_name))?;
+
+            let status = match &snapshot.status_detail {
+                Some(detail) => format!("{}: {}", snapshot.status, detail),
+                None => snapshot.status.clone(),
+            };
+            let pid = snapshot
+                .pid
+                .map(|pid| format!(", pid {}", pid))
+                .unwrap_or_default();
+            manifest.push_str(&format!(
+                "- {} [{}] {}{} -> {}\n",
+                snapshot.name, snapshot.process_type, status, pid, file_name
+            ));
+        }
+
+        manifest.push_str("\n## Lifecycle events\n\n");
+        for event in self.lifecycle_events() {
+            manifest.push_str(&format!(
+                "{}  {}  {}\n",
+                event.at.format("%Y-%m-%d %H:%M:%S%.3f"),
+                event.process_name,
+                event.kind
+            ));
+        }
+
+        let path = dir.join("manifest.txt");
+        fs::write(&path, manifest)
+            .map_err(|err| format!("Failed to write '{}': {}", path.display(), err))
+    }
+
+    /// File name suggested when exporting logs for a process.
+    pub fn suggested_log_export_name(&self, id: &str) -> Option<String> {
+        let processes = self.processes.lock().unwrap();
+        processes.get(id).map(|state| {
+            format!(
+                "{}_{}.log",
+                sanitize_path_component(&state.config.name),
+                chrono::Local::now().format("%Y-%m-%d_%H-%M-%S")
+            )
         })
     }
 
@@ -1264,6 +2809,34 @@ impl ProcessManager {
         snapshots
     }
 
+    /// Look an entry up by id, falling back to its display name (ignoring case) so scripts
+    /// can address processes without knowing their generated ids. A name shared by several
+    /// entries is an error rather than a guess, since any pick could target the wrong one.
+    pub fn resolve_process_id(&self, id_or_name: &str) -> Result<Option<String>, String> {
+        let processes = self.processes.lock().unwrap();
+        if processes.contains_key(id_or_name) {
+            return Ok(Some(id_or_name.to_string()));
+        }
+        let name = id_or_name.trim();
+        let mut matches = processes
+            .iter()
+            .filter(|(_, state)| state.config.name.trim().eq_ignore_ascii_case(name))
+            .map(|(id, _)| id.clone());
+        let first = matches.next();
+        if matches.next().is_some() {
+            return Err(format!(
+                "Process name '{}' matches more than one entry; use its id instead",
+                name
+            ));
+        }
+        Ok(first)
+    }
+
+    pub fn get_runtime_info(&self, id: &str) -> Option<ProcessRuntimeInfo> {
+        let processes = self.processes.lock().unwrap();
+        processes.get(id).map(runtime_info_from_state)
+    }
+
     pub fn get_process_snapshot(&self, id: &str) -> Option<ProcessRuntimeSnapshot> {
         let processes = self.processes.lock().unwrap();
         processes.get(id).map(process_snapshot_from_state)
@@ -1289,11 +2862,13 @@ impl ProcessManager {
         counts
     }
 
-    pub fn build_ui_snapshot(
-        &self,
-        selected_id: Option<&str>,
-        log_limit: usize,
-    ) -> UiRuntimeSnapshot {
+    /// Log lines appended to a process's buffer since `cursor` (a previous `next_sequence`).
+    pub fn get_logs_since(&self, id: &str, cursor: u64) -> Option<LogDelta> {
+        let processes = self.processes.lock().unwrap();
+        processes.get(id).map(|state| state.logs.since(cursor))
+    }
+
+    pub fn build_ui_snapshot(&self) -> UiRuntimeSnapshot {
         let processes = self.processes.lock().unwrap();
         let mut counts = ProcessCounts {
             total: processes.len(),
@@ -1301,12 +2876,18 @@ impl ProcessManager {
         };
         let mut statuses = HashMap::with_capacity(processes.len());
         let mut resource_usage = HashMap::with_capacity(processes.len());
-        let mut selected_logs = Vec::new();
-        let mut selected_log_count = 0usize;
+        let mut health = HashMap::new();
+        let mut started_at = HashMap::new();
 
         for (id, state) in processes.iter() {
             statuses.insert(id.clone(), state.status.clone());
             resource_usage.insert(id.clone(), state.resource_usage);
+            if state.config.health_check.enabled {
+                health.insert(id.clone(), state.health);
+            }
+            if let Some(started) = runtime_info_from_state(state).started_at {
+                started_at.insert(id.clone(), started);
+            }
 
             match &state.status {
                 ProcessStatus::Running => counts.running += 1,
@@ -1315,26 +2896,176 @@ impl ProcessManager {
                 ProcessStatus::Stopping => counts.stopping += 1,
                 ProcessStatus::Error(_) => counts.error += 1,
             }
-
-            if selected_id == Some(id.as_str()) {
-                selected_log_count = state.logs.len();
-                let start = state.logs.len().saturating_sub(log_limit);
-                selected_logs = state.logs[start..].to_vec();
-            }
         }
 
         UiRuntimeSnapshot {
             counts,
             statuses,
             resource_usage,
-            selected_logs,
-            selected_log_count,
+            health,
+            started_at,
+            last_log_lines: HashMap::new(),
+            docker: self.docker_status(),
         }
     }
 
-    /// Check and update docker container status
-    #[allow(dead_code)]
-    pub fn refresh_docker_status(&self, id: &str) {
+    /// Case-insensitive search through every process's log buffer, in sidebar order. Lines come
+    /// back without ANSI codes, oldest first, keeping the newest matches once the combined
+    /// total reaches `LOG_SEARCH_RESULT_LIMIT`. Processes without a match are left out.
+    pub fn search_all_logs(&self, query: &str) -> Vec<(String, Vec<String>)> {
+        if query.trim().is_empty() {
+            return Vec::new();
+        }
+        let needle = query.to_lowercase();
+        let ids = self.ordered_ids_where(|_| true);
+        let processes = self.processes.lock().unwrap();
+        let mut remaining = LOG_SEARCH_RESULT_LIMIT;
+        let mut results = Vec::new();
+        for id in ids {
+            if remaining == 0 {
+                break;
+            }
+            let Some(state) = processes.get(&id) else {
+                continue;
+            };
+            let mut lines: Vec<String> = state
+                .logs
+                .iter()
+                .rev()
+                .map(|line| strip_ansi(line))
+                .filter(|line| line.to_lowercase().contains(&needle))
+                .take(remaining)
+                .collect();
+            if lines.is_empty() {
+                continue;
+            }
+            lines.reverse();
+            remaining -= lines.len();
+            results.push((id, lines));
+        }
+        results
+    }
+
+    /// The newest `limit` lines across every process, oldest first, interleaved by the time
+    /// they were received. Lines received at the same instant keep sidebar order.
+    pub fn combined_logs(&self, limit: usize) -> Vec<CombinedLogEntry> {
+        let ids = self.ordered_ids_where(|_| true);
+        let processes = self.processes.lock().unwrap();
+        let states: Vec<&ProcessState> = ids.iter().filter_map(|id| processes.get(id)).collect();
+
+        // Walk each buffer backwards from its newest line, always taking the newest head.
+        let mut remaining: Vec<usize> = states.iter().map(|state| state.logs.len()).collect();
+        let mut heads = BinaryHeap::new();
+        for (index, state) in states.iter().enumerate() {
+            if let Some(entry) = state.logs.lines.back() {
+                heads.push((entry.at, index));
+            }
+        }
+        let mut combined = Vec::with_capacity(limit.min(remaining.iter().sum()));
+        while combined.len() < limit {
+            let Some((_, index)) = heads.pop() else {
+                break;
+            };
+            let state = states[index];
+            remaining[index] -= 1;
+            combined.push(CombinedLogEntry {
+                process_id: state.config.id.clone(),
+                process_name: state.config.name.clone(),
+                entry: state.logs.lines[remaining[index]].clone(),
+            });
+            if let Some(previous) = remaining[index].checked_sub(1) {
+                heads.push((state.logs.lines[previous].at, index));
+            }
+        }
+        combined.reverse();
+        combined
+    }
+
+    /// Latest non-blank log line of every entry that has one, for the sidebar preview.
+    pub fn last_log_lines(&self) -> HashMap<String, String> {
+        let processes = self.processes.lock().unwrap();
+        processes
+            .iter()
+            .filter_map(|(id, state)| {
+                let line = state
+                    .logs
+                    .iter()
+                    .rev()
+                    .map(|line| strip_ansi(line))
+                    .find(|line| !line.trim().is_empty())?;
+                Some((id.clone(), line.trim().to_string()))
+            })
+            .collect()
+    }
+
+    /// Check the watch paths of running entries. Returns ids whose files changed and then
+    /// stayed quiet for the debounce window; each one gets a log line naming the trigger.
+    fn poll_watch_paths(&self, watches: &mut HashMap<String, WatchState>) -> Vec<String> {
+        let targets: Vec<(String, u64, ProcessConfig)> = {
+            let processes = self.processes.lock().unwrap();
+            processes
+                .iter()
+                .filter(|(_, state)| {
+                    state.config.process_type == ProcessType::Process
+                        && !state.config.watch_paths.is_empty()
+                        && state.status == ProcessStatus::Running
+                })
+                .map(|(id, state)| (id.clone(), state.start_generation, state.config.clone()))
+                .collect()
+        };
+        watches.retain(|id, _| targets.iter().any(|(target, _, _)| target == id));
+
+        let now = Instant::now();
+        let mut triggered = Vec::new();
+        for (id, generation, config) in targets {
+            let watch = match watches.get_mut(&id) {
+                Some(watch) if watch.generation == generation => watch,
+                _ => {
+                    // New instance: start watching fresh instead of reacting to older edits.
+                    let watch = WatchState {
+                        generation,
+                        watcher: PathWatcher::new(&config),
+                        pending: None,
+                    };
+                    watches.insert(id, watch);
+                    continue;
+                }
+            };
+
+            if let Some(path) = watch.watcher.changed_path() {
+                watch.pending = Some((now, path));
+            } else if watch
+                .pending
+                .as_ref()
+                .is_some_and(|(changed_at, _)| now.duration_since(*changed_at) >= WATCH_DEBOUNCE)
+            {
+                if let Some((_, path)) = watch.pending.take() {
+                    triggered.push((id, path));
+                }
+            }
+        }
+
+        if triggered.is_empty() {
+            return Vec::new();
+        }
+        {
+            let mut processes = self.processes.lock().unwrap();
+            for (id, path) in &triggered {
+                if let Some(state) = processes.get_mut(id) {
+                    log_process_state_event(
+                        state,
+                        format!("[Watch: {} changed. Restarting...]", path.display()),
+                    );
+                }
+            }
+        }
+        self.notify();
+        triggered.into_iter().map(|(id, _)| id).collect()
+    }
+
+    /// Check and update docker container status
+    #[allow(dead_code)]
+    pub fn refresh_docker_status(&self, id: &str) {
         refresh_docker_status_inner(
             id,
             &self.processes,
@@ -1357,6 +3088,12 @@ fn refresh_resource_usage(processes: &Arc<Mutex<HashMap<String, ProcessState>>>)
     let mut processes = processes.lock().unwrap();
 
     for state in processes.values_mut() {
+        if state.config.process_type == ProcessType::Docker
+            && state.status == ProcessStatus::Running
+        {
+            // Sampled separately through `docker stats`.
+            continue;
+        }
         if state.config.process_type != ProcessType::Process
             || state.status != ProcessStatus::Running
         {
@@ -1415,6 +3152,41 @@ fn refresh_resource_usage(processes: &Arc<Mutex<HashMap<String, ProcessState>>>)
     updated
 }
 
+/// Kill a local entry's process tree right away and mark it Stopped. Docker entries and
+/// stops already in flight are left alone.
+fn force_stop_local_process(state: &mut ProcessState) {
+    if state.config.process_type != ProcessType::Process
+        || (state.child.is_none() && state.status == ProcessStatus::Stopping)
+    {
+        return;
+    }
+    if let Some(ref mut child) = state.child {
+        let pid = child.id();
+        #[cfg(windows)]
+        {
+            if let Some(job) = state.job.take() {
+                drop(job);
+            } else {
+                let _ = kill_process_tree(pid, &state.config.run_as);
+            }
+        }
+        #[cfg(not(windows))]
+        {
+            if kill_process_tree(pid, &state.config.run_as).is_err() {
+                let _ = child.kill();
+            }
+        }
+        let _ = child.wait();
+        state.child = None;
+    }
+    #[cfg(windows)]
+    {
+        state.job = None;
+    }
+    state.set_status(ProcessStatus::Stopped);
+    let _ = clear_resource_usage(state);
+}
+
 fn clear_resource_usage(state: &mut ProcessState) -> bool {
     let changed =
         state.resource_usage != ProcessResourceUsage::default() || state.resource_sample.is_some();
@@ -1432,7 +3204,155 @@ fn resource_usage_changed(previous: ProcessResourceUsage, next: ProcessResourceU
         }
 }
 
-#[cfg(not(windows))]
+/// Sample running Docker containers with one `docker stats` call. CPU is scaled to the
+/// whole machine so it lines up with the per-process numbers.
+fn refresh_docker_resource_usage(processes: &Arc<Mutex<HashMap<String, ProcessState>>>) -> bool {
+    let targets: Vec<(String, DockerTarget)> = {
+        let processes = processes.lock().unwrap();
+        processes
+            .iter()
+            .filter(|(_, state)| {
+                state.config.process_type.is_container() && state.status == ProcessStatus::Running
+            })
+            .filter_map(|(id, state)| Some((id.clone(), DockerTarget::from_config(&state.config)?)))
+            .collect()
+    };
+    if targets.is_empty() {
+        return false;
+    }
+    // Resolved outside the lock: a compose service needs a `docker compose ps` call.
+    let containers: Vec<(String, Vec<String>)> = targets
+        .into_iter()
+        .map(|(id, target)| (id, target.stats_containers()))
+        .collect();
+    let names: Vec<&String> = containers.iter().flat_map(|(_, names)| names).collect();
+    let stats = if names.is_empty() {
+        HashMap::new()
+    } else {
+        sample_docker_stats(&names)
+    };
+
+    let mut updated = false;
+    let mut processes = processes.lock().unwrap();
+    for (id, names) in containers {
+        let Some(state) = processes.get_mut(&id) else {
+            continue;
+        };
+        if state.status != ProcessStatus::Running {
+            continue;
+        }
+        let new_usage = combined_resource_usage(names.iter().filter_map(|name| stats.get(name)));
+        if resource_usage_changed(state.resource_usage, new_usage) {
+            state.resource_usage = new_usage;
+            updated = true;
+        }
+    }
+
+    updated
+}
+
+/// One `docker stats` sample per container, keyed by the name or id it was asked for.
+fn sample_docker_stats(names: &[&String]) -> HashMap<String, ProcessResourceUsage> {
+    let mut cmd = Command::new("docker");
+    cmd.args([
+        "stats",
+        "--no-stream",
+        "--format",
+        "{{.Container}}|{{.CPUPerc}}|{{.MemUsage}}",
+    ]);
+    cmd.args(names);
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000);
+    }
+
+    let Ok(output) = cmd.output() else {
+        return HashMap::new();
+    };
+    let processor_count = std::thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(1)
+        .max(1) as f32;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(parse_docker_stats_line)
+        .map(|(name, mut usage)| {
+            usage.cpu_percent = usage
+                .cpu_percent
+                .map(|cpu| (cpu / processor_count).clamp(0.0, 100.0));
+            (name, usage)
+        })
+        .collect()
+}
+
+/// Usage of an entry backed by several containers, such as a scaled compose service.
+fn combined_resource_usage<'a>(
+    samples: impl Iterator<Item = &'a ProcessResourceUsage>,
+) -> ProcessResourceUsage {
+    fn add<T: std::ops::Add<Output = T>>(total: Option<T>, value: Option<T>) -> Option<T> {
+        match (total, value) {
+            (Some(total), Some(value)) => Some(total + value),
+            (total, value) => total.or(value),
+        }
+    }
+    samples.fold(ProcessResourceUsage::default(), |total, sample| {
+        ProcessResourceUsage {
+            cpu_percent: add(total.cpu_percent, sample.cpu_percent).map(|cpu| cpu.min(100.0)),
+            memory_bytes: add(total.memory_bytes, sample.memory_bytes),
+        }
+    })
+}
+
+/// Parse one `Name|CPUPerc|MemUsage` line from `docker stats --format`.
+fn parse_docker_stats_line(line: &str) -> Option<(String, ProcessResourceUsage)> {
+    let mut fields = line.trim().splitn(3, '|');
+    let name = fields.next()?.trim();
+    if name.is_empty() {
+        return None;
+    }
+    let cpu_percent = fields
+        .next()
+        .and_then(|cpu| cpu.trim().trim_end_matches('%').parse::<f32>().ok());
+    let memory_bytes = fields
+        .next()
+        .and_then(|memory| memory.split('/').next())
+        .and_then(parse_docker_size);
+
+    Some((
+        name.to_string(),
+        ProcessResourceUsage {
+            cpu_percent,
+            memory_bytes,
+        },
+    ))
+}
+
+/// Parse a docker size such as `512KiB`, `12.5MiB`, or `1.2GB` into bytes.
+fn parse_docker_size(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let split = value
+        .find(|ch: char| ch.is_ascii_alphabetic())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number.trim().parse().ok()?;
+    let multiplier = match unit.trim() {
+        "" | "B" => 1.0,
+        "kB" | "KB" => 1_000.0,
+        "KiB" => 1_024.0,
+        "MB" => 1_000_000.0,
+        "MiB" => 1_048_576.0,
+        "GB" => 1_000_000_000.0,
+        "GiB" => 1_073_741_824.0,
+        "TB" => 1_000_000_000_000.0,
+        "TiB" => 1_099_511_627_776.0,
+        _ => return None,
+    };
+    Some((number * multiplier) as u64)
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
 fn sample_process_resources(
     _state: &ProcessState,
     _root_pid: u32,
@@ -1440,6 +3360,87 @@ fn sample_process_resources(
     None
 }
 
+#[cfg(target_os = "linux")]
+fn sample_process_resources(_state: &ProcessState, root_pid: u32) -> Option<ResourceCounterSample> {
+    let ticks_per_second = unsafe { libc::sysconf(libc::_SC_CLK_TCK) }.max(1) as u64;
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) }.max(1) as u64;
+
+    let mut cpu_ticks: Option<u64> = None;
+    let mut memory_bytes: Option<u64> = None;
+    for pid in linux_process_tree(root_pid) {
+        if let Some((_, ticks)) = fs::read_to_string(format!("/proc/{}/stat", pid))
+            .ok()
+            .and_then(|stat| parse_proc_stat(&stat))
+        {
+            cpu_ticks = Some(cpu_ticks.unwrap_or(0).saturating_add(ticks));
+        }
+        if let Some(pages) = fs::read_to_string(format!("/proc/{}/statm", pid))
+            .ok()
+            .and_then(|statm| statm.split_whitespace().nth(1)?.parse::<u64>().ok())
+        {
+            memory_bytes = Some(
+                memory_bytes
+                    .unwrap_or(0)
+                    .saturating_add(pages.saturating_mul(page_size)),
+            );
+        }
+    }
+
+    if cpu_ticks.is_none() && memory_bytes.is_none() {
+        return None;
+    }
+
+    Some(ResourceCounterSample {
+        cpu_time_100ns: cpu_ticks
+            .map(|ticks| ticks.saturating_mul(CPU_TIME_UNITS_PER_SECOND as u64) / ticks_per_second),
+        memory_bytes,
+    })
+}
+
+/// The root pid plus every descendant, found by walking parent links in `/proc`.
+#[cfg(target_os = "linux")]
+fn linux_process_tree(root_pid: u32) -> Vec<u32> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    if let Ok(entries) = fs::read_dir("/proc") {
+        for entry in entries.flatten() {
+            let Some(pid) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            if let Some((parent, _)) = fs::read_to_string(entry.path().join("stat"))
+                .ok()
+                .and_then(|stat| parse_proc_stat(&stat))
+            {
+                children.entry(parent).or_default().push(pid);
+            }
+        }
+    }
+
+    let mut pids = vec![root_pid];
+    let mut index = 0;
+    while index < pids.len() {
+        if let Some(next) = children.get(&pids[index]) {
+            pids.extend(next.iter().copied());
+        }
+        index += 1;
+    }
+    pids
+}
+
+/// Extract `(ppid, utime + stime)` from `/proc/<pid>/stat`.
+#[cfg(target_os = "linux")]
+fn parse_proc_stat(stat: &str) -> Option<(u32, u64)> {
+    // The command name can contain spaces and parentheses, so split after the last ')'.
+    let fields: Vec<&str> = stat[stat.rfind(')')? + 1..].split_whitespace().collect();
+    let parent = fields.get(1)?.parse().ok()?;
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+    Some((parent, utime.saturating_add(stime)))
+}
+
 #[cfg(windows)]
 fn sample_process_resources(state: &ProcessState, root_pid: u32) -> Option<ResourceCounterSample> {
     let pids = state
@@ -1661,59 +3662,521 @@ fn open_process_for_metrics(pid: u32) -> Option<ScopedProcessHandle> {
     }
 }
 
+/// Launch due probes for running entries and reset health for everything else.
+fn run_health_checks(
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+    event_tx: &watch::Sender<u64>,
+    event_version: &Arc<AtomicU64>,
+) {
+    let now = Instant::now();
+    let mut due = Vec::new();
+    let mut updated = false;
+    {
+        let mut processes = processes.lock().unwrap();
+        for (id, state) in processes.iter_mut() {
+            let check = &state.config.health_check;
+            if !check.enabled || check.url.is_empty() || state.status != ProcessStatus::Running {
+                updated |= state.health != HealthStatus::Unknown;
+                state.health = HealthStatus::Unknown;
+                state.health_checked_at = None;
+                state.health_failures = 0;
+                continue;
+            }
+
+            let interval = Duration::from_secs(check.interval_secs.max(1));
+            if state.health_probe_in_flight
+                || state
+                    .health_checked_at
+                    .is_some_and(|checked_at| now.duration_since(checked_at) < interval)
+            {
+                continue;
+            }
+
+            state.health_checked_at = Some(now);
+            state.health_probe_in_flight = true;
+            due.push((id.clone(), check.clone(), state.start_generation));
+        }
+    }
+
+    if updated {
+        bump_event(event_tx, event_version);
+    }
+
+    for (id, check, generation) in due {
+        let processes = processes.clone();
+        let processes_for_kill = processes.clone();
+        let event_tx = event_tx.clone();
+        let event_version = event_version.clone();
+        thread::spawn(move || {
+            let result = probe_http(&check.url, Duration::from_secs(check.timeout_secs.max(1)));
+            let health = match result {
+                Ok(code) if code == check.expected_status => HealthStatus::Healthy,
+                _ => HealthStatus::Unhealthy,
+            };
+
+            let mut processes = processes.lock().unwrap();
+            let Some(state) = processes.get_mut(&id) else {
+                return;
+            };
+            state.health_probe_in_flight = false;
+            if state.start_generation != generation || state.status != ProcessStatus::Running {
+                return;
+            }
+
+            let watchdog_due = record_health_result(state, health == HealthStatus::Healthy);
+            let changed = state.health != health;
+            if changed {
+                let message = match result {
+                    Ok(code) if code == check.expected_status => {
+                        "[Health check passed]".to_string()
+                    }
+                    Ok(code) => format!(
+                        "[Health check failed: expected HTTP {} but got {}]",
+                        check.expected_status, code
+                    ),
+                    Err(err) => format!("[Health check failed: {}]", err),
+                };
+                log_process_state_event(state, message);
+                state.health = health;
+            }
+
+            let watchdog_pid = if watchdog_due { state.pid } else { None };
+            if watchdog_pid.is_some() {
+                log_process_state_event(
+                    state,
+                    format!(
+                        "[Watchdog: {} failed health checks in a row. Killing the process so it restarts.]",
+                        check.restart_after_failures
+                    ),
+                );
+                state.unhealthy_restart = true;
+            }
+            let run_as = state.config.run_as.clone();
+            drop(processes);
+
+            if let Some(pid) = watchdog_pid {
+                if let Err(err) = kill_process_tree(pid, &run_as) {
+                    let mut processes = processes_for_kill.lock().unwrap();
+                    if let Some(state) = processes.get_mut(&id) {
+                        state.unhealthy_restart = false;
+                        log_process_state_event(
+                            state,
+                            format!("[Watchdog could not kill the process: {}]", err),
+                        );
+                    }
+                }
+            }
+            if changed || watchdog_pid.is_some() {
+                bump_event(&event_tx, &event_version);
+            }
+        });
+    }
+}
+
+/// Count a probe result toward the watchdog threshold. Returns true once the process has
+/// failed enough checks in a row that it should be killed and restarted by its policy.
+fn record_health_result(state: &mut ProcessState, healthy: bool) -> bool {
+    if healthy {
+        state.health_failures = 0;
+        return false;
+    }
+    state.health_failures = state.health_failures.saturating_add(1);
+    let threshold = state.config.health_check.restart_after_failures;
+    if threshold == 0
+        || state.health_failures < threshold
+        || state.config.process_type != ProcessType::Process
+        || !state.config.restart_policy.is_enabled()
+        || !managed_restart_active_now(state)
+    {
+        return false;
+    }
+    state.health_failures = 0;
+    true
+}
+
+/// Issue a bare `GET` and return the response status code. Only plain `http://` is supported.
+fn probe_http(url: &str, timeout: Duration) -> Result<u16, String> {
+    let (host, port, path) = parse_http_url(url)?;
+    let addresses: Vec<_> = (host.as_str(), port)
+        .to_socket_addrs()
+        .map_err(|err| format!("could not resolve {}: {}", host, err))?
+        .collect();
+    // `localhost` often resolves to `::1` first while the service only listens on IPv4.
+    let mut last_error = None;
+    let mut stream = None;
+    for address in &addresses {
+        match TcpStream::connect_timeout(address, timeout) {
+            Ok(connected) => {
+                stream = Some(connected);
+                break;
+            }
+            Err(err) => last_error = Some(format!("could not connect to {}: {}", address, err)),
+        }
+    }
+    let mut stream = stream
+        .ok_or_else(|| last_error.unwrap_or_else(|| format!("could not resolve {}", host)))?;
+    let _ = stream.set_read_timeout(Some(timeout));
+    let _ = stream.set_write_timeout(Some(timeout));
+
+    let request = format!(
+        "GET {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: simple-rust-process-manager\r\nConnection: close\r\n\r\n",
+        path, host
+    );
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|err| format!("request failed: {}", err))?;
+
+    let mut status_line = String::new();
+    BufReader::new(stream.take(1024))
+        .read_line(&mut status_line)
+        .map_err(|err| format!("no response: {}", err))?;
+    parse_http_status_line(&status_line)
+        .ok_or_else(|| format!("unexpected response '{}'", status_line.trim()))
+}
+
+/// Split `http://host[:port][/path]` into its parts, defaulting to port 80 and `/`.
+fn parse_http_url(url: &str) -> Result<(String, u16, String), String> {
+    let rest = url
+        .trim()
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("only http:// URLs are supported, got '{}'", url))?;
+    let (authority, path) = match rest.find('/') {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, "/"),
+    };
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) if !authority.ends_with(']') => (
+            host,
+            port.parse::<u16>()
+                .map_err(|_| format!("invalid port in '{}'", url))?,
+        ),
+        _ => (authority, 80),
+    };
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    if host.is_empty() {
+        return Err(format!("missing host in '{}'", url));
+    }
+
+    Ok((host.to_string(), port, path.to_string()))
+}
+
+fn parse_http_status_line(line: &str) -> Option<u16> {
+    let mut parts = line.split_whitespace();
+    if !parts.next()?.starts_with("HTTP/") {
+        return None;
+    }
+    parts.next()?.parse().ok()
+}
+
 fn append_runtime_log(
     processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
     process_id: &str,
     line: String,
     is_stderr: bool,
 ) -> (bool, bool) {
-    let (disk_log, formatted, has_error) = {
+    append_output_line(processes, process_id, line, is_stderr, false)
+}
+
+/// Append a line read from a process. `open` lines (ended by a lone `\r`) are redrawn by the
+/// stream's next line and only reach the disk log once a newline settles them.
+fn append_output_line(
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+    process_id: &str,
+    line: String,
+    is_stderr: bool,
+    open: bool,
+) -> (bool, bool) {
+    let (disk_log, settled, formatted, has_error) = {
         let mut processes = processes.lock().unwrap();
         let Some(state) = processes.get_mut(process_id) else {
             return (false, false);
         };
+        let settled = take_settled_open_line(state, is_stderr, line.is_empty(), open);
+        if line.is_empty() {
+            state.logs.push_output(line, is_stderr, open);
+            let disk_log = state.disk_log.clone();
+            drop(processes);
+            if let (Some(file), Some(text)) = (disk_log, settled) {
+                write_disk_log_line(&file, &text);
+            }
+            return (false, false);
+        }
 
-        let sanitized = sanitize_runtime_log_line(&line);
-        let formatted = if is_stderr {
-            format!("[stderr] {}", sanitized)
-        } else {
-            sanitized
-        };
-        let has_error = line_has_error(&formatted);
+        let settings = state.logs.settings.clone();
+        let colored = sanitize_runtime_log_line_keep_colors(&line);
+        let colored = with_log_timestamp(
+            &settings,
+            if is_stderr {
+                format!("[stderr] {}", colored)
+            } else {
+                colored
+            },
+        );
+        let formatted = disk_output_text(&settings, &line, is_stderr);
+        let has_error = line_has_error(&formatted, &settings.highlight_rules());
+        state.open_disk_line = open.then(|| formatted.clone());
         let disk_log = state.disk_log.clone();
-        push_in_memory_log(&mut state.logs, formatted.clone());
-        (disk_log, formatted, has_error)
+        push_in_memory_output(&mut state.logs, colored, is_stderr, open);
+        (disk_log, settled, (!open).then_some(formatted), has_error)
     };
 
     if let Some(file) = disk_log {
-        write_disk_log_line(&file, &formatted);
+        for text in settled.iter().chain(&formatted) {
+            write_disk_log_line(&file, text);
+        }
     }
 
     (true, has_error)
 }
 
-fn log_process_state_event(state: &mut ProcessState, message: String) {
-    if let Some(file) = state.disk_log.clone() {
-        write_disk_log_line(&file, &message);
+/// The disk log text of an open line that this line settles: a blank line from the same
+/// stream (the `\n` of a `\r\n` ending) or any output from the other stream. A line that
+/// redraws the open one replaces it instead. Call before the line is pushed.
+fn take_settled_open_line(
+    state: &mut ProcessState,
+    is_stderr: bool,
+    blank: bool,
+    open: bool,
+) -> Option<String> {
+    let settled = match state.logs.open_line {
+        Some(stream) if stream == is_stderr => blank && !open,
+        Some(_) => !blank,
+        None => false,
+    };
+    if settled {
+        state.open_disk_line.take()
+    } else {
+        None
     }
-    push_in_memory_log(&mut state.logs, message);
 }
 
-fn push_in_memory_log(logs: &mut Vec<String>, line: String) {
-    logs.push(line);
-    if logs.len() > IN_MEMORY_LOG_LIMIT {
-        logs.remove(0);
-    }
+/// A line of process output as the disk log records it: plain text, tagged when it came from
+/// stderr, with the arrival time when timestamps are on.
+fn disk_output_text(settings: &LogSettings, line: &str, is_stderr: bool) -> String {
+    let plain = sanitize_runtime_log_line(line);
+    with_log_timestamp(
+        settings,
+        if is_stderr {
+            format!("[stderr] {}", plain)
+        } else {
+            plain
+        },
+    )
 }
 
-fn write_disk_log_line(file: &SharedLogFile, line: &str) {
-    if let Ok(mut file) = file.lock() {
-        let _ = writeln!(file, "{}", line);
-        let _ = file.flush();
+/// Like `read_until(b'\n')`, but a lone `\r` also ends the line, so progress bars that redraw
+/// in place arrive as separate lines instead of one ever-growing line. Bytes are kept raw;
+/// callers decode them lossily so invalid UTF-8 never costs a whole line.
+fn read_output_line(reader: &mut impl BufRead, buffer: &mut Vec<u8>) -> std::io::Result<usize> {
+    let mut read = 0;
+    loop {
+        let available = match reader.fill_buf() {
+            Ok(available) => available,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        if available.is_empty() {
+            return Ok(read);
+        }
+        let (used, done) = match available
+            .iter()
+            .position(|byte| matches!(byte, b'\n' | b'\r'))
+        {
+            Some(index) => (index + 1, true),
+            None => (available.len(), false),
+        };
+        buffer.extend_from_slice(&available[..used]);
+        reader.consume(used);
+        read += used;
+        if done {
+            return Ok(read);
+        }
+    }
+}
+
+/// Prefix a captured line with its arrival time when timestamps are enabled.
+fn with_log_timestamp(settings: &LogSettings, line: String) -> String {
+    if settings.show_timestamps.load(Ordering::Relaxed) {
+        format!("[{}] {}", chrono::Local::now().format("%H:%M:%S%.3f"), line)
+    } else {
+        line
+    }
+}
+
+fn log_process_state_event(state: &mut ProcessState, message: String) {
+    if let Some(file) = state.disk_log.clone() {
+        write_disk_log_line(&file, &message);
+    }
+    push_in_memory_log(&mut state.logs, message);
+}
+
+/// Per-process output budget shared by its reader threads. Lines over the budget skip the
+/// in-memory log, and with it the process lock, so a runaway process cannot starve the UI.
+/// They still reach the disk log.
+struct OutputRateLimiter {
+    window_start: Instant,
+    admitted: usize,
+    suppressed: usize,
+}
+
+impl OutputRateLimiter {
+    fn new(now: Instant) -> Self {
+        Self {
+            window_start: now,
+            admitted: 0,
+            suppressed: 0,
+        }
+    }
+
+    /// Whether to keep the next line, plus the count of lines dropped in the previous
+    /// one-second window once that window has closed.
+    fn admit(&mut self, now: Instant, limit: usize) -> (bool, Option<usize>) {
+        let mut reported = None;
+        if now.saturating_duration_since(self.window_start) >= Duration::from_secs(1) {
+            self.window_start = now;
+            self.admitted = 0;
+            reported = self.take_suppressed();
+        }
+        if limit == 0 || self.admitted < limit {
+            self.admitted += 1;
+            (true, reported)
+        } else {
+            self.suppressed += 1;
+            (false, reported)
+        }
+    }
+
+    fn take_suppressed(&mut self) -> Option<usize> {
+        (self.suppressed > 0).then(|| std::mem::take(&mut self.suppressed))
+    }
+
+    /// The drop count for the window that started at `window_start`, unless a later line has
+    /// already closed that window and reported it.
+    fn take_suppressed_for(&mut self, window_start: Instant) -> Option<usize> {
+        if self.window_start == window_start {
+            self.take_suppressed()
+        } else {
+            None
+        }
+    }
+}
+
+type SharedRateLimiter = Arc<Mutex<OutputRateLimiter>>;
+
+/// Run `line` through the process's rate limiter, logging a marker for lines dropped in
+/// the last window. Returns whether the line should go to the in-memory log; a dropped line
+/// is written straight to `disk_log` instead. The first drop in a window also arranges for
+/// the marker to be logged when the window ends, even if no more output arrives.
+fn admit_output_line(
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+    id: &str,
+    settings: &SharedLogSettings,
+    limiter: &SharedRateLimiter,
+    disk_log: Option<&SharedLogFile>,
+    line: &[u8],
+    is_stderr: bool,
+) -> bool {
+    if line.is_empty() {
+        // Empty lines only settle a `\r` line and are never dropped.
+        return true;
+    }
+    let limit = settings.max_output_lines_per_second();
+    let (admit, suppressed, first_drop) = {
+        let mut limiter = limiter.lock().unwrap();
+        let (admit, suppressed) = limiter.admit(Instant::now(), limit);
+        let first_drop = (!admit && limiter.suppressed == 1).then_some(limiter.window_start);
+        (admit, suppressed, first_drop)
+    };
+    if let Some(count) = suppressed {
+        log_suppressed_output(processes, id, settings, count);
+    }
+    if !admit {
+        if let Some(file) = disk_log {
+            write_disk_log_line(
+                file,
+                &disk_output_text(settings, &String::from_utf8_lossy(line), is_stderr),
+            );
+        }
+    }
+    if let Some(window_start) = first_drop {
+        let processes = processes.clone();
+        let id = id.to_string();
+        let settings = settings.clone();
+        let limiter = limiter.clone();
+        thread::spawn(move || {
+            thread::sleep(
+                (window_start + Duration::from_secs(1)).saturating_duration_since(Instant::now()),
+            );
+            let suppressed = limiter.lock().unwrap().take_suppressed_for(window_start);
+            if let Some(count) = suppressed {
+                log_suppressed_output(&processes, &id, &settings, count);
+            }
+        });
+    }
+    admit
+}
+
+/// Log the lines a reader dropped that no later window has reported yet.
+fn flush_suppressed_output(
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+    id: &str,
+    settings: &LogSettings,
+    limiter: &SharedRateLimiter,
+) {
+    let suppressed = limiter.lock().unwrap().take_suppressed();
+    if let Some(count) = suppressed {
+        log_suppressed_output(processes, id, settings, count);
+    }
+}
+
+fn log_suppressed_output(
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+    id: &str,
+    settings: &LogSettings,
+    count: usize,
+) {
+    let (updated, _) = append_runtime_log(
+        processes,
+        id,
+        format!(
+            "[{} lines suppressed: output over {} lines/s]",
+            count,
+            settings.max_output_lines_per_second()
+        ),
+        false,
+    );
+    if updated {
+        mark_log_event(settings);
+    }
+}
+
+fn push_in_memory_log(logs: &mut LogBuffer, line: String) {
+    logs.push_back(line);
+    logs.trim(logs.settings.max_lines());
+}
+
+fn push_in_memory_output(logs: &mut LogBuffer, line: String, is_stderr: bool, open: bool) {
+    logs.push_output(line, is_stderr, open);
+    logs.trim(logs.settings.max_lines());
+}
+
+fn write_disk_log_line(file: &SharedLogFile, line: &str) {
+    if let Ok(mut file) = file.lock() {
+        let _ = writeln!(file, "{}", line);
+        let _ = file.flush();
     }
 }
 
 fn sanitize_runtime_log_line(line: &str) -> String {
+    sanitize_log_text(line, false)
+}
+
+/// Like `sanitize_runtime_log_line`, but keeps SGR color sequences for the log view.
+fn sanitize_runtime_log_line_keep_colors(line: &str) -> String {
+    sanitize_log_text(line, true)
+}
+
+fn sanitize_log_text(line: &str, keep_sgr: bool) -> String {
     let mut sanitized = String::with_capacity(line.len());
     let mut chars = line.chars().peekable();
 
@@ -1722,11 +4185,17 @@ fn sanitize_runtime_log_line(line: &str) -> String {
             match chars.peek().copied() {
                 Some('[') => {
                     chars.next();
-                    while let Some(next) = chars.next() {
+                    let mut sequence = String::new();
+                    for next in chars.by_ref() {
+                        sequence.push(next);
                         if ('@'..='~').contains(&next) {
                             break;
                         }
                     }
+                    if keep_sgr && sequence.ends_with('m') {
+                        sanitized.push_str("\u{1b}[");
+                        sanitized.push_str(&sequence);
+                    }
                 }
                 Some(']') => {
                     chars.next();
@@ -1753,17 +4222,94 @@ fn sanitize_runtime_log_line(line: &str) -> String {
     sanitized
 }
 
-fn create_disk_log_session(
-    base_directory: &str,
-    config: &ProcessConfig,
-) -> Result<SharedLogFile, String> {
-    let process_directory = resolve_log_root(base_directory)
+fn process_log_directory(base_directory: &str, config: &ProcessConfig) -> PathBuf {
+    resolve_log_root(base_directory)
         .join(PROCESS_LOG_FOLDER_NAME)
         .join(sanitize_path_component(if config.name.trim().is_empty() {
             &config.id
         } else {
             &config.name
-        }));
+        }))
+}
+
+/// Seed the in-memory buffer with the tail of the newest session log so history survives restarts.
+fn restore_previous_session_logs(state: &mut ProcessState, base_directory: &str) {
+    let process_directory = process_log_directory(base_directory, &state.config);
+    let Ok(entries) = fs::read_dir(&process_directory) else {
+        return;
+    };
+    let Some(latest) = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("log"))
+        .max()
+    else {
+        return;
+    };
+    let limit = RESTORED_LOG_LINE_LIMIT.min(state.logs.settings.max_lines());
+    let Ok(lines) = read_tail_lines(&latest, limit) else {
+        return;
+    };
+    if lines.is_empty() {
+        return;
+    }
+
+    push_in_memory_log(
+        &mut state.logs,
+        format!(
+            "[Previous session: last {} lines from {}]",
+            lines.len(),
+            latest.file_name().unwrap_or_default().to_string_lossy()
+        ),
+    );
+    for line in lines {
+        let source = if is_stderr_line(&line) {
+            LogSource::Stderr
+        } else {
+            LogSource::Stdout
+        };
+        state.logs.push_entry(source, line);
+        state.logs.trim(state.logs.settings.max_lines());
+    }
+}
+
+/// The last `limit` lines of a file, read backwards from its end so a huge log costs no more
+/// than its tail. At most `RESTORED_LOG_BYTE_LIMIT` bytes are read.
+fn read_tail_lines(path: &Path, limit: usize) -> std::io::Result<Vec<String>> {
+    const CHUNK: u64 = 64 * 1024;
+    let mut file = File::open(path)?;
+    let end = file.seek(SeekFrom::End(0))?;
+    let floor = end.saturating_sub(RESTORED_LOG_BYTE_LIMIT);
+    let mut start = end;
+    let mut tail: Vec<u8> = Vec::new();
+    // One newline more than `limit` guarantees the first kept line is complete.
+    while start > floor && tail.iter().filter(|byte| **byte == b'\n').count() <= limit {
+        let next = start.saturating_sub(CHUNK).max(floor);
+        let mut chunk = vec![0; (start - next) as usize];
+        file.seek(SeekFrom::Start(next))?;
+        file.read_exact(&mut chunk)?;
+        chunk.extend_from_slice(&tail);
+        tail = chunk;
+        start = next;
+    }
+    if start > 0 {
+        // Drop the partial line the read began in.
+        let first_line_end = tail.iter().position(|byte| *byte == b'\n');
+        tail.drain(..first_line_end.map_or(tail.len(), |index| index + 1));
+    }
+    Ok(tail_lines(&String::from_utf8_lossy(&tail), limit))
+}
+
+fn tail_lines(content: &str, limit: usize) -> Vec<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let start = lines.len().saturating_sub(limit);
+    lines[start..].iter().map(|line| line.to_string()).collect()
+}
+
+fn create_disk_log_session(
+    base_directory: &str,
+    config: &ProcessConfig,
+) -> Result<SharedLogFile, String> {
+    let process_directory = process_log_directory(base_directory, config);
 
     fs::create_dir_all(&process_directory)
         .map_err(|err| format!("Failed to create log folder: {}", err))?;
@@ -1855,9 +4401,57 @@ fn sanitize_path_component(value: &str) -> String {
     }
 }
 
+fn runtime_info_from_state(state: &ProcessState) -> ProcessRuntimeInfo {
+    let running = state.status == ProcessStatus::Running;
+    ProcessRuntimeInfo {
+        restart_count: state.restart_count,
+        started_at: state.started_at.filter(|_| running),
+        pid: state.pid.filter(|_| running),
+        last_exit_code: state.last_exit_code,
+    }
+}
+
+/// Spacing between Docker status polls: quick right after a status change, the configured
+/// interval normally, and slower once containers have been stable for a minute.
+fn docker_poll_delay(base: Duration, since_activity: Duration) -> Duration {
+    let base = base.max(DOCKER_POLL_MIN_INTERVAL);
+    if since_activity < DOCKER_POLL_BOOST_WINDOW {
+        base.min(DOCKER_POLL_FAST_INTERVAL)
+    } else if since_activity >= DOCKER_POLL_IDLE_AFTER {
+        base * DOCKER_POLL_IDLE_FACTOR
+    } else {
+        base
+    }
+}
+
+/// Exit code for display. Unix signal deaths use the shell convention (SIGKILL -> 137).
+fn exit_code(status: ExitStatus) -> Option<i32> {
+    if let Some(code) = status.code() {
+        return Some(code);
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return Some(128 + signal);
+        }
+    }
+    None
+}
+
+/// Status for a process that exited without a stop request: clean exits are Stopped,
+/// anything else is an Error carrying the code.
+fn exit_status_after_unrequested_exit(code: Option<i32>) -> ProcessStatus {
+    match code {
+        Some(0) | None => ProcessStatus::Stopped,
+        Some(code) => ProcessStatus::Error(format!("exited with code {}", code)),
+    }
+}
+
 fn process_snapshot_from_state(state: &ProcessState) -> ProcessRuntimeSnapshot {
     let (status, status_detail) = status_parts(&state.status);
     let pid = state.child.as_ref().map(|child| child.id());
+    let runtime_info = runtime_info_from_state(state);
 
     ProcessRuntimeSnapshot {
         id: state.config.id.clone(),
@@ -1868,9 +4462,14 @@ fn process_snapshot_from_state(state: &ProcessState) -> ProcessRuntimeSnapshot {
         pid,
         cpu_percent: state.resource_usage.cpu_percent,
         memory_bytes: state.resource_usage.memory_bytes,
+        restart_count: runtime_info.restart_count,
+        uptime_seconds: runtime_info.uptime().map(|uptime| uptime.as_secs()),
+        last_exit_code: runtime_info.last_exit_code,
+        health: state.config.health_check.enabled.then_some(state.health),
         auto_start: state.config.auto_start,
         startup_delay_seconds: state.config.startup_delay_seconds,
-        auto_restart: state.config.auto_restart,
+        auto_restart: state.config.restart_policy.is_enabled(),
+        restart_policy: state.config.restart_policy,
         respond_to_start_all: state.config.respond_to_start_all,
         respond_to_stop_all: state.config.respond_to_stop_all,
         respond_to_restart_all: state.config.respond_to_restart_all,
@@ -1889,6 +4488,24 @@ fn status_parts(status: &ProcessStatus) -> (String, Option<String>) {
     }
 }
 
+/// The log settings an entry's buffer shares with its manager.
+fn log_settings_for(
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+    id: &str,
+) -> SharedLogSettings {
+    processes
+        .lock()
+        .unwrap()
+        .get(id)
+        .map(|state| state.logs.settings.clone())
+        .unwrap_or_default()
+}
+
+/// Note new log output. The background flusher turns any number of these into one event.
+fn mark_log_event(settings: &LogSettings) {
+    settings.event_pending.store(true, Ordering::Relaxed);
+}
+
 fn bump_event(event_tx: &watch::Sender<u64>, event_version: &Arc<AtomicU64>) {
     let next = event_version
         .fetch_add(1, Ordering::Relaxed)
@@ -1909,6 +4526,114 @@ fn bump_error(
     *entry = entry.wrapping_add(1);
 }
 
+/// Wipe the log buffer for a new run, or, when the entry keeps its history, mark where the
+/// new run begins so earlier crash output stays readable.
+fn reset_logs_for_start(state: &mut ProcessState) {
+    if state.config.clear_logs_on_start {
+        state.logs.clear();
+    } else if state.logs.len() > 0 {
+        push_in_memory_log(&mut state.logs, "[--- restarted ---]".to_string());
+    }
+}
+
+/// Mark a dormant process as Starting and hand back what is needed to launch it.
+fn begin_process_start(
+    id: &str,
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+    event_tx: &watch::Sender<u64>,
+    event_version: &Arc<AtomicU64>,
+) -> Option<(ProcessConfig, u64)> {
+    let mut processes = processes.lock().unwrap();
+    println!("[DEBUG] Got lock, looking for process id: {}", id);
+    let Some(state) = processes.get_mut(id) else {
+        println!("[DEBUG] Process not found in manager!");
+        return None;
+    };
+    println!(
+        "[DEBUG] Found process: {}, status: {:?}",
+        state.config.name, state.status
+    );
+    if !process_is_dormant(state) || state.status == ProcessStatus::Starting {
+        println!("[DEBUG] Already running or starting, returning");
+        if let Some(label) = in_flight_label(&state.status) {
+            log_process_state_event(state, format!("[Ignored start: already {}]", label));
+            bump_event(event_tx, event_version);
+        }
+        return None; // Already running
+    }
+    state.suppress_restart_once = false;
+    state.restart_attempts = 0;
+    state.restart_count = 0;
+    state.set_status(ProcessStatus::Starting);
+    reset_logs_for_start(state);
+    state.disk_log = None;
+    let _ = clear_resource_usage(state);
+    state.start_generation = state.start_generation.wrapping_add(1);
+    bump_event(event_tx, event_version);
+    Some((state.config.clone(), state.start_generation))
+}
+
+/// Order `ids` so dependencies come first, keeping the given order otherwise. Entries
+/// caught in (or behind) a dependency cycle are returned separately.
+fn dependency_start_order(
+    ids: &[String],
+    depends_on: &HashMap<String, Vec<String>>,
+) -> (Vec<String>, Vec<String>) {
+    let mut ordered: Vec<String> = Vec::with_capacity(ids.len());
+    let mut remaining: Vec<String> = ids.to_vec();
+
+    loop {
+        let next = remaining.iter().position(|id| {
+            depends_on
+                .get(id)
+                .into_iter()
+                .flatten()
+                .all(|dependency| !ids.contains(dependency) || ordered.contains(dependency))
+        });
+        match next {
+            Some(index) => ordered.push(remaining.remove(index)),
+            None => break,
+        }
+    }
+
+    (ordered, remaining)
+}
+
+enum DependencyReadiness {
+    Ready,
+    Waiting,
+    Failed(String),
+    Cancelled,
+}
+
+fn dependency_readiness(
+    processes: &HashMap<String, ProcessState>,
+    id: &str,
+    start_generation: u64,
+    config: &ProcessConfig,
+) -> DependencyReadiness {
+    match processes.get(id) {
+        Some(state)
+            if state.start_generation == start_generation
+                && state.status == ProcessStatus::Starting => {}
+        _ => return DependencyReadiness::Cancelled,
+    }
+
+    let mut readiness = DependencyReadiness::Ready;
+    for dependency in &config.depends_on {
+        // Entries deleted since the dependency was configured don't block anything.
+        let Some(state) = processes.get(dependency) else {
+            continue;
+        };
+        match state.status {
+            ProcessStatus::Running => {}
+            ProcessStatus::Starting => readiness = DependencyReadiness::Waiting,
+            _ => return DependencyReadiness::Failed(state.config.name.clone()),
+        }
+    }
+    readiness
+}
+
 fn launch_process(
     id: &str,
     config: &ProcessConfig,
@@ -1932,7 +4657,7 @@ fn launch_process(
             error_version,
             process_error_versions,
         ),
-        ProcessType::Docker => ProcessManager::start_docker_container(
+        ProcessType::Docker | ProcessType::Compose => ProcessManager::start_docker_container(
             id,
             config,
             start_generation,
@@ -2016,24 +4741,38 @@ fn stop_process_inner(
     let mut child_to_kill: Option<Child> = None;
     #[cfg(windows)]
     let mut job_to_close: Option<JobHandle> = None;
-    let mut docker_container: Option<String> = None;
+    let mut docker_target: Option<DockerTarget> = None;
+    let stop_timeout;
+    let run_as;
 
     {
         let mut processes = processes_arc.lock().unwrap();
         if let Some(state) = processes.get_mut(id) {
+            if state.status == ProcessStatus::Stopping {
+                // The first stop's kill (or `docker stop`) is still running; stopping again
+                // would mark the entry Stopped before it actually exits.
+                log_process_state_event(state, "[Ignored stop: already stopping]".to_string());
+                drop(processes);
+                bump_event(&event_tx, &event_version);
+                return;
+            }
             state.suppress_restart_once = true;
+            state.started_at = None;
+            state.pid = None;
+            stop_timeout = Duration::from_secs(state.config.stop_timeout_secs);
+            run_as = state.config.run_as.clone();
             state.start_generation = state.start_generation.wrapping_add(1);
             match state.config.process_type {
                 ProcessType::Process => {
                     if let Some(child) = state.child.take() {
-                        state.status = ProcessStatus::Stopping;
+                        state.set_status(ProcessStatus::Stopping);
                         child_to_kill = Some(child);
                         #[cfg(windows)]
                         {
                             job_to_close = state.job.take();
                         }
                     } else {
-                        state.status = ProcessStatus::Stopped;
+                        state.set_status(ProcessStatus::Stopped);
                         #[cfg(windows)]
                         {
                             state.job = None;
@@ -2041,9 +4780,9 @@ fn stop_process_inner(
                         let _ = clear_resource_usage(state);
                     }
                 }
-                ProcessType::Docker => {
-                    state.status = ProcessStatus::Stopping;
-                    docker_container = Some(state.config.command.clone());
+                ProcessType::Docker | ProcessType::Compose => {
+                    state.set_status(ProcessStatus::Stopping);
+                    docker_target = DockerTarget::from_config(&state.config);
                 }
             }
         } else {
@@ -2058,23 +4797,34 @@ fn stop_process_inner(
             let pid = child.id();
             let mut stop_error: Option<String> = None;
 
+            // A refused request (common for Windows console programs) skips straight to the
+            // force-kill instead of sitting out the whole stop timeout.
+            let stop_requested = request_graceful_stop(pid, &run_as);
+            let exited_gracefully = stop_requested && wait_for_child_exit(&mut child, stop_timeout);
+
             #[cfg(windows)]
             {
                 let had_job = job_to_close.is_some();
                 if let Some(job) = job_to_close {
                     drop(job);
                 }
-                if let Err(e) = kill_process_tree(pid) {
-                    if !had_job {
-                        stop_error = Some(e);
-                        let _ = child.kill();
+                if !exited_gracefully {
+                    if let Err(e) = kill_process_tree(pid, &run_as) {
+                        if !had_job {
+                            stop_error = Some(e);
+                            let _ = child.kill();
+                        }
                     }
                 }
             }
             #[cfg(not(windows))]
             {
-                if let Err(e) = child.kill() {
-                    stop_error = Some(e.to_string());
+                if !exited_gracefully {
+                    if let Err(group_err) = kill_process_tree(pid, &run_as) {
+                        if let Err(e) = child.kill() {
+                            stop_error = Some(format!("{}; {}", group_err, e));
+                        }
+                    }
                 }
             }
 
@@ -2086,47 +4836,59 @@ fn stop_process_inner(
                 if let Some(err) = stop_error {
                     log_process_state_event(state, format!("[Stop error: {}]", err));
                 }
-                log_process_state_event(state, "[Process stopped]".to_string());
-                state.status = ProcessStatus::Stopped;
-                state.disk_log = None;
+                if exited_gracefully {
+                    log_process_state_event(state, "[Process stopped]".to_string());
+                } else if !stop_requested {
+                    log_process_state_event(
+                        state,
+                        "[Process force-killed: it did not accept a graceful stop]".to_string(),
+                    );
+                } else {
+                    log_process_state_event(
+                        state,
+                        format!(
+                            "[Process force-killed after {}s stop timeout]",
+                            stop_timeout.as_secs()
+                        ),
+                    );
+                }
                 let _ = clear_resource_usage(state);
             }
+            drop(processes);
+
+            // Stay Stopping until the post-stop hook is done, so a restart waiting for Stopped
+            // cannot run its pre-start hook alongside it.
+            let _ = run_hook(&id_owned, ProcessHook::PostStop, &processes_arc);
+            let mut processes = processes_arc.lock().unwrap();
+            if let Some(state) = processes.get_mut(&id_owned) {
+                state.set_status(ProcessStatus::Stopped);
+                state.disk_log = None;
+            }
+            drop(processes);
             bump_event(&event_tx, &event_version);
         });
         return;
     }
 
-    if let Some(container_name) = docker_container {
+    if let Some(target) = docker_target {
         thread::spawn(move || {
-            let mut cmd = Command::new("docker");
-            cmd.args(["stop", &container_name]);
-
-            #[cfg(windows)]
-            {
-                use std::os::windows::process::CommandExt;
-                cmd.creation_flags(0x08000000);
-            }
-
-            let output = cmd.output();
+            let output = target.stop_command().output();
 
             let mut processes = processes_arc.lock().unwrap();
             if let Some(state) = processes.get_mut(&id_owned) {
                 match output {
                     Ok(out) if out.status.success() => {
-                        state.status = ProcessStatus::Stopped;
-                        log_process_state_event(
-                            state,
-                            format!("[Docker container '{}' stopped]", container_name),
-                        );
+                        state.set_status(ProcessStatus::Stopped);
+                        log_process_state_event(state, format!("[{} stopped]", target.describe()));
                     }
                     Ok(out) => {
                         let stderr = String::from_utf8_lossy(&out.stderr);
                         log_process_state_event(state, format!("[Stop error: {}]", stderr));
-                        state.status = ProcessStatus::Stopped;
+                        state.set_status(ProcessStatus::Stopped);
                     }
                     Err(e) => {
                         log_process_state_event(state, format!("[Stop error: {}]", e));
-                        state.status = ProcessStatus::Stopped;
+                        state.set_status(ProcessStatus::Stopped);
                     }
                 }
                 state.disk_log = None;
@@ -2137,6 +4899,8 @@ fn stop_process_inner(
     }
 }
 
+// Built and consumed within one scheduler tick, so the size gap between variants is harmless.
+#[allow(clippy::large_enum_variant)]
 enum SchedulerAction {
     Start {
         id: String,
@@ -2174,14 +4938,14 @@ fn run_scheduler_tick(
         for (id, state) in processes_guard.iter_mut() {
             let schedule_runtime = schedule_guard.entry(id.clone()).or_default();
 
-            if state.config.auto_restart && state.config.restart_schedule.enabled {
+            if state.config.restart_policy.is_enabled() && state.config.restart_schedule.enabled {
                 let active = state.config.restart_schedule.active_at(day_index, hour);
                 let previous_active = schedule_runtime.last_restart_schedule_active;
 
                 if active && previous_active != Some(true) && process_is_dormant(state) {
                     state.suppress_restart_once = false;
-                    state.status = ProcessStatus::Starting;
-                    state.logs.clear();
+                    state.set_status(ProcessStatus::Starting);
+                    reset_logs_for_start(state);
                     state.disk_log = None;
                     let _ = clear_resource_usage(state);
                     state.start_generation = state.start_generation.wrapping_add(1);
@@ -2219,8 +4983,8 @@ fn run_scheduler_tick(
                 schedule_runtime.last_scheduled_run_minute = Some(minute_key);
                 if process_is_dormant(state) {
                     state.suppress_restart_once = false;
-                    state.status = ProcessStatus::Starting;
-                    state.logs.clear();
+                    state.set_status(ProcessStatus::Starting);
+                    reset_logs_for_start(state);
                     state.disk_log = None;
                     let _ = clear_resource_usage(state);
                     state.start_generation = state.start_generation.wrapping_add(1);
@@ -2270,6 +5034,15 @@ fn run_scheduler_tick(
     }
 }
 
+/// Word for a start or stop that is still in flight, used when a duplicate request is ignored.
+fn in_flight_label(status: &ProcessStatus) -> Option<&'static str> {
+    match status {
+        ProcessStatus::Starting => Some("starting"),
+        ProcessStatus::Stopping => Some("stopping"),
+        _ => None,
+    }
+}
+
 fn process_is_dormant(state: &ProcessState) -> bool {
     state.child.is_none()
         && matches!(
@@ -2278,15 +5051,108 @@ fn process_is_dormant(state: &ProcessState) -> bool {
         )
 }
 
-fn managed_restart_active_now(config: &ProcessConfig) -> bool {
+/// Log a failed launch attempt and sleep before the next one. Returns false when the entry
+/// was stopped or started again meanwhile, so the retry should be abandoned.
+#[allow(clippy::too_many_arguments)]
+fn wait_to_retry_start(
+    id: &str,
+    start_generation: u64,
+    error: &str,
+    attempt: u32,
+    max_attempts: u32,
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+    event_tx: &watch::Sender<u64>,
+    event_version: &Arc<AtomicU64>,
+) -> bool {
+    {
+        let mut processes = processes.lock().unwrap();
+        let Some(state) = processes.get_mut(id) else {
+            return false;
+        };
+        if !start_request_is_current_state(state, start_generation) {
+            return false;
+        }
+        log_process_state_event(
+            state,
+            format!(
+                "[Start failed: {}. Retrying launch {} of {} in {}s...]",
+                error,
+                attempt,
+                max_attempts,
+                START_RETRY_DELAY.as_secs()
+            ),
+        );
+    }
+    bump_event(event_tx, event_version);
+    thread::sleep(START_RETRY_DELAY);
+    start_request_is_current(id, start_generation, processes)
+}
+
+fn managed_restart_active_now(state: &ProcessState) -> bool {
+    if state.managed_restarts_paused.load(Ordering::Relaxed) {
+        return false;
+    }
     let now = chrono::Local::now();
-    config
+    state
+        .config
         .restart_schedule
         .active_at(now.weekday().num_days_from_monday() as usize, now.hour())
 }
 
+/// Record a managed restart attempt for a process that just went down.
+///
+/// Returns the backoff delay and the start generation the restart belongs to, or `None`
+/// after marking the process as errored once its retry budget is spent.
+fn plan_managed_restart(state: &mut ProcessState, reason: &str) -> Option<(Duration, u64)> {
+    if state
+        .started_at
+        .is_some_and(|started_at| started_at.elapsed() >= MANAGED_RESTART_STABLE_UPTIME)
+    {
+        state.restart_attempts = 0;
+    }
+    state.restart_attempts = state.restart_attempts.saturating_add(1);
+
+    let max_attempts = state.config.max_restart_attempts;
+    if max_attempts > 0 && state.restart_attempts > max_attempts {
+        let message = format!("Gave up after {} restart attempts", max_attempts);
+        log_process_state_event(state, format!("[{} {}]", reason, message));
+        state.set_status(ProcessStatus::Error(message));
+        return None;
+    }
+
+    let delay = managed_restart_delay(state.restart_attempts);
+    state.record_lifecycle(LifecycleEventKind::RestartScheduled {
+        attempt: state.restart_attempts,
+    });
+    log_process_state_event(
+        state,
+        format!(
+            "[{} Restart attempt {}{} in {}s...]",
+            reason,
+            state.restart_attempts,
+            if max_attempts > 0 {
+                format!(" of {}", max_attempts)
+            } else {
+                String::new()
+            },
+            delay.as_secs()
+        ),
+    );
+    state.set_status(ProcessStatus::Starting);
+    Some((delay, state.start_generation))
+}
+
+/// Exponential backoff: 1s, 2s, 4s, ... capped at 30s.
+fn managed_restart_delay(attempt: u32) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(5);
+    (MANAGED_RESTART_BASE_DELAY * 2u32.pow(exponent)).min(MANAGED_RESTART_MAX_DELAY)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn schedule_managed_restart(
     id: String,
+    delay: Duration,
+    restart_generation: u64,
     processes: Arc<Mutex<HashMap<String, ProcessState>>>,
     log_directory: Arc<Mutex<String>>,
     event_tx: watch::Sender<u64>,
@@ -2298,7 +5164,7 @@ fn schedule_managed_restart(
     bump_error(&error_version, &process_error_versions, &id);
 
     thread::spawn(move || {
-        thread::sleep(std::time::Duration::from_secs(1));
+        thread::sleep(delay);
 
         let (config, start_generation) = {
             let mut processes = processes.lock().unwrap();
@@ -2311,20 +5177,26 @@ fn schedule_managed_restart(
                 return;
             }
 
-            if !state.config.auto_restart
-                || !managed_restart_active_now(&state.config)
+            // A stop or manual start since the restart was planned bumps the generation.
+            if state.start_generation != restart_generation
                 || state.child.is_some()
-                || matches!(
-                    state.status,
-                    ProcessStatus::Running | ProcessStatus::Starting | ProcessStatus::Stopping
-                )
+                || state.status != ProcessStatus::Starting
             {
                 return;
             }
+            // Restarts were paused, turned off, or left their active hours during the backoff.
+            if !state.config.restart_policy.is_enabled() || !managed_restart_active_now(state) {
+                log_process_state_event(state, "[Managed restart cancelled]".to_string());
+                state.set_status(exit_status_after_unrequested_exit(state.last_exit_code));
+                drop(processes);
+                bump_event(&event_tx, &event_version);
+                return;
+            }
 
             state.suppress_restart_once = false;
-            state.status = ProcessStatus::Starting;
-            state.logs.clear();
+            state.restart_count = state.restart_count.saturating_add(1);
+            state.set_status(ProcessStatus::Starting);
+            reset_logs_for_start(state);
             state.disk_log = None;
             let _ = clear_resource_usage(state);
             state.start_generation = state.start_generation.wrapping_add(1);
@@ -2347,16 +5219,6 @@ fn schedule_managed_restart(
     });
 }
 
-fn line_has_error(line: &str) -> bool {
-    let trimmed = line.trim();
-    let content = if let Some(rest) = trimmed.strip_prefix("[stderr]") {
-        rest.trim_start()
-    } else {
-        trimmed
-    };
-    contains_error_indicator(content)
-}
-
 #[cfg(windows)]
 pub(crate) struct JobHandle {
     handle: windows_sys::Win32::Foundation::HANDLE,
@@ -2421,6 +5283,427 @@ fn assign_job(job: &JobHandle, child: &Child) -> Result<(), String> {
     }
 }
 
+/// A configured working directory must exist; spawning into a missing one gives an opaque OS error.
+fn check_working_directory(working_directory: &str) -> Result<(), String> {
+    let trimmed = working_directory.trim();
+    if trimmed.is_empty() || Path::new(trimmed).is_dir() {
+        Ok(())
+    } else {
+        Err(format!("Working directory not found: {}", trimmed))
+    }
+}
+
+/// Variables from the entry's env file and `env` list. Explicit entries come last so they win;
+/// an unreadable env file is reported alongside whatever could still be collected.
+fn process_env_vars(config: &ProcessConfig) -> (Vec<(String, String)>, Option<String>) {
+    let mut vars = Vec::new();
+    let mut env_file_error = None;
+    if !config.env_file.trim().is_empty() {
+        match load_env_file(&resolve_env_file_path(config)) {
+            Ok(file_vars) => vars.extend(file_vars),
+            Err(err) => env_file_error = Some(err),
+        }
+    }
+    for (key, value) in &config.env {
+        let key = key.trim();
+        if key.is_empty() {
+            continue;
+        }
+        vars.push((key.to_string(), value.trim().to_string()));
+    }
+    (vars, env_file_error)
+}
+
+/// Copy of `config` with `${VAR}` placeholders in the command, arguments, and working
+/// directory filled in. The entry's own variables win over the environment it starts with.
+fn expand_config_placeholders(
+    config: &ProcessConfig,
+    process_env: &[(String, String)],
+) -> Result<ProcessConfig, String> {
+    let base_env: Vec<(String, String)> = if config.inherit_env {
+        std::env::vars().collect()
+    } else {
+        minimal_env_vars()
+    };
+    // Windows variable names are case-insensitive.
+    let same_name = |key: &str, name: &str| {
+        if cfg!(windows) {
+            key.eq_ignore_ascii_case(name)
+        } else {
+            key == name
+        }
+    };
+    let lookup = |name: &str| {
+        process_env
+            .iter()
+            .rev()
+            .chain(base_env.iter())
+            .find(|(key, _)| same_name(key, name))
+            .map(|(_, value)| value.clone())
+    };
+    let expand = |field: &str, text: &str| {
+        expand_placeholders(text, lookup).map_err(|err| format!("{} in the {}", err, field))
+    };
+
+    let mut expanded = config.clone();
+    expanded.command = expand("command", &config.command)?;
+    expanded.args = config
+        .args
+        .iter()
+        .map(|arg| expand("arguments", arg))
+        .collect::<Result<_, _>>()?;
+    expanded.working_directory = expand("working directory", &config.working_directory)?;
+    Ok(expanded)
+}
+
+/// Replace each `${NAME}` in `text` with `lookup(NAME)`. Only identifier names are
+/// placeholders, so shell syntax like `${PORT:-3000}` or `${#items}` passes through untouched,
+/// and `$${` writes a literal `${`. Every undefined name is reported at once so a broken
+/// command is never launched with blanks in it.
+fn expand_placeholders(
+    text: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut expanded = String::with_capacity(text.len());
+    let mut undefined: Vec<&str> = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('$') {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        if let Some(escaped) = after.strip_prefix("${") {
+            expanded.push_str("${");
+            rest = escaped;
+            continue;
+        }
+        let name = after
+            .strip_prefix('{')
+            .and_then(|inner| inner.split_once('}'))
+            .map(|(name, _)| name)
+            .filter(|name| is_placeholder_name(name));
+        let Some(name) = name else {
+            expanded.push('$');
+            rest = after;
+            continue;
+        };
+        match lookup(name) {
+            Some(value) => expanded.push_str(&value),
+            None if !undefined.contains(&name) => undefined.push(name),
+            None => {}
+        }
+        rest = &after[name.len() + 2..];
+    }
+    expanded.push_str(rest);
+
+    match undefined.as_slice() {
+        [] => Ok(expanded),
+        [name] => Err(format!("Undefined variable ${{{}}}", name)),
+        names => Err(format!(
+            "Undefined variables {}",
+            names
+                .iter()
+                .map(|name| format!("${{{}}}", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// `[A-Za-z_][A-Za-z0-9_]*`, the names `${...}` placeholders accept.
+fn is_placeholder_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Base environment for entries with `inherit_env` off: a `PATH` covering the system
+/// directories and, on Windows, the few system variables most programs cannot start without.
+fn minimal_env_vars() -> Vec<(String, String)> {
+    #[cfg(windows)]
+    {
+        let mut vars: Vec<(String, String)> = [
+            "SystemRoot",
+            "SystemDrive",
+            "windir",
+            "ComSpec",
+            "PATHEXT",
+            "TEMP",
+            "TMP",
+        ]
+        .iter()
+        .filter_map(|key| {
+            std::env::var(key)
+                .ok()
+                .map(|value| (key.to_string(), value))
+        })
+        .collect();
+        let root = std::env::var("SystemRoot").unwrap_or_else(|_| "C:\\Windows".to_string());
+        vars.push((
+            "PATH".to_string(),
+            format!("{0}\\System32;{0};{0}\\System32\\Wbem", root),
+        ));
+        vars
+    }
+    #[cfg(not(windows))]
+    {
+        vec![(
+            "PATH".to_string(),
+            "/usr/local/bin:/usr/bin:/bin".to_string(),
+        )]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProcessHook {
+    PreStart,
+    PostStop,
+}
+
+impl ProcessHook {
+    fn label(self) -> &'static str {
+        match self {
+            ProcessHook::PreStart => "pre-start",
+            ProcessHook::PostStop => "post-stop",
+        }
+    }
+
+    fn command(self, config: &ProcessConfig) -> &str {
+        match self {
+            ProcessHook::PreStart => &config.pre_start,
+            ProcessHook::PostStop => &config.post_stop,
+        }
+    }
+}
+
+/// Run a `Process` entry's hook to completion in its working directory, appending the output
+/// to its log. A hook still running after `hook_timeout_secs` is killed and counts as failed.
+/// Blank hooks succeed immediately.
+fn run_hook(
+    id: &str,
+    hook: ProcessHook,
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+) -> Result<(), String> {
+    let (config, log_settings) = {
+        let mut processes = processes.lock().unwrap();
+        let Some(state) = processes.get_mut(id) else {
+            return Ok(());
+        };
+        let command = hook.command(&state.config);
+        if command.is_empty() || state.config.process_type != ProcessType::Process {
+            return Ok(());
+        }
+        let message = format!("[Running {} hook: {}]", hook.label(), command);
+        log_process_state_event(state, message);
+        (state.config.clone(), state.logs.settings.clone())
+    };
+    mark_log_event(&log_settings);
+
+    let command_line = hook.command(&config);
+    let built = if config.use_shell {
+        Ok(build_shell_command(command_line))
+    } else {
+        parse_command(command_line)
+            .and_then(|(program, args)| build_command(&program, &args, &config.working_directory))
+    };
+    let timeout = Duration::from_secs(config.hook_timeout_secs.max(1));
+    let result = built.and_then(|(mut cmd, _)| {
+        if !config.working_directory.is_empty() {
+            cmd.current_dir(&config.working_directory);
+        }
+        if !config.inherit_env {
+            cmd.env_clear();
+            cmd.envs(minimal_env_vars());
+        }
+        let (vars, _) = process_env_vars(&config);
+        cmd.envs(vars);
+        run_hook_command(cmd, timeout)
+    });
+
+    let mut processes = processes.lock().unwrap();
+    let outcome = match result {
+        Ok(output) => {
+            if let Some(state) = processes.get_mut(id) {
+                for stream in [&output.stdout, &output.stderr] {
+                    for line in String::from_utf8_lossy(stream).lines() {
+                        if line.trim().is_empty() {
+                            continue;
+                        }
+                        log_process_state_event(
+                            state,
+                            format!("[{}: {}]", hook.label(), sanitize_runtime_log_line(line)),
+                        );
+                    }
+                }
+            }
+            match output.status {
+                Some(status) if status.success() => Ok(()),
+                Some(status) => Err(format!(
+                    "{} hook failed with {}",
+                    hook.label(),
+                    exit_code(status)
+                        .map(|code| format!("exit code {}", code))
+                        .unwrap_or_else(|| "no exit code".to_string())
+                )),
+                None => Err(format!(
+                    "{} hook timed out after {}s and was killed",
+                    hook.label(),
+                    timeout.as_secs()
+                )),
+            }
+        }
+        Err(err) => Err(format!("{} hook failed: {}", hook.label(), err)),
+    };
+    if let (Err(message), Some(state)) = (&outcome, processes.get_mut(id)) {
+        log_process_state_event(state, format!("[{}]", message));
+    }
+    drop(processes);
+    mark_log_event(&log_settings);
+    outcome
+}
+
+/// What a hook printed, and its exit status unless it was killed for running too long.
+struct HookOutput {
+    status: Option<ExitStatus>,
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+}
+
+/// Spawn a hook in its own process group and collect its output, killing the group if it is
+/// still running after `timeout`.
+fn run_hook_command(mut cmd: Command, timeout: Duration) -> Result<HookOutput, String> {
+    cmd.stdin(Stdio::null());
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+
+    let mut child = cmd.spawn().map_err(|err| err.to_string())?;
+    let read_all = |pipe: Option<Box<dyn Read + Send>>| {
+        thread::spawn(move || {
+            let mut bytes = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut bytes);
+            }
+            bytes
+        })
+    };
+    let stdout = read_all(child.stdout.take().map(|pipe| Box::new(pipe) as _));
+    let stderr = read_all(child.stderr.take().map(|pipe| Box::new(pipe) as _));
+
+    let finished = wait_for_child_exit(&mut child, timeout);
+    if !finished && kill_process_tree(child.id(), "").is_err() {
+        let _ = child.kill();
+    }
+    let status = child.wait().map_err(|err| err.to_string())?;
+    Ok(HookOutput {
+        status: finished.then_some(status),
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+/// Fail before launch, with sudo's own message, when switching to `user` would prompt for a
+/// password or is not allowed. Blank means the manager's own user.
+#[cfg(unix)]
+fn check_run_as(user: &str) -> Result<(), String> {
+    if user.is_empty() {
+        return Ok(());
+    }
+    let output = Command::new("sudo")
+        .args(["-n", "-u", user, "--", "true"])
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|err| format!("Cannot run as '{}': sudo is unavailable ({})", user, err))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let reason = stderr.lines().next().unwrap_or("").trim();
+    Err(format!(
+        "Cannot run as '{}': {}",
+        user,
+        if reason.is_empty() {
+            "sudo refused"
+        } else {
+            reason
+        }
+    ))
+}
+
+#[cfg(windows)]
+fn check_run_as(user: &str) -> Result<(), String> {
+    if user.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Cannot run as '{}': running as another user is not supported on Windows. Start the manager elevated instead.",
+            user
+        ))
+    }
+}
+
+/// Re-target a built command through non-interactive sudo. sudo resets the environment to its
+/// own minimal one, so the per-process variables are set on sudo itself and named in
+/// `--preserve-env`, which keeps their values off the command line where `ps` would show them.
+#[cfg(unix)]
+fn wrap_run_as(
+    cmd: &Command,
+    user: &str,
+    process_env: &[(String, String)],
+    inherit_env: bool,
+) -> Command {
+    let mut wrapped = Command::new("sudo");
+    wrapped.arg("-n");
+    let mut names: Vec<&str> = Vec::new();
+    for (key, _) in process_env {
+        if !names.contains(&key.as_str()) {
+            names.push(key);
+        }
+    }
+    if !names.is_empty() {
+        wrapped.arg(format!("--preserve-env={}", names.join(",")));
+    }
+    wrapped.args(["-u", user, "--"]);
+    wrapped.arg(cmd.get_program());
+    wrapped.args(cmd.get_args());
+
+    if !inherit_env {
+        wrapped.env_clear();
+    }
+    for (key, value) in cmd.get_envs() {
+        match value {
+            Some(value) => wrapped.env(key, value),
+            None => wrapped.env_remove(key),
+        };
+    }
+    wrapped
+}
+
+#[cfg(not(windows))]
+fn build_shell_command(command_line: &str) -> (Command, String) {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command_line);
+    (cmd, "sh -c".to_string())
+}
+
+#[cfg(windows)]
+fn build_shell_command(command_line: &str) -> (Command, String) {
+    use std::os::windows::process::CommandExt;
+    // raw_arg keeps cmd's own quoting rules intact instead of escaping the line again.
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C").raw_arg(command_line);
+    (cmd, "cmd /C".to_string())
+}
+
 #[cfg(not(windows))]
 fn build_command(
     program: &str,
@@ -2432,6 +5715,36 @@ fn build_command(
     Ok((cmd, program.to_string()))
 }
 
+/// Locate `program` the way a spawn would: paths with a separator are taken from the working
+/// directory (or as given when absolute), bare names are looked up on PATH.
+#[cfg(unix)]
+fn find_program(program: &str, working_directory: &str) -> Result<PathBuf, String> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let is_executable = |path: &Path| {
+        fs::metadata(path)
+            .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+    };
+    let program = program.trim();
+    if program.contains('/') {
+        let path = Path::new(working_directory.trim()).join(program);
+        return if is_executable(&path) {
+            Ok(path)
+        } else {
+            Err(format!(
+                "Program not found or not executable: {}",
+                path.display()
+            ))
+        };
+    }
+
+    let path_env = std::env::var_os("PATH").unwrap_or_default();
+    std::env::split_paths(&path_env)
+        .map(|dir| dir.join(program))
+        .find(|candidate| is_executable(candidate))
+        .ok_or_else(|| format!("Program not found on PATH: {}", program))
+}
+
 #[cfg(windows)]
 struct ResolvedProgram {
     path: String,
@@ -2584,6 +5897,347 @@ fn is_supported_extension(ext: &str) -> bool {
     matches!(ext, "exe" | "com" | "cmd" | "bat")
 }
 
+fn resolve_env_file_path(config: &ProcessConfig) -> PathBuf {
+    let path = PathBuf::from(config.env_file.trim());
+    if path.is_absolute() || config.working_directory.trim().is_empty() {
+        path
+    } else {
+        Path::new(config.working_directory.trim()).join(path)
+    }
+}
+
+struct WatchState {
+    /// Start generation the watcher was set up for.
+    generation: u64,
+    watcher: PathWatcher,
+    /// Most recent change still inside the debounce window.
+    pending: Option<(Instant, PathBuf)>,
+}
+
+/// Change source for one entry's watch paths: kernel file events where available, otherwise a
+/// modification-time scan on each poll.
+enum PathWatcher {
+    #[cfg(target_os = "linux")]
+    Inotify(InotifyWatcher),
+    Scan {
+        roots: Vec<PathBuf>,
+        files: HashMap<PathBuf, SystemTime>,
+    },
+}
+
+impl PathWatcher {
+    fn new(config: &ProcessConfig) -> Self {
+        let roots = watch_roots(config);
+        #[cfg(target_os = "linux")]
+        if let Some(watcher) = InotifyWatcher::new(&roots) {
+            return PathWatcher::Inotify(watcher);
+        }
+        PathWatcher::Scan {
+            files: scan_watch_roots(&roots),
+            roots,
+        }
+    }
+
+    /// A path that changed since the previous call, if any.
+    fn changed_path(&mut self) -> Option<PathBuf> {
+        match self {
+            #[cfg(target_os = "linux")]
+            PathWatcher::Inotify(watcher) => watcher.changed_path(),
+            PathWatcher::Scan { roots, files } => {
+                let after = scan_watch_roots(roots);
+                let changed = changed_watch_path(files, &after);
+                *files = after;
+                changed
+            }
+        }
+    }
+}
+
+/// The entry's watch paths, resolved against its working directory.
+fn watch_roots(config: &ProcessConfig) -> Vec<PathBuf> {
+    config
+        .watch_paths
+        .iter()
+        .map(|path| {
+            let path = PathBuf::from(path.trim());
+            let path = if path.is_absolute() || config.working_directory.trim().is_empty() {
+                path
+            } else {
+                Path::new(config.working_directory.trim()).join(path)
+            };
+            // Drops `.` segments so trigger paths read cleanly in the log.
+            path.components().collect()
+        })
+        .collect()
+}
+
+/// Modification times of every file under the watch roots, skipping ignored folders.
+fn scan_watch_roots(roots: &[PathBuf]) -> HashMap<PathBuf, SystemTime> {
+    let mut files = HashMap::new();
+    for path in roots {
+        collect_modified_times(path, &mut files);
+    }
+    files
+}
+
+#[cfg(target_os = "linux")]
+const INOTIFY_EVENT_MASK: u32 = libc::IN_CREATE
+    | libc::IN_DELETE
+    | libc::IN_MODIFY
+    | libc::IN_ATTRIB
+    | libc::IN_MOVED_FROM
+    | libc::IN_MOVED_TO;
+
+/// Recursive inotify watch over an entry's watch paths. Folders get their own watch (new ones
+/// are added as they appear); a watched file is followed through its parent folder so editors
+/// that save by replacing the file keep triggering.
+#[cfg(target_os = "linux")]
+struct InotifyWatcher {
+    fd: std::os::fd::OwnedFd,
+    roots: Vec<PathBuf>,
+    /// Watched folder per watch descriptor, and whether every entry in it counts.
+    dirs: HashMap<i32, (PathBuf, bool)>,
+    /// Watched files whose parent folder is only watched on their behalf.
+    files: std::collections::HashSet<PathBuf>,
+}
+
+#[cfg(target_os = "linux")]
+impl InotifyWatcher {
+    /// `None` when inotify is unavailable or its watch limit runs out, so the caller can scan.
+    fn new(roots: &[PathBuf]) -> Option<Self> {
+        use std::os::fd::FromRawFd;
+
+        let fd = unsafe { libc::inotify_init1(libc::IN_NONBLOCK | libc::IN_CLOEXEC) };
+        if fd < 0 {
+            return None;
+        }
+        let mut watcher = Self {
+            fd: unsafe { std::os::fd::OwnedFd::from_raw_fd(fd) },
+            roots: roots.to_vec(),
+            dirs: HashMap::new(),
+            files: std::collections::HashSet::new(),
+        };
+        for root in roots {
+            let Ok(metadata) = fs::symlink_metadata(root) else {
+                continue;
+            };
+            let added = if metadata.is_dir() {
+                watcher.watch_tree(root)
+            } else {
+                watcher.files.insert(root.clone());
+                root.parent()
+                    .is_none_or(|parent| watcher.watch_dir(parent, false))
+            };
+            if !added {
+                return None;
+            }
+        }
+        Some(watcher)
+    }
+
+    /// Watch a folder and every folder below it that is not ignored.
+    fn watch_tree(&mut self, dir: &Path) -> bool {
+        if !self.watch_dir(dir, true) {
+            return false;
+        }
+        let Ok(entries) = fs::read_dir(dir) else {
+            return true;
+        };
+        for entry in entries.flatten() {
+            let is_dir = entry.file_type().is_ok_and(|kind| kind.is_dir());
+            if is_dir
+                && !is_ignored_watch_dir(&entry.file_name())
+                && !self.watch_tree(&entry.path())
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Returns false only when the kernel refuses more watches.
+    fn watch_dir(&mut self, dir: &Path, recursive: bool) -> bool {
+        use std::os::fd::AsRawFd;
+        use std::os::unix::ffi::OsStrExt;
+
+        let Ok(c_path) = std::ffi::CString::new(dir.as_os_str().as_bytes()) else {
+            return true;
+        };
+        let wd = unsafe {
+            libc::inotify_add_watch(self.fd.as_raw_fd(), c_path.as_ptr(), INOTIFY_EVENT_MASK)
+        };
+        if wd < 0 {
+            let error = std::io::Error::last_os_error().raw_os_error();
+            return !matches!(error, Some(libc::ENOSPC) | Some(libc::ENOMEM));
+        }
+        let entry = self
+            .dirs
+            .entry(wd)
+            .or_insert_with(|| (dir.to_path_buf(), recursive));
+        entry.1 |= recursive;
+        true
+    }
+
+    /// Drain queued events and return the last path that counts as a change.
+    fn changed_path(&mut self) -> Option<PathBuf> {
+        use std::os::fd::AsRawFd;
+        use std::os::unix::ffi::OsStrExt;
+
+        const HEADER: usize = std::mem::size_of::<libc::inotify_event>();
+        let mut buffer = [0u8; 4096];
+        let mut changed = None;
+        let mut new_dirs = Vec::new();
+        loop {
+            let read = unsafe {
+                libc::read(
+                    self.fd.as_raw_fd(),
+                    buffer.as_mut_ptr().cast(),
+                    buffer.len(),
+                )
+            };
+            if read <= 0 {
+                break;
+            }
+            let read = read as usize;
+            let mut offset = 0;
+            while offset + HEADER <= read {
+                let event: libc::inotify_event =
+                    unsafe { std::ptr::read_unaligned(buffer.as_ptr().add(offset).cast()) };
+                let name_end = (offset + HEADER + event.len as usize).min(read);
+                let name = buffer[offset + HEADER..name_end]
+                    .split(|byte| *byte == 0)
+                    .next()
+                    .unwrap_or_default();
+                offset = name_end;
+
+                if event.mask & libc::IN_Q_OVERFLOW != 0 {
+                    changed = changed.or_else(|| self.roots.first().cloned());
+                    continue;
+                }
+                if event.mask & libc::IN_IGNORED != 0 {
+                    self.dirs.remove(&event.wd);
+                    continue;
+                }
+                let Some((dir, recursive)) = self.dirs.get(&event.wd) else {
+                    continue;
+                };
+                let is_dir = event.mask & libc::IN_ISDIR != 0;
+                let name = std::ffi::OsStr::from_bytes(name);
+                let path = if name.is_empty() {
+                    dir.clone()
+                } else {
+                    dir.join(name)
+                };
+                if !recursive {
+                    if self.files.contains(&path) {
+                        changed = Some(path);
+                    }
+                    continue;
+                }
+                if is_dir && is_ignored_watch_dir(name) {
+                    continue;
+                }
+                if is_dir && event.mask & (libc::IN_CREATE | libc::IN_MOVED_TO) != 0 {
+                    new_dirs.push(path.clone());
+                }
+                changed = Some(path);
+            }
+        }
+        for dir in new_dirs {
+            self.watch_tree(&dir);
+        }
+        changed
+    }
+}
+
+fn is_ignored_watch_dir(name: &std::ffi::OsStr) -> bool {
+    name.to_str()
+        .is_some_and(|name| WATCH_IGNORED_DIRS.contains(&name))
+}
+
+fn collect_modified_times(path: &Path, files: &mut HashMap<PathBuf, SystemTime>) {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return;
+    };
+    if !metadata.is_dir() {
+        if let Ok(modified) = metadata.modified() {
+            files.insert(path.to_path_buf(), modified);
+        }
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(path) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let ignored = entry.file_type().is_ok_and(|kind| kind.is_dir())
+            && is_ignored_watch_dir(&entry.file_name());
+        if !ignored {
+            collect_modified_times(&entry.path(), files);
+        }
+    }
+}
+
+/// A path that was added, modified, or removed between two scans.
+fn changed_watch_path(
+    before: &HashMap<PathBuf, SystemTime>,
+    after: &HashMap<PathBuf, SystemTime>,
+) -> Option<PathBuf> {
+    after
+        .iter()
+        .find(|(path, modified)| before.get(*path) != Some(*modified))
+        .or_else(|| before.iter().find(|(path, _)| !after.contains_key(*path)))
+        .map(|(path, _)| path.clone())
+}
+
+fn load_env_file(path: &Path) -> Result<Vec<(String, String)>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("Could not read env file '{}': {}", path.display(), err))?;
+    Ok(parse_env_file(&content))
+}
+
+/// Parse simple dotenv content: `KEY=VALUE` lines, `#` comments, and blank lines.
+fn parse_env_file(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                return None;
+            }
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let key = key.trim();
+            if key.is_empty() {
+                return None;
+            }
+            let value = value.trim();
+            let value = [('"', '"'), ('\'', '\'')]
+                .iter()
+                .find_map(|(open, close)| {
+                    value
+                        .strip_prefix(*open)
+                        .and_then(|rest| rest.strip_suffix(*close))
+                })
+                .unwrap_or(value);
+            Some((key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Explicit `args` bypass tokenization; otherwise the command string is parsed.
+fn program_and_args(config: &ProcessConfig) -> Result<(String, Vec<String>), String> {
+    if config.args.is_empty() {
+        return parse_command(&config.command);
+    }
+
+    let program = config.command.trim();
+    if program.is_empty() {
+        return Err("Command is empty".to_string());
+    }
+    Ok((program.to_string(), config.args.clone()))
+}
+
 fn parse_command(command: &str) -> Result<(String, Vec<String>), String> {
     let mut args = Vec::new();
     let mut current = String::new();
@@ -2638,10 +6292,202 @@ fn parse_command(command: &str) -> Result<(String, Vec<String>), String> {
     Ok((program, args))
 }
 
-#[cfg(windows)]
-fn kill_process_tree(pid: u32) -> Result<(), String> {
-    let mut cmd = Command::new("taskkill");
-    cmd.args(["/PID", &pid.to_string(), "/T", "/F"]);
+/// Whether something already accepts or holds TCP connections on `port` locally.
+fn port_in_use(port: u16) -> bool {
+    if let Err(err) = std::net::TcpListener::bind(("0.0.0.0", port)) {
+        return err.kind() == std::io::ErrorKind::AddrInUse;
+    }
+    // Windows allows a wildcard bind next to a loopback listener, so also try connecting.
+    let address = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+    std::net::TcpStream::connect_timeout(&address, PORT_CHECK_CONNECT_TIMEOUT).is_ok()
+}
+
+/// Log a warning when `expected_port` is taken before launch. With `free_port` set, the
+/// listening processes are killed and the port gets a moment to come free.
+fn check_expected_port(
+    id: &str,
+    port: u16,
+    free_port: bool,
+    processes_arc: &Arc<Mutex<HashMap<String, ProcessState>>>,
+) {
+    if !port_in_use(port) {
+        return;
+    }
+
+    let holders: Vec<u32> = port_holder_pids(port)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|pid| *pid != std::process::id())
+        .collect();
+    let held_by = if holders.is_empty() {
+        String::new()
+    } else {
+        format!(
+            " (held by PID {})",
+            holders
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+
+    let message = if !free_port || holders.is_empty() {
+        format!(
+            "[Warning: port {} already in use before start{}.]",
+            port, held_by
+        )
+    } else {
+        let mut failures = Vec::new();
+        for pid in &holders {
+            if let Err(err) = kill_process_tree(*pid, "") {
+                failures.push(err);
+            }
+        }
+        let deadline = Instant::now() + PORT_RELEASE_TIMEOUT;
+        while port_in_use(port) && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(100));
+        }
+        if failures.is_empty() && !port_in_use(port) {
+            format!(
+                "[Port {} was already in use before start{}. Killed the holder.]",
+                port, held_by
+            )
+        } else {
+            failures.insert(0, format!("port {} is still in use{}", port, held_by));
+            format!("[Warning: {}.]", failures.join("; "))
+        }
+    };
+
+    let mut processes = processes_arc.lock().unwrap();
+    if let Some(state) = processes.get_mut(id) {
+        log_process_state_event(state, message);
+    }
+}
+
+/// Pids of processes listening on `port`, via `lsof`.
+#[cfg(unix)]
+fn port_holder_pids(port: u16) -> Result<Vec<u32>, String> {
+    let output = Command::new("lsof")
+        .args(["-nP", "-t", &format!("-iTCP:{}", port), "-sTCP:LISTEN"])
+        .output()
+        .map_err(|e| format!("Failed to run lsof: {}", e))?;
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.trim().parse().ok())
+        .collect())
+}
+
+/// Pids of processes listening on `port`, via `netstat -ano`.
+#[cfg(windows)]
+fn port_holder_pids(port: u16) -> Result<Vec<u32>, String> {
+    let mut cmd = Command::new("netstat");
+    cmd.args(["-ano", "-p", "TCP"]);
+    use std::os::windows::process::CommandExt;
+    cmd.creation_flags(0x08000000);
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run netstat: {}", e))?;
+    let suffix = format!(":{}", port);
+    let mut pids = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let columns: Vec<&str> = line.split_whitespace().collect();
+        if let [_, local, _, state, pid] = columns.as_slice() {
+            if local.ends_with(&suffix) && state.eq_ignore_ascii_case("LISTENING") {
+                if let Ok(pid) = pid.parse::<u32>() {
+                    if !pids.contains(&pid) {
+                        pids.push(pid);
+                    }
+                }
+            }
+        }
+    }
+    Ok(pids)
+}
+
+/// Ask a process group to exit on its own before any force-kill. Returns false if the
+/// request could not be delivered, so there is no point waiting for it.
+#[cfg(unix)]
+fn request_graceful_stop(pid: u32, run_as: &str) -> bool {
+    signal_process_group(pid, libc::SIGTERM, run_as).is_ok()
+}
+
+/// Signal the group led by `pid`, falling back to just the process if it has no group of its own.
+/// A `run_as` group is led by the root-owned `sudo`, so it is signalled as that user instead.
+#[cfg(unix)]
+fn signal_process_group(pid: u32, signal: libc::c_int, run_as: &str) -> Result<(), String> {
+    if !run_as.is_empty() {
+        return signal_process_group_as(pid, signal, run_as);
+    }
+    let pid = pid as libc::pid_t;
+    if unsafe { libc::kill(-pid, signal) } == 0 || unsafe { libc::kill(pid, signal) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error().to_string())
+    }
+}
+
+/// `sudo -n -u <user> kill -<signal> -- -<pgid>`, which reaches the user's processes in the
+/// group even though the manager may not signal the `sudo` leading it.
+#[cfg(unix)]
+fn signal_process_group_as(pid: u32, signal: libc::c_int, user: &str) -> Result<(), String> {
+    let output = Command::new("sudo")
+        .args(["-n", "-u", user, "--", "kill"])
+        .arg(format!("-{}", signal))
+        .arg("--")
+        .arg(format!("-{}", pid))
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|err| format!("sudo is unavailable ({})", err))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    match stderr.lines().next().map(str::trim) {
+        Some(reason) if !reason.is_empty() => Err(reason.to_string()),
+        _ => Err(format!("kill as '{}' failed", user)),
+    }
+}
+
+#[cfg(unix)]
+fn kill_process_tree(pid: u32, run_as: &str) -> Result<(), String> {
+    signal_process_group(pid, libc::SIGKILL, run_as)
+        .map_err(|e| format!("Failed to kill process group {}: {}", pid, e))
+}
+
+/// Ask a process tree to close on its own before any force-kill. Returns false when taskkill
+/// refuses, as it does for console programs that have no window to close.
+#[cfg(windows)]
+fn request_graceful_stop(pid: u32, _run_as: &str) -> bool {
+    let mut cmd = Command::new("taskkill");
+    cmd.args(["/PID", &pid.to_string(), "/T"]);
+    use std::os::windows::process::CommandExt;
+    cmd.creation_flags(0x08000000);
+    cmd.output().is_ok_and(|output| output.status.success())
+}
+
+/// Poll until the child exits or the timeout elapses. Returns true if it exited.
+fn wait_for_child_exit(child: &mut Child, timeout: Duration) -> bool {
+    let start = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => return true,
+            Ok(None) => {}
+            Err(_) => return false,
+        }
+
+        if start.elapsed() >= timeout {
+            return false;
+        }
+
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+#[cfg(windows)]
+fn kill_process_tree(pid: u32, _run_as: &str) -> Result<(), String> {
+    let mut cmd = Command::new("taskkill");
+    cmd.args(["/PID", &pid.to_string(), "/T", "/F"]);
     use std::os::windows::process::CommandExt;
     cmd.creation_flags(0x08000000);
     let output = cmd
@@ -2656,31 +6502,186 @@ fn kill_process_tree(pid: u32) -> Result<(), String> {
     }
 }
 
-fn refresh_docker_status_inner(
-    id: &str,
-    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
-    log_directory: &Arc<Mutex<String>>,
-    event_tx: &watch::Sender<u64>,
-    event_version: &Arc<AtomicU64>,
-    error_version: &Arc<AtomicU64>,
-    process_error_versions: &Arc<Mutex<HashMap<String, u64>>>,
-) {
-    let container_name = {
-        let processes = processes.lock().unwrap();
-        if let Some(state) = processes.get(id) {
-            if state.config.process_type == ProcessType::Docker {
-                Some(state.config.command.clone())
-            } else {
-                None
+/// A docker-managed entry: either a single container or one service of a compose file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum DockerTarget {
+    Container(String),
+    ComposeService { file: String, service: String },
+}
+
+impl DockerTarget {
+    fn from_config(config: &ProcessConfig) -> Option<Self> {
+        match config.process_type {
+            ProcessType::Process => None,
+            ProcessType::Docker => Some(Self::Container(config.command.trim().to_string())),
+            ProcessType::Compose => Some(Self::ComposeService {
+                file: config.compose_file.trim().to_string(),
+                service: config.command.trim().to_string(),
+            }),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Container(name) => format!("Docker container '{}'", name),
+            Self::ComposeService { service, .. } => format!("Compose service '{}'", service),
+        }
+    }
+
+    /// Fail early with a readable message when the container is missing or Docker is unavailable.
+    /// Compose services are created by `up`, so only plain containers are checked.
+    fn check_exists(&self) -> Result<(), String> {
+        match self {
+            Self::Container(name) if !self.container_exists()? => {
+                Err(format!("Container '{}' not found. Is it created?", name))
             }
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether `docker container inspect` finds the container. Errors only when Docker
+    /// itself cannot be reached. Compose services always count as existing.
+    fn container_exists(&self) -> Result<bool, String> {
+        let Self::Container(name) = self else {
+            return Ok(true);
+        };
+        let output = self
+            .docker(&["container", "inspect", "--format", "{{.Id}}", name])
+            .output()
+            .map_err(|e| docker_spawn_error(&e))?;
+        if output.status.success() {
+            return Ok(true);
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        match docker_daemon_error(&stderr) {
+            Some(message) => Err(message),
+            None => Ok(false),
+        }
+    }
+
+    /// Config-time check for the process dialog's Test button. Unlike `check_exists`, compose
+    /// services are checked too, by asking compose which services the file defines.
+    fn validate(&self) -> Result<String, String> {
+        let Self::ComposeService { file, service } = self else {
+            self.check_exists()?;
+            return Ok(format!("{} exists.", self.describe()));
+        };
+        let output = self
+            .docker(&["config", "--services"])
+            .output()
+            .map_err(|e| docker_spawn_error(&e))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(docker_daemon_error(&stderr).unwrap_or_else(|| {
+                format!("Could not read compose file {}: {}", file, stderr.trim())
+            }));
+        }
+        if String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .any(|line| line.trim() == service)
+        {
+            Ok(format!("{} is defined in {}.", self.describe(), file))
         } else {
-            None
+            Err(format!("{} has no service '{}'.", file, service))
         }
-    };
+    }
+
+    /// What to pass `docker stats`: the container itself, or the ids of the service's running
+    /// containers (one per replica). Empty when a compose service has none up.
+    fn stats_containers(&self) -> Vec<String> {
+        let service = match self {
+            Self::Container(name) => return vec![name.clone()],
+            Self::ComposeService { service, .. } => service,
+        };
+        match self.docker(&["ps", "-q", service]).output() {
+            Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(str::to_string)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn start_command(&self) -> Command {
+        match self {
+            Self::Container(name) => self.docker(&["start", name]),
+            Self::ComposeService { service, .. } => self.docker(&["up", "-d", service]),
+        }
+    }
+
+    /// `docker run -d --name <container> <run_args>`. Only plain containers are created this way.
+    fn run_command(&self, run_args: &[String]) -> Option<Command> {
+        let Self::Container(name) = self else {
+            return None;
+        };
+        let mut cmd = self.docker(&["run", "-d", "--name", name]);
+        cmd.args(run_args);
+        Some(cmd)
+    }
 
-    if let Some(container_name) = container_name {
+    fn stop_command(&self) -> Command {
+        match self {
+            Self::Container(name) => self.docker(&["stop", name]),
+            Self::ComposeService { service, .. } => self.docker(&["stop", service]),
+        }
+    }
+
+    /// Follow the logs, starting with the last `tail` lines (all of them for `None`) and
+    /// skipping anything older than `since` when it is set.
+    fn logs_command(&self, tail: Option<u32>, since: &str) -> Command {
+        let tail = tail.map_or_else(|| "all".to_string(), |lines| lines.to_string());
+        let mut args = vec!["logs", "-f", "--tail", tail.as_str()];
+        if !since.is_empty() {
+            args.extend(["--since", since]);
+        }
+        match self {
+            Self::Container(name) => args.push(name),
+            Self::ComposeService { service, .. } => args.extend(["--no-log-prefix", service]),
+        }
+        self.docker(&args)
+    }
+
+    fn exec_command(&self, args: &[String]) -> Command {
+        let mut cmd = match self {
+            Self::Container(name) => self.docker(&["exec", name]),
+            Self::ComposeService { service, .. } => self.docker(&["exec", "-T", service]),
+        };
+        cmd.args(args);
+        cmd
+    }
+
+    fn status_command(&self) -> Command {
+        match self {
+            Self::Container(name) => self.docker(&["inspect", "-f", "{{.State.Running}}", name]),
+            Self::ComposeService { .. } => {
+                self.docker(&["ps", "--services", "--status", "running"])
+            }
+        }
+    }
+
+    /// Interpret the stdout of `status_command`.
+    fn is_running(&self, stdout: &str) -> bool {
+        match self {
+            Self::Container(_) => stdout.trim().eq_ignore_ascii_case("true"),
+            Self::ComposeService { service, .. } => {
+                stdout.lines().any(|line| line.trim() == service)
+            }
+        }
+    }
+
+    /// Build a hidden `docker` invocation, prefixing `compose -f <file>` for compose services.
+    fn docker(&self, args: &[&str]) -> Command {
         let mut cmd = Command::new("docker");
-        cmd.args(["inspect", "-f", "{{.State.Running}}", &container_name]);
+        if let Self::ComposeService { file, .. } = self {
+            cmd.arg("compose");
+            if !file.is_empty() {
+                cmd.args(["-f", file]);
+            }
+        }
+        cmd.args(args);
 
         #[cfg(windows)]
         {
@@ -2688,38 +6689,162 @@ fn refresh_docker_status_inner(
             cmd.creation_flags(0x08000000);
         }
 
-        if let Ok(output) = cmd.output() {
-            let is_running = String::from_utf8_lossy(&output.stdout)
-                .trim()
-                .eq_ignore_ascii_case("true");
+        cmd
+    }
+}
+
+/// Ask the daemon for its version; this fails fast when Docker is missing or not running.
+fn probe_docker_daemon() -> DockerStatus {
+    let mut cmd = Command::new("docker");
+    cmd.args(["version", "--format", "{{.Server.Version}}"]);
+    cmd.stdout(Stdio::null());
+    cmd.stderr(Stdio::null());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000);
+    }
+
+    match cmd.status() {
+        Ok(status) if status.success() => DockerStatus::Available,
+        _ => DockerStatus::Unavailable,
+    }
+}
+
+/// Explain a failure to launch the docker CLI at all.
+fn docker_spawn_error(err: &std::io::Error) -> String {
+    if err.kind() == std::io::ErrorKind::NotFound {
+        "Docker is not installed or not on PATH.".to_string()
+    } else {
+        err.to_string()
+    }
+}
+
+/// Recognize docker CLI output that means the daemon itself is unreachable.
+fn docker_daemon_error(stderr: &str) -> Option<String> {
+    let stderr = stderr.to_ascii_lowercase();
+    let unreachable = [
+        "cannot connect to the docker daemon",
+        "is the docker daemon running",
+        "error during connect",
+        "docker desktop is not running",
+    ]
+    .iter()
+    .any(|pattern| stderr.contains(pattern));
+    unreachable.then(|| "Docker daemon is not running. Start Docker and try again.".to_string())
+}
+
+/// Split an entry's `docker_run_args` with the same quoting rules as commands.
+fn parse_docker_run_args(run_args: &str) -> Result<Vec<String>, String> {
+    let (first, mut rest) =
+        parse_command(run_args).map_err(|err| format!("Docker run arguments: {}", err))?;
+    rest.insert(0, first);
+    Ok(rest)
+}
+
+/// The `docker run` command as it is logged, quoting arguments that contain spaces.
+fn docker_run_command_line(name: &str, run_args: &[String]) -> String {
+    let mut line = format!("docker run -d --name {}", name);
+    for arg in run_args {
+        line.push(' ');
+        if arg.is_empty() || arg.contains(char::is_whitespace) {
+            line.push_str(&format!("\"{}\"", arg));
+        } else {
+            line.push_str(arg);
+        }
+    }
+    line
+}
+
+/// Create a Docker entry's container with `docker run -d` when it does not exist yet and the
+/// entry has run arguments. Existing containers, and entries without arguments, are left alone.
+fn create_container_if_missing(
+    id: &str,
+    target: &DockerTarget,
+    config: &ProcessConfig,
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+) -> Result<(), String> {
+    if config.docker_run_args.is_empty() || target.container_exists()? {
+        return Ok(());
+    }
+    let run_args = parse_docker_run_args(&config.docker_run_args)?;
+    let Some(mut cmd) = target.run_command(&run_args) else {
+        return Ok(());
+    };
+    let log = |message: String| {
+        if let Some(state) = processes.lock().unwrap().get_mut(id) {
+            log_process_state_event(state, message);
+        }
+    };
+    log(format!(
+        "[Container not found. Creating it: {}]",
+        docker_run_command_line(config.command.trim(), &run_args)
+    ));
+
+    let output = cmd.output().map_err(|e| docker_spawn_error(&e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(docker_daemon_error(&stderr)
+            .unwrap_or_else(|| format!("docker run failed: {}", stderr.trim())));
+    }
+    let container_id = String::from_utf8_lossy(&output.stdout);
+    log(format!(
+        "[Created {} ({})]",
+        target.describe(),
+        container_id.trim().chars().take(12).collect::<String>()
+    ));
+    Ok(())
+}
+
+fn refresh_docker_status_inner(
+    id: &str,
+    processes: &Arc<Mutex<HashMap<String, ProcessState>>>,
+    log_directory: &Arc<Mutex<String>>,
+    event_tx: &watch::Sender<u64>,
+    event_version: &Arc<AtomicU64>,
+    error_version: &Arc<AtomicU64>,
+    process_error_versions: &Arc<Mutex<HashMap<String, u64>>>,
+) {
+    let target = {
+        let processes = processes.lock().unwrap();
+        processes
+            .get(id)
+            .and_then(|state| DockerTarget::from_config(&state.config))
+    };
+
+    if let Some(target) = target {
+        if let Ok(output) = target.status_command().output() {
+            let is_running = target.is_running(&String::from_utf8_lossy(&output.stdout));
 
             let mut updated = false;
-            let mut should_schedule_restart = false;
+            let mut had_error = false;
+            let mut restart_plan = None;
             {
                 let mut processes = processes.lock().unwrap();
                 if let Some(state) = processes.get_mut(id) {
                     if is_running {
                         if state.status != ProcessStatus::Running {
-                            state.status = ProcessStatus::Running;
+                            state.set_status(ProcessStatus::Running);
+                            state.started_at = Some(Instant::now());
                             updated = true;
                         }
-                    } else if state.config.auto_restart
-                        && managed_restart_active_now(&state.config)
+                    } else if state.config.restart_policy.restarts_after(None)
+                        && managed_restart_active_now(state)
                         && !state.suppress_restart_once
                         && state.status == ProcessStatus::Running
                     {
-                        log_process_state_event(
-                            state,
-                            "[Container stopped unexpectedly. Restarting...]".to_string(),
-                        );
-                        state.status = ProcessStatus::Starting;
-                        should_schedule_restart = true;
+                        restart_plan =
+                            plan_managed_restart(state, "Container stopped unexpectedly.");
+                        had_error = restart_plan.is_none();
+                        state.started_at = None;
                         updated = true;
                     } else {
                         if state.status != ProcessStatus::Stopped {
-                            state.status = ProcessStatus::Stopped;
+                            state.set_status(ProcessStatus::Stopped);
                             updated = true;
                         }
+                        state.started_at = None;
                         if state.suppress_restart_once {
                             state.suppress_restart_once = false;
                         }
@@ -2732,12 +6857,17 @@ fn refresh_docker_status_inner(
                 }
             }
 
+            if had_error {
+                bump_error(error_version, process_error_versions, id);
+            }
             if updated {
                 bump_event(event_tx, event_version);
             }
-            if should_schedule_restart {
+            if let Some((delay, restart_generation)) = restart_plan {
                 schedule_managed_restart(
                     id.to_string(),
+                    delay,
+                    restart_generation,
                     processes.clone(),
                     log_directory.clone(),
                     event_tx.clone(),
@@ -2752,7 +6882,26 @@ fn refresh_docker_status_inner(
 
 #[cfg(test)]
 mod tests {
-    use super::sanitize_runtime_log_line;
+    use super::{sanitize_runtime_log_line, LifecycleEventKind, ProcessStatus};
+    use crate::config::{ProcessConfig, ProcessType, DEFAULT_GROUP_NAME};
+    use std::time::Duration;
+
+    /// A `Process` entry named `name` that runs `true`.
+    fn test_config(name: &str) -> ProcessConfig {
+        ProcessConfig::new(
+            name.to_string(),
+            "true".to_string(),
+            String::new(),
+            ProcessType::Process,
+        )
+    }
+
+    /// A manager holding just `config`.
+    fn test_manager_with(config: &ProcessConfig) -> super::ProcessManager {
+        let manager = super::ProcessManager::new();
+        manager.init_from_config(std::slice::from_ref(config));
+        manager
+    }
 
     #[test]
     fn strips_ansi_csi_sequences() {
@@ -2765,4 +6914,1168 @@ mod tests {
         let line = "\u{1b}]0;Process Manager\u{7}server started";
         assert_eq!(sanitize_runtime_log_line(line), "server started");
     }
+
+    #[test]
+    fn watch_scan_skips_ignored_folders_and_spots_changes() {
+        let root = std::env::temp_dir().join(format!("pm-watch-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::create_dir_all(root.join("node_modules")).unwrap();
+        std::fs::write(root.join("src").join("main.js"), "one").unwrap();
+        std::fs::write(root.join("node_modules").join("dep.js"), "dep").unwrap();
+        let mut config = ProcessConfig::new(
+            "Web".to_string(),
+            "node src/main.js".to_string(),
+            root.display().to_string(),
+            ProcessType::Process,
+        );
+        config.watch_paths = vec![".".to_string()];
+
+        let roots = super::watch_roots(&config);
+        let before = super::scan_watch_roots(&roots);
+        std::fs::write(root.join("src").join("extra.js"), "two").unwrap();
+        let after = super::scan_watch_roots(&roots);
+        let _ = std::fs::remove_dir_all(&root);
+
+        assert_eq!(before.len(), 1);
+        assert_eq!(
+            super::changed_watch_path(&before, &after),
+            Some(root.join("src").join("extra.js"))
+        );
+        assert_eq!(super::changed_watch_path(&after, &after), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn inotify_watch_reports_changes_outside_ignored_folders() {
+        let root = std::env::temp_dir().join(format!("pm-inotify-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::create_dir_all(root.join("node_modules")).unwrap();
+        let mut watcher = super::InotifyWatcher::new(std::slice::from_ref(&root)).unwrap();
+
+        std::fs::write(root.join("node_modules").join("dep.js"), "dep").unwrap();
+        let ignored = watcher.changed_path();
+        std::fs::write(root.join("src").join("main.js"), "one").unwrap();
+        let changed = watcher.changed_path();
+        std::fs::create_dir(root.join("lib")).unwrap();
+        watcher.changed_path();
+        std::fs::write(root.join("lib").join("util.js"), "two").unwrap();
+        let nested = watcher.changed_path();
+        let _ = std::fs::remove_dir_all(&root);
+
+        assert_eq!(ignored, None);
+        assert_eq!(changed, Some(root.join("src").join("main.js")));
+        assert_eq!(nested, Some(root.join("lib").join("util.js")));
+    }
+
+    #[test]
+    fn export_logs_writes_header_for_empty_buffer() {
+        let config = test_config("API");
+        let manager = test_manager_with(&config);
+        let id = config.id.clone();
+
+        let path = std::env::temp_dir().join(format!("pm-export-{}.log", id));
+        manager
+            .export_logs(&id, &path)
+            .expect("export should succeed");
+        let content = std::fs::read_to_string(&path).expect("export should exist");
+        let _ = std::fs::remove_file(&path);
+
+        assert!(content.starts_with("# API\n# Exported "));
+        assert!(content.ends_with("\n\n"));
+    }
+
+    #[test]
+    fn export_all_logs_writes_a_file_per_process_and_a_manifest() {
+        let manager = super::ProcessManager::new();
+        for name in ["API", "api", "Web/UI"] {
+            manager.add_process(test_config(name));
+        }
+
+        let dir = std::env::temp_dir().join(format!("pm-export-all-{}", uuid::Uuid::new_v4()));
+        manager
+            .export_all_logs(&dir)
+            .expect("export should succeed");
+        let mut files: Vec<String> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        files.sort();
+        let manifest = std::fs::read_to_string(dir.join("manifest.txt")).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            files,
+            vec!["API.log", "Web_UI.log", "api (2).log", "manifest.txt"]
+        );
+        assert!(manifest.contains("- Web/UI [Process] Stopped -> Web_UI.log"));
+        assert!(manifest.contains("## Lifecycle events"));
+    }
+
+    #[test]
+    fn dependency_order_starts_dependencies_first_and_isolates_cycles() {
+        let ids: Vec<String> = ["web", "api", "db", "a", "b"]
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+        let depends_on: std::collections::HashMap<String, Vec<String>> = [
+            ("web", vec!["api"]),
+            ("api", vec!["db", "outside"]),
+            ("a", vec!["b"]),
+            ("b", vec!["a"]),
+        ]
+        .into_iter()
+        .map(|(id, deps)| (id.to_string(), deps.into_iter().map(String::from).collect()))
+        .collect();
+
+        let (ordered, blocked) = super::dependency_start_order(&ids, &depends_on);
+        assert_eq!(ordered, ["db", "api", "web"]);
+        assert_eq!(blocked, ["a", "b"]);
+    }
+
+    #[test]
+    fn stack_actions_follow_sidebar_order() {
+        let manager = super::ProcessManager::new();
+        let configs: Vec<ProcessConfig> = ["web", "api", "db"]
+            .iter()
+            .map(|name| test_config(name))
+            .collect();
+        let ids: Vec<String> = configs.iter().map(|config| config.id.clone()).collect();
+        manager.init_from_config(&configs);
+        assert_eq!(manager.ordered_ids_where(|_| true), ids);
+
+        let reordered = vec![ids[2].clone(), ids[0].clone(), ids[1].clone()];
+        manager.set_process_order(reordered.clone());
+        assert_eq!(manager.ordered_ids_where(|_| true), reordered);
+    }
+
+    #[test]
+    fn combined_logs_interleave_by_time_and_keep_the_newest() {
+        let manager = super::ProcessManager::new();
+        let configs: Vec<ProcessConfig> = ["API", "Web"]
+            .iter()
+            .map(|name| test_config(name))
+            .collect();
+        manager.init_from_config(&configs);
+        let base = std::time::SystemTime::UNIX_EPOCH;
+        {
+            let mut processes = manager.processes.lock().unwrap();
+            for (id, lines) in [
+                (&configs[0].id, [(1, "api one"), (4, "api two")]),
+                (&configs[1].id, [(2, "web one"), (4, "web two")]),
+            ] {
+                let logs = &mut processes.get_mut(id).unwrap().logs;
+                for (secs, text) in lines {
+                    logs.push_entry(super::LogSource::Stdout, text.to_string());
+                    logs.lines.back_mut().unwrap().at = base + Duration::from_secs(secs);
+                }
+            }
+        }
+
+        let lines = |limit| -> Vec<(String, String)> {
+            manager
+                .combined_logs(limit)
+                .into_iter()
+                .map(|line| (line.process_name, line.entry.text))
+                .collect()
+        };
+        let pair = |name: &str, text: &str| (name.to_string(), text.to_string());
+        assert_eq!(
+            lines(10),
+            vec![
+                pair("API", "api one"),
+                pair("Web", "web one"),
+                pair("API", "api two"),
+                pair("Web", "web two"),
+            ]
+        );
+        assert_eq!(
+            lines(2),
+            vec![pair("API", "api two"), pair("Web", "web two")]
+        );
+    }
+
+    #[test]
+    fn sync_with_config_adds_removes_and_updates_entries() {
+        let manager = super::ProcessManager::new();
+        let configs: Vec<ProcessConfig> = ["API", "Web"]
+            .iter()
+            .map(|name| test_config(name))
+            .collect();
+        manager.init_from_config(&configs);
+
+        let mut edited = configs[1].clone();
+        edited.command = "npm start".to_string();
+        let added = test_config("Worker");
+        manager.sync_with_config(&[added.clone(), edited.clone()]);
+
+        assert_eq!(
+            manager.ordered_ids_where(|_| true),
+            vec![added.id.clone(), edited.id.clone()]
+        );
+        assert_eq!(manager.get_status(&configs[0].id), None);
+        assert_eq!(
+            manager.get_process_config(&edited.id).unwrap().command,
+            "npm start"
+        );
+    }
+
+    #[test]
+    fn status_transitions_are_recorded_as_lifecycle_events() {
+        let config = test_config("Worker");
+        let manager = test_manager_with(&config);
+
+        {
+            let mut processes = manager.processes.lock().unwrap();
+            let state = processes.get_mut(&config.id).unwrap();
+            state.set_status(ProcessStatus::Starting);
+            state.set_status(ProcessStatus::Running);
+            state.set_status(ProcessStatus::Running);
+            state.set_status(ProcessStatus::Stopping);
+            state.set_status(ProcessStatus::Stopped);
+        }
+
+        let events = manager.lifecycle_events();
+        let kinds: Vec<_> = events.iter().map(|event| event.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                LifecycleEventKind::Starting,
+                LifecycleEventKind::Started,
+                LifecycleEventKind::StopRequested,
+                LifecycleEventKind::Stopped,
+            ]
+        );
+        assert!(events.iter().all(|event| event.process_name == "Worker"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_as_wraps_the_command_in_sudo_with_process_env() {
+        let (mut cmd, _) = super::build_command("node", &["server.js".to_string()], "").unwrap();
+        let process_env = [("PORT".to_string(), "8080".to_string())];
+        cmd.env("PORT", "8080");
+        let wrapped = super::wrap_run_as(&cmd, "svc", &process_env, true);
+        assert!(wrapped
+            .get_envs()
+            .any(|(key, value)| key == "PORT" && value == Some("8080".as_ref())));
+
+        assert_eq!(wrapped.get_program(), "sudo");
+        let args: Vec<_> = wrapped
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            args,
+            [
+                "-n",
+                "--preserve-env=PORT",
+                "-u",
+                "svc",
+                "--",
+                "node",
+                "server.js"
+            ]
+        );
+        assert!(!args.iter().any(|arg| arg.contains("8080")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failing_hook_reports_exit_code_and_logs_output() {
+        let mut config = test_config("App");
+        config.use_shell = true;
+        config.pre_start = "echo migrating; exit 3".to_string();
+        let manager = test_manager_with(&config);
+
+        let result = super::run_hook(&config.id, super::ProcessHook::PreStart, &manager.processes);
+        assert_eq!(
+            result,
+            Err("pre-start hook failed with exit code 3".to_string())
+        );
+        assert!(
+            super::run_hook(&config.id, super::ProcessHook::PostStop, &manager.processes).is_ok()
+        );
+
+        let processes = manager.processes.lock().unwrap();
+        let logs: Vec<_> = processes[&config.id].logs.iter().cloned().collect();
+        assert!(logs.iter().any(|line| line == "[pre-start: migrating]"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn clean_environment_keeps_only_minimal_path_and_explicit_vars() {
+        let mut config = test_config("App");
+        config.use_shell = true;
+        config.inherit_env = false;
+        config.env = vec![("ONLY".to_string(), "1".to_string())];
+        config.pre_start = "env".to_string();
+        let manager = test_manager_with(&config);
+
+        assert!(
+            super::run_hook(&config.id, super::ProcessHook::PreStart, &manager.processes).is_ok()
+        );
+
+        let processes = manager.processes.lock().unwrap();
+        let logs: Vec<_> = processes[&config.id].logs.iter().cloned().collect();
+        assert!(logs.iter().any(|line| line == "[pre-start: ONLY=1]"));
+        assert!(logs
+            .iter()
+            .any(|line| line == "[pre-start: PATH=/usr/local/bin:/usr/bin:/bin]"));
+        assert!(!logs
+            .iter()
+            .any(|line| line.starts_with("[pre-start: HOME=")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn hook_running_past_its_timeout_is_killed() {
+        let mut config = test_config("App");
+        config.use_shell = true;
+        config.pre_start = "sleep 30 & sleep 30".to_string();
+        config.hook_timeout_secs = 1;
+        let manager = test_manager_with(&config);
+
+        let start = std::time::Instant::now();
+        assert_eq!(
+            super::run_hook(&config.id, super::ProcessHook::PreStart, &manager.processes),
+            Err("pre-start hook timed out after 1s and was killed".to_string())
+        );
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn switching_stacks_swaps_states_at_once_and_kills_old_processes_in_background() {
+        use std::io::Read;
+        use std::os::unix::process::CommandExt;
+
+        let old = test_config("Old");
+        let manager = std::sync::Arc::new(test_manager_with(&old));
+        // The backgrounded sleep inherits stdout, so the pipe only closes once it is gone too.
+        let mut child = super::Command::new("sh")
+            .args(["-c", "sleep 30 & wait"])
+            .stdout(super::Stdio::piped())
+            .process_group(0)
+            .spawn()
+            .expect("sh should spawn");
+        let mut stdout = child.stdout.take().unwrap();
+        {
+            let mut processes = manager.processes.lock().unwrap();
+            let state = processes.get_mut(&old.id).unwrap();
+            state.child = Some(child);
+            state.set_status(ProcessStatus::Running);
+        }
+
+        let mut new = test_config("New");
+        new.auto_start = false;
+        manager.switch_stack(std::slice::from_ref(&new));
+        assert_eq!(manager.get_status(&old.id), None);
+        assert_eq!(manager.get_status(&new.id), Some(ProcessStatus::Stopped));
+
+        let start = std::time::Instant::now();
+        let _ = stdout.read_to_end(&mut Vec::new());
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn log_settings_and_restart_pause_belong_to_their_manager() {
+        let config = test_config("App");
+        let limited = test_manager_with(&config);
+        let other = test_manager_with(&config);
+        limited.set_max_log_lines(2);
+        limited.set_managed_restarts_paused(true);
+
+        for manager in [&limited, &other] {
+            let mut processes = manager.processes.lock().unwrap();
+            let logs = &mut processes.get_mut(&config.id).unwrap().logs;
+            for line in ["one", "two", "three"] {
+                super::push_in_memory_log(logs, line.to_string());
+            }
+        }
+
+        assert_eq!(limited.get_log_count(&config.id), Some(2));
+        assert_eq!(other.get_log_count(&config.id), Some(3));
+        assert!(!other.managed_restarts_paused());
+    }
+
+    #[test]
+    fn search_all_logs_groups_matches_by_process() {
+        let manager = super::ProcessManager::new();
+        let api = test_config("API");
+        let web = test_config("Web");
+        manager.init_from_config(&[api.clone(), web.clone()]);
+        {
+            let mut processes = manager.processes.lock().unwrap();
+            let logs = &mut processes.get_mut(&api.id).unwrap().logs;
+            super::push_in_memory_log(logs, "listening on 8080".to_string());
+            super::push_in_memory_log(logs, "\x1b[31mERROR\x1b[0m db timeout".to_string());
+            let logs = &mut processes.get_mut(&web.id).unwrap().logs;
+            super::push_in_memory_log(logs, "compiled".to_string());
+        }
+
+        let results = manager.search_all_logs("error");
+        assert_eq!(
+            results,
+            vec![(api.id.clone(), vec!["ERROR db timeout".to_string()])]
+        );
+        assert!(manager.search_all_logs("  ").is_empty());
+    }
+
+    #[test]
+    fn kept_logs_get_a_separator_on_start() {
+        let mut config = test_config("App");
+        config.clear_logs_on_start = false;
+        let mut state = super::ProcessState::new(config);
+        super::reset_logs_for_start(&mut state);
+        assert_eq!(state.logs.len(), 0);
+
+        super::push_in_memory_log(&mut state.logs, "panic: boom".to_string());
+        super::reset_logs_for_start(&mut state);
+        let lines: Vec<_> = state.logs.iter().cloned().collect();
+        assert_eq!(lines, ["panic: boom", "[--- restarted ---]"]);
+
+        state.config.clear_logs_on_start = true;
+        super::reset_logs_for_start(&mut state);
+        assert_eq!(state.logs.len(), 0);
+    }
+
+    #[test]
+    fn duplicate_start_and_stop_requests_are_ignored_with_feedback() {
+        let config = test_config("Api");
+        let manager = test_manager_with(&config);
+        let generation = {
+            let mut processes = manager.processes.lock().unwrap();
+            let state = processes.get_mut(&config.id).unwrap();
+            state.set_status(ProcessStatus::Starting);
+            state.start_generation
+        };
+
+        manager.start_process(&config.id);
+        {
+            let mut processes = manager.processes.lock().unwrap();
+            let state = processes.get_mut(&config.id).unwrap();
+            assert_eq!(state.start_generation, generation);
+            let lines: Vec<_> = state.logs.iter().cloned().collect();
+            assert_eq!(lines, ["[Ignored start: already starting]"]);
+            state.set_status(ProcessStatus::Stopping);
+        }
+
+        manager.stop_process(&config.id);
+        let processes = manager.processes.lock().unwrap();
+        let state = &processes[&config.id];
+        assert_eq!(state.status, ProcessStatus::Stopping);
+        assert_eq!(state.start_generation, generation);
+        assert_eq!(
+            state.logs.iter().last().map(String::as_str),
+            Some("[Ignored stop: already stopping]")
+        );
+    }
+
+    #[test]
+    fn failed_launch_is_retried_before_giving_up() {
+        let mut config = ProcessConfig::new(
+            "Missing".to_string(),
+            "/no/such/program-for-start-retries".to_string(),
+            String::new(),
+            ProcessType::Process,
+        );
+        config.startup_delay_seconds = 0;
+        config.start_retries = 1;
+        let manager = test_manager_with(&config);
+        manager.start_process(&config.id);
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(10);
+        while !matches!(
+            manager.get_status(&config.id),
+            Some(ProcessStatus::Error(_))
+        ) {
+            assert!(std::time::Instant::now() < deadline, "start never gave up");
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        let processes = manager.processes.lock().unwrap();
+        let lines: Vec<_> = processes[&config.id].logs.iter().cloned().collect();
+        assert_eq!(
+            lines
+                .iter()
+                .filter(|line| line.contains("Retrying launch 1 of 1"))
+                .count(),
+            1
+        );
+        assert!(lines
+            .last()
+            .is_some_and(|line| line.starts_with("[Failed to start:")));
+    }
+
+    #[test]
+    fn output_over_the_rate_limit_is_dropped_and_reported_once_per_window() {
+        let start = std::time::Instant::now();
+        let mut limiter = super::OutputRateLimiter::new(start);
+        for _ in 0..3 {
+            assert_eq!(limiter.admit(start, 3), (true, None));
+        }
+        assert_eq!(limiter.admit(start, 3), (false, None));
+        assert_eq!(
+            limiter.admit(start + Duration::from_millis(500), 3),
+            (false, None)
+        );
+        assert_eq!(
+            limiter.admit(start + Duration::from_secs(1), 3),
+            (true, Some(2))
+        );
+        assert_eq!(limiter.take_suppressed(), None);
+
+        // The end-of-window flush only reports a window that no later line has closed.
+        let later = start + Duration::from_secs(1);
+        assert_eq!(limiter.admit(later, 1), (false, None));
+        assert_eq!(limiter.take_suppressed_for(start), None);
+        assert_eq!(limiter.take_suppressed_for(later), Some(1));
+        assert_eq!(limiter.take_suppressed_for(later), None);
+
+        let mut unlimited = super::OutputRateLimiter::new(start);
+        assert!((0..10_000).all(|_| unlimited.admit(start, 0).0));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn signals_are_only_sent_to_running_process_entries() {
+        let config = test_config("Daemon");
+        let manager = test_manager_with(&config);
+
+        assert_eq!(
+            manager.send_signal(&config.id, super::ProcessSignal::Hup),
+            Err("'Daemon' is not running.".to_string())
+        );
+        assert!(manager
+            .send_signal("missing", super::ProcessSignal::Hup)
+            .is_err());
+        let processes = manager.processes.lock().unwrap();
+        assert_eq!(processes[&config.id].logs.len(), 0);
+        assert_eq!(processes[&config.id].status, ProcessStatus::Stopped);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn validate_config_resolves_programs_and_working_directories() {
+        let manager = super::ProcessManager::new();
+        let mut config = ProcessConfig::new(
+            "Shell".to_string(),
+            "sh -c true".to_string(),
+            String::new(),
+            ProcessType::Process,
+        );
+        let found = manager.validate_config(&config).unwrap();
+        assert!(found.starts_with("Found /"), "{}", found);
+        assert!(found.ends_with("/sh."), "{}", found);
+
+        config.command = "definitely-not-a-real-program-xyz".to_string();
+        assert_eq!(
+            manager.validate_config(&config),
+            Err("Program not found on PATH: definitely-not-a-real-program-xyz".to_string())
+        );
+
+        config.command = "sh".to_string();
+        config.working_directory = "/definitely/missing/dir".to_string();
+        assert_eq!(
+            manager.validate_config(&config),
+            Err("Working directory not found: /definitely/missing/dir".to_string())
+        );
+    }
+
+    #[test]
+    fn crlf_output_reaches_the_disk_log() {
+        let config = test_config("Console");
+        let manager = test_manager_with(&config);
+        let path = std::env::temp_dir().join(format!("pm-crlf-{}.log", config.id));
+        let file = std::fs::File::create(&path).unwrap();
+        manager
+            .processes
+            .lock()
+            .unwrap()
+            .get_mut(&config.id)
+            .unwrap()
+            .disk_log = Some(std::sync::Arc::new(std::sync::Mutex::new(file)));
+
+        let mut reader = std::io::Cursor::new(b"one\r\ntwo\r\n10%\r100%\r\ndone\n".to_vec());
+        let mut buffer = Vec::new();
+        loop {
+            buffer.clear();
+            if super::read_output_line(&mut reader, &mut buffer).unwrap() == 0 {
+                break;
+            }
+            let open = buffer.last() == Some(&b'\r');
+            while matches!(buffer.last(), Some(b'\n' | b'\r')) {
+                buffer.pop();
+            }
+            let line = String::from_utf8_lossy(&buffer).into_owned();
+            super::append_output_line(&manager.processes, &config.id, line, false, open);
+        }
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(written, "one\ntwo\n100%\ndone\n");
+    }
+
+    #[test]
+    fn output_lines_split_on_carriage_returns_and_keep_invalid_utf8() {
+        let mut reader = std::io::Cursor::new(b"one\r\n10%\r20%\rdone\n\xffbad".to_vec());
+        let mut buffer = Vec::new();
+        let mut lines = Vec::new();
+        loop {
+            buffer.clear();
+            if super::read_output_line(&mut reader, &mut buffer).unwrap() == 0 {
+                break;
+            }
+            while matches!(buffer.last(), Some(b'\n' | b'\r')) {
+                buffer.pop();
+            }
+            if !buffer.is_empty() {
+                lines.push(String::from_utf8_lossy(&buffer).into_owned());
+            }
+        }
+        assert_eq!(lines, ["one", "10%", "20%", "done", "\u{fffd}bad"]);
+    }
+
+    #[test]
+    fn processes_resolve_by_id_or_name() {
+        let config = test_config("Web Server");
+        let manager = test_manager_with(&config);
+
+        assert_eq!(
+            manager.resolve_process_id(&config.id),
+            Ok(Some(config.id.clone()))
+        );
+        assert_eq!(
+            manager.resolve_process_id("web server"),
+            Ok(Some(config.id.clone()))
+        );
+        assert_eq!(manager.resolve_process_id("web"), Ok(None));
+    }
+
+    #[test]
+    fn shared_names_do_not_resolve_to_either_entry() {
+        let first = test_config("Worker");
+        let second = test_config("worker ");
+        let manager = super::ProcessManager::new();
+        manager.init_from_config(&[first.clone(), second.clone()]);
+
+        assert!(manager.resolve_process_id("worker").is_err());
+        assert_eq!(
+            manager.resolve_process_id(&second.id),
+            Ok(Some(second.id.clone()))
+        );
+    }
+
+    #[test]
+    fn group_actions_resolve_members_by_group_name() {
+        let manager = super::ProcessManager::new();
+        let configs: Vec<ProcessConfig> = [
+            ("web", None),
+            ("db", Some("Infra")),
+            ("redis", Some("Infra")),
+        ]
+        .iter()
+        .map(|(name, group)| {
+            let mut config = test_config(name);
+            config.group = group.map(str::to_string);
+            config
+        })
+        .collect();
+        manager.init_from_config(&configs);
+
+        assert_eq!(
+            manager.group_ids("Infra"),
+            vec![configs[1].id.clone(), configs[2].id.clone()]
+        );
+        assert_eq!(
+            manager.group_ids(DEFAULT_GROUP_NAME),
+            vec![configs[0].id.clone()]
+        );
+        assert!(manager.group_ids("Frontend").is_empty());
+    }
+
+    #[test]
+    fn managed_restart_backs_off_then_gives_up() {
+        let mut config = test_config("API");
+        config.max_restart_attempts = 3;
+        let mut state = super::ProcessState::new(config);
+
+        let delays: Vec<u64> = (0..3)
+            .map(|_| {
+                super::plan_managed_restart(&mut state, "Down.")
+                    .expect("restart should be planned")
+                    .0
+                    .as_secs()
+            })
+            .collect();
+        assert_eq!(delays, vec![1, 2, 4]);
+        assert!(super::plan_managed_restart(&mut state, "Down.").is_none());
+        assert!(matches!(state.status, super::ProcessStatus::Error(_)));
+        assert_eq!(super::managed_restart_delay(12).as_secs(), 30);
+    }
+
+    #[test]
+    fn parses_docker_stats_line() {
+        let (name, usage) = super::parse_docker_stats_line("web|12.50%|256MiB / 1.944GiB").unwrap();
+        assert_eq!(name, "web");
+        assert_eq!(usage.cpu_percent, Some(12.5));
+        assert_eq!(usage.memory_bytes, Some(256 * 1024 * 1024));
+        assert_eq!(super::parse_docker_size("1.5kB"), Some(1_500));
+        assert_eq!(super::parse_docker_size("??"), None);
+    }
+
+    #[test]
+    fn compose_replica_usage_is_summed() {
+        let usage = |cpu_percent, memory_bytes| super::ProcessResourceUsage {
+            cpu_percent,
+            memory_bytes,
+        };
+        let samples = [usage(Some(12.5), Some(100)), usage(Some(2.5), None)];
+        assert_eq!(
+            super::combined_resource_usage(samples.iter()),
+            usage(Some(15.0), Some(100))
+        );
+        assert_eq!(
+            super::combined_resource_usage(std::iter::empty()),
+            usage(None, None)
+        );
+    }
+
+    #[test]
+    fn log_buffer_drops_oldest_lines_first() {
+        let mut logs = super::LogBuffer::new();
+        for index in 0..5 {
+            logs.push_back(index.to_string());
+        }
+        logs.trim(3);
+        assert_eq!(logs.iter().collect::<Vec<_>>(), ["2", "3", "4"]);
+    }
+
+    #[test]
+    fn log_delta_returns_only_new_lines() {
+        let mut logs = super::LogBuffer::new();
+        logs.push_back("a".to_string());
+        logs.push_back("b".to_string());
+        let first = logs.since(0);
+        assert_eq!(texts(&first), ["a", "b"]);
+
+        logs.push_back("c".to_string());
+        logs.trim(2);
+        let next = logs.since(first.next_sequence);
+        assert_eq!((next.first_sequence, next.next_sequence), (1, 3));
+        assert_eq!(texts(&next), ["c"]);
+
+        logs.clear();
+        let cleared = logs.since(next.next_sequence);
+        assert_eq!(cleared.first_sequence, 3);
+        assert!(cleared.entries.is_empty());
+        // A cursor from before the oldest buffered line gets the whole buffer.
+        logs.push_back("d".to_string());
+        assert_eq!(texts(&logs.since(0)), ["d"]);
+    }
+
+    #[test]
+    fn carriage_return_lines_are_redrawn_in_place() {
+        let mut logs = super::LogBuffer::new();
+        logs.push_output("10%".to_string(), false, true);
+        let open = logs.since(0);
+        assert_eq!(texts(&open), ["10%"]);
+        // The cursor stays on the open line so it is handed over again.
+        assert_eq!(open.next_sequence, 0);
+
+        logs.push_output("20%".to_string(), false, true);
+        logs.push_output("done".to_string(), false, false);
+        let settled = logs.since(open.next_sequence);
+        assert_eq!(texts(&settled), ["done"]);
+        assert_eq!(settled.next_sequence, 1);
+
+        // `\r\n` keeps the text; a line from the other stream does not redraw.
+        logs.push_output("ok".to_string(), false, true);
+        logs.push_output(String::new(), false, false);
+        logs.push_output("50%".to_string(), false, true);
+        logs.push_output("[stderr] warn".to_string(), true, false);
+        assert_eq!(
+            logs.iter().collect::<Vec<_>>(),
+            ["done", "ok", "50%", "[stderr] warn"]
+        );
+    }
+
+    fn texts(delta: &super::LogDelta) -> Vec<&str> {
+        delta
+            .entries
+            .iter()
+            .map(|entry| entry.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn log_entries_remember_their_source_and_kind() {
+        use super::LogSource;
+        use crate::log_classification::LogLineKind;
+
+        let mut logs = super::LogBuffer::new();
+        logs.push_back("[Started with PID 42]".to_string());
+        logs.push_output("listening".to_string(), false, false);
+        logs.push_output("[stderr] 10%".to_string(), true, true);
+        logs.push_output("[stderr] ERROR bind failed".to_string(), true, false);
+
+        let delta = logs.since(0);
+        assert_eq!(
+            texts(&delta),
+            [
+                "[Started with PID 42]",
+                "listening",
+                "[stderr] ERROR bind failed"
+            ]
+        );
+        let tags: Vec<_> = delta
+            .entries
+            .iter()
+            .map(|entry| (entry.source, entry.kind))
+            .collect();
+        assert_eq!(
+            tags,
+            [
+                (LogSource::Manager, LogLineKind::System),
+                (LogSource::Stdout, LogLineKind::Stdout),
+                (LogSource::Stderr, LogLineKind::Error),
+            ]
+        );
+    }
+
+    #[test]
+    fn tail_lines_keeps_the_newest_lines() {
+        assert_eq!(
+            super::tail_lines("one\ntwo\r\nthree\n", 2),
+            vec!["two".to_string(), "three".to_string()]
+        );
+        assert!(super::tail_lines("", 5).is_empty());
+
+        let path = std::env::temp_dir().join(format!("pm-tail-{}.log", uuid::Uuid::new_v4()));
+        let content: String = (0..20_000).map(|n| format!("line {}\n", n)).collect();
+        std::fs::write(&path, content).unwrap();
+        let lines = super::read_tail_lines(&path, 3).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(lines, ["line 19997", "line 19998", "line 19999"]);
+    }
+
+    #[test]
+    fn parses_health_check_urls_and_status_lines() {
+        assert_eq!(
+            super::parse_http_url("http://localhost:3000/health?full=1"),
+            Ok(("localhost".to_string(), 3000, "/health?full=1".to_string()))
+        );
+        assert_eq!(
+            super::parse_http_url("http://[::1]"),
+            Ok(("::1".to_string(), 80, "/".to_string()))
+        );
+        assert!(super::parse_http_url("https://example.com").is_err());
+        assert_eq!(
+            super::parse_http_status_line("HTTP/1.1 503 Service Unavailable\r\n"),
+            Some(503)
+        );
+        assert_eq!(super::parse_http_status_line("SSH-2.0-OpenSSH"), None);
+    }
+
+    #[test]
+    fn compose_status_matches_running_service() {
+        let mut config = ProcessConfig::new(
+            "db".to_string(),
+            "db".to_string(),
+            String::new(),
+            ProcessType::Compose,
+        );
+        config.compose_file = "docker-compose.yml".to_string();
+        let target = super::DockerTarget::from_config(&config).unwrap();
+
+        assert!(target.is_running("web\ndb\n"));
+        assert!(!target.is_running("web\ndb-replica\n"));
+        let args: Vec<_> = target
+            .start_command()
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            args,
+            ["compose", "-f", "docker-compose.yml", "up", "-d", "db"]
+        );
+    }
+
+    #[test]
+    fn docker_polls_speed_up_after_activity_and_back_off_when_idle() {
+        let base = Duration::from_secs(2);
+        assert_eq!(
+            super::docker_poll_delay(base, Duration::from_secs(1)),
+            Duration::from_millis(500)
+        );
+        assert_eq!(
+            super::docker_poll_delay(base, Duration::from_secs(30)),
+            base
+        );
+        assert_eq!(
+            super::docker_poll_delay(base, Duration::from_secs(120)),
+            Duration::from_secs(8)
+        );
+    }
+
+    #[test]
+    fn health_probe_reaches_an_ipv4_only_localhost_service() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.read(&mut [0; 1024]);
+            stream
+                .write_all(b"HTTP/1.0 204 No Content\r\n\r\n")
+                .unwrap();
+        });
+
+        let url = format!("http://localhost:{}/health", port);
+        assert_eq!(super::probe_http(&url, Duration::from_secs(2)), Ok(204));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn detects_a_port_that_is_already_bound() {
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        assert!(super::port_in_use(port));
+        drop(listener);
+        assert!(!super::port_in_use(port));
+    }
+
+    #[test]
+    fn docker_logs_honor_tail_and_since() {
+        let target = super::DockerTarget::Container("api".to_string());
+        let args = |tail, since| -> Vec<String> {
+            target
+                .logs_command(tail, since)
+                .get_args()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect()
+        };
+
+        assert_eq!(args(Some(10), ""), ["logs", "-f", "--tail", "10", "api"]);
+        assert_eq!(
+            args(None, "5m"),
+            ["logs", "-f", "--tail", "all", "--since", "5m", "api"]
+        );
+    }
+
+    #[test]
+    fn explicit_args_skip_command_parsing() {
+        let mut config = ProcessConfig::new(
+            "tool".to_string(),
+            "C:/Program Files/Tool/tool.exe".to_string(),
+            String::new(),
+            ProcessType::Process,
+        );
+        config.args = vec!["--name".to_string(), "two words".to_string()];
+        assert_eq!(
+            super::program_and_args(&config).unwrap(),
+            (
+                "C:/Program Files/Tool/tool.exe".to_string(),
+                vec!["--name".to_string(), "two words".to_string()]
+            )
+        );
+
+        config.args.clear();
+        config.command = "npm run \"dev server\"".to_string();
+        assert_eq!(
+            super::program_and_args(&config).unwrap(),
+            (
+                "npm".to_string(),
+                vec!["run".to_string(), "dev server".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn parses_env_file_lines() {
+        let content = "# comment\n\nPORT=3000\nexport NODE_ENV = \"development\"\nNAME='my app'\nnot a pair\n=missing\n";
+        assert_eq!(
+            super::parse_env_file(content),
+            vec![
+                ("PORT".to_string(), "3000".to_string()),
+                ("NODE_ENV".to_string(), "development".to_string()),
+                ("NAME".to_string(), "my app".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn docker_daemon_errors_are_recognized() {
+        assert!(super::docker_daemon_error(
+            "Cannot connect to the Docker daemon at unix:///var/run/docker.sock. Is the docker daemon running?"
+        )
+        .is_some());
+        assert!(super::docker_daemon_error("Error: No such container: web").is_none());
+    }
+
+    #[test]
+    fn missing_working_directory_is_reported() {
+        assert!(super::check_working_directory("").is_ok());
+        assert!(super::check_working_directory(env!("CARGO_MANIFEST_DIR")).is_ok());
+        assert_eq!(
+            super::check_working_directory(" /no/such/dir "),
+            Err("Working directory not found: /no/such/dir".to_string())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn signal_exit_maps_to_shell_exit_code() {
+        let status = std::process::Command::new("sh")
+            .args(["-c", "kill -9 $$"])
+            .status()
+            .unwrap();
+        assert_eq!(super::exit_code(status), Some(137));
+    }
+
+    #[test]
+    fn health_watchdog_fires_after_consecutive_failures_only() {
+        let mut config = test_config("Api");
+        config.health_check.restart_after_failures = 3;
+        let mut state = super::ProcessState::new(config);
+
+        // Without a restart policy there is nothing to bring the process back.
+        for _ in 0..5 {
+            assert!(!super::record_health_result(&mut state, false));
+        }
+
+        state.config.restart_policy = crate::config::RestartPolicy::OnFailure;
+        state.health_failures = 0;
+        assert!(!super::record_health_result(&mut state, false));
+        assert!(!super::record_health_result(&mut state, false));
+        assert!(!super::record_health_result(&mut state, true));
+        assert!(!super::record_health_result(&mut state, false));
+        assert!(!super::record_health_result(&mut state, false));
+        assert!(super::record_health_result(&mut state, false));
+        assert_eq!(state.health_failures, 0);
+
+        state.config.health_check.restart_after_failures = 0;
+        for _ in 0..5 {
+            assert!(!super::record_health_result(&mut state, false));
+        }
+    }
+
+    #[test]
+    fn docker_run_args_respect_quoting_in_the_logged_command() {
+        let run_args = super::parse_docker_run_args(
+            r#"-p 5432:5432 -e "POSTGRES_PASSWORD=two words" postgres:16"#,
+        )
+        .unwrap();
+        assert_eq!(
+            run_args,
+            vec![
+                "-p",
+                "5432:5432",
+                "-e",
+                "POSTGRES_PASSWORD=two words",
+                "postgres:16"
+            ]
+        );
+        assert_eq!(
+            super::docker_run_command_line("db", &run_args),
+            r#"docker run -d --name db -p 5432:5432 -e "POSTGRES_PASSWORD=two words" postgres:16"#
+        );
+        assert!(super::parse_docker_run_args(r#"-e "UNCLOSED postgres"#).is_err());
+    }
+
+    #[test]
+    fn placeholders_expand_from_the_entry_env_and_report_undefined_names() {
+        let lookup = |name: &str| (name == "SCRIPT").then(|| "dev".to_string());
+        assert_eq!(
+            super::expand_placeholders("npm run ${SCRIPT} -- ${SCRIPT}", lookup).unwrap(),
+            "npm run dev -- dev"
+        );
+        assert_eq!(
+            super::expand_placeholders("echo $HOME ${TARGET} ${PORT} ${TARGET}", lookup)
+                .unwrap_err(),
+            "Undefined variables ${TARGET}, ${PORT}"
+        );
+        // Shell syntax that is not a bare name is left for the shell.
+        for text in [
+            "serve --port ${PORT:-3000}",
+            "echo ${#items} ${ SCRIPT } ${} ${SCRIPT",
+        ] {
+            assert_eq!(super::expand_placeholders(text, lookup).unwrap(), text);
+        }
+        assert_eq!(
+            super::expand_placeholders("echo $${SCRIPT} ${SCRIPT}$", lookup).unwrap(),
+            "echo ${SCRIPT} dev$"
+        );
+
+        let mut config = ProcessConfig::new(
+            "App".to_string(),
+            "npm run ${SCRIPT}".to_string(),
+            "${APP_DIR}/web".to_string(),
+            ProcessType::Process,
+        );
+        config.inherit_env = false;
+        let env = vec![
+            ("SCRIPT".to_string(), "dev".to_string()),
+            ("APP_DIR".to_string(), "/srv/app".to_string()),
+        ];
+        let expanded = super::expand_config_placeholders(&config, &env).unwrap();
+        assert_eq!(expanded.command, "npm run dev");
+        assert_eq!(expanded.working_directory, "/srv/app/web");
+
+        config.command = "npm run ${MISSING}".to_string();
+        assert_eq!(
+            super::expand_config_placeholders(&config, &env).unwrap_err(),
+            "Undefined variable ${MISSING} in the command"
+        );
+    }
+
+    #[test]
+    fn unrequested_non_zero_exit_is_an_error() {
+        assert_eq!(
+            super::exit_status_after_unrequested_exit(Some(0)),
+            ProcessStatus::Stopped
+        );
+        assert_eq!(
+            super::exit_status_after_unrequested_exit(Some(137)),
+            ProcessStatus::Error("exited with code 137".to_string())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn graceful_stop_ends_process_before_timeout() {
+        let mut child = super::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .expect("sleep should spawn");
+
+        assert!(super::request_graceful_stop(child.id(), ""));
+        assert!(super::wait_for_child_exit(
+            &mut child,
+            super::Duration::from_secs(5)
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn killing_process_tree_reaches_grandchildren() {
+        use std::io::Read;
+        use std::os::unix::process::CommandExt;
+
+        // The backgrounded sleep inherits stdout, so the pipe only closes once it is gone too.
+        let mut child = super::Command::new("sh")
+            .args(["-c", "sleep 30 & wait"])
+            .stdout(super::Stdio::piped())
+            .process_group(0)
+            .spawn()
+            .expect("sh should spawn");
+        let mut stdout = child.stdout.take().unwrap();
+        std::thread::sleep(super::Duration::from_millis(200));
+
+        let start = super::Instant::now();
+        super::kill_process_tree(child.id(), "").unwrap();
+        let _ = stdout.read_to_end(&mut Vec::new());
+        let _ = child.wait();
+        assert!(start.elapsed() < super::Duration::from_secs(10));
+    }
 }
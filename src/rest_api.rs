@@ -334,6 +334,10 @@ async fn list_processes(State(state): State<ApiState>) -> Json<Vec<ProcessRuntim
 }
 
 async fn get_process(State(state): State<ApiState>, Path(id): Path<String>) -> impl IntoResponse {
+    let id = match state.manager.resolve_process_id(&id) {
+        Ok(resolved) => resolved.unwrap_or(id),
+        Err(message) => return ambiguous_name_response(message),
+    };
     match state.manager.get_process_snapshot(&id) {
         Some(process) => (StatusCode::OK, Json(process)).into_response(),
         None => (
@@ -353,6 +357,10 @@ async fn get_process_logs(
     Query(query): Query<LogQuery>,
 ) -> impl IntoResponse {
     let limit = normalize_log_limit(query.limit);
+    let id = match state.manager.resolve_process_id(&id) {
+        Ok(resolved) => resolved.unwrap_or(id),
+        Err(message) => return ambiguous_name_response(message),
+    };
     let total_available_lines = match state.manager.get_log_count(&id) {
         Some(count) => count,
         None => {
@@ -421,9 +429,9 @@ async fn reload_stack(State(state): State<ApiState>) -> impl IntoResponse {
     if let Ok(mut stack_name) = state.stack_name.write() {
         *stack_name = config.stack_name.clone();
     }
-    state
-        .manager
-        .set_log_directory(config.log_directory.clone());
+    if let Err(err) = state.manager.apply_settings(&config) {
+        eprintln!("[WARN] Keeping previous log highlighting: {}", err);
+    }
     state.manager.reload_from_config(&config.processes);
     Json(stack_ack_with_message(
         "reload",
@@ -435,11 +443,15 @@ async fn reload_stack(State(state): State<ApiState>) -> impl IntoResponse {
 }
 
 async fn start_process(State(state): State<ApiState>, Path(id): Path<String>) -> impl IntoResponse {
-    process_action(&state.manager, id, "start", ProcessManager::start_process)
+    process_action(&state.manager, id, "start", |manager, id| {
+        manager.start_process(id)
+    })
 }
 
 async fn stop_process(State(state): State<ApiState>, Path(id): Path<String>) -> impl IntoResponse {
-    process_action(&state.manager, id, "stop", ProcessManager::stop_process)
+    process_action(&state.manager, id, "stop", |manager, id| {
+        manager.stop_process(id)
+    })
 }
 
 async fn restart_process(
@@ -473,6 +485,10 @@ async fn reload_process(
     };
 
     config.normalize();
+    let id = match state.manager.resolve_process_id(&id) {
+        Ok(resolved) => resolved.unwrap_or(id),
+        Err(message) => return ambiguous_name_response(message),
+    };
 
     let updated_process = match config
         .processes
@@ -601,7 +617,7 @@ async fn topology(State(state): State<ApiState>) -> Json<TopologyResponse> {
         ],
         usage_notes: vec![
             "Control endpoints are fire-and-poll. After a POST, poll GET /processes.",
-            "Always target individual components by stable id, not display name.",
+            "Prefer targeting individual components by stable id. An exact display name (ignoring case) also works in place of {id} for scripts; the response's target_id reports the resolved id. A name shared by several entries answers 409, so use the id there.",
             "Fetch recent output with GET /processes/{id}/logs?limit=N when an agent needs tail logs.",
             "This server binds only to 127.0.0.1 and is reachable only from the same machine.",
             "POST /stack/reload stops all managed processes first, regardless of status or stack-control settings.",
@@ -618,8 +634,12 @@ fn process_action(
     manager: &Arc<ProcessManager>,
     id: String,
     action: &'static str,
-    action_fn: fn(&ProcessManager, &str),
+    action_fn: fn(&Arc<ProcessManager>, &str),
 ) -> axum::response::Response {
+    let id = match manager.resolve_process_id(&id) {
+        Ok(resolved) => resolved.unwrap_or(id),
+        Err(message) => return ambiguous_name_response(message),
+    };
     if manager.get_process_snapshot(&id).is_none() {
         return (
             StatusCode::NOT_FOUND,
@@ -631,7 +651,7 @@ fn process_action(
             .into_response();
     }
 
-    action_fn(manager.as_ref(), &id);
+    action_fn(manager, &id);
 
     (
         StatusCode::OK,
@@ -646,6 +666,14 @@ fn process_action(
         .into_response()
 }
 
+fn ambiguous_name_response(message: String) -> axum::response::Response {
+    (
+        StatusCode::CONFLICT,
+        Json(ErrorResponse { ok: false, message }),
+    )
+        .into_response()
+}
+
 fn stack_ack(action: &'static str) -> AckResponse {
     stack_ack_with_message(action, format!("{} requested", capitalize(action)))
 }
@@ -746,7 +774,7 @@ pub fn build_agent_bootstrap(
     } else {
         for process in processes {
             lines.push(format!(
-                "- {} | id={} | type={} | status={} | pid={} | cpu={} | ram_bytes={} | auto_start={} | startup_delay_seconds={} | auto_restart={} | stack_start={} | stack_stop={} | stack_restart={}",
+                "- {} | id={} | type={} | status={} | pid={} | cpu={} | ram_bytes={} | auto_start={} | startup_delay_seconds={} | auto_restart={} | restart_policy={:?} | stack_start={} | stack_stop={} | stack_restart={}",
                 process.name,
                 process.id,
                 process.process_type,
@@ -757,6 +785,7 @@ pub fn build_agent_bootstrap(
                 process.auto_start,
                 process.startup_delay_seconds,
                 process.auto_restart,
+                process.restart_policy,
                 process.respond_to_start_all,
                 process.respond_to_stop_all,
                 process.respond_to_restart_all
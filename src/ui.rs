@@ -1,11 +1,14 @@
 //! Native desktop shell built with egui/eframe.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::PathBuf;
-use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+use std::time::{Duration, Instant, SystemTime};
 
 use eframe::egui::{
     self, Align, Align2, Button, CentralPanel, Color32, Context, CornerRadius, FontId, Key, Layout,
@@ -14,60 +17,57 @@ use eframe::egui::{
 };
 #[cfg(windows)]
 use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use regex::{Regex, RegexBuilder};
 use tokio::runtime::Runtime;
 
+use crate::ansi::{ansi_to_segments, strip_ansi};
 use crate::config::{
-    weekly_hour_enabled, weekly_hour_index, AppConfig, ManagedRestartSchedule, ProcessConfig,
-    ProcessType, ScheduledRun, ScheduledRunMode, DEFAULT_LOG_ROTATION_COUNT,
-    DEFAULT_STARTUP_DELAY_SECONDS, WEEKLY_HOUR_COUNT,
+    normalize_env_vars, weekly_hour_enabled, weekly_hour_index, AppConfig, HealthCheck,
+    LogColorRule, LogHighlightRules, ManagedRestartSchedule, ProcessConfig, ProcessType,
+    RestartPolicy, ScheduledRun, ScheduledRunMode, Theme, WindowState, DEFAULT_DOCKER_TAIL,
+    DEFAULT_GROUP_NAME, DEFAULT_HOOK_TIMEOUT_SECONDS, DEFAULT_LOG_ROTATION_COUNT,
+    DEFAULT_MAX_RESTART_ATTEMPTS, DEFAULT_STARTUP_DELAY_SECONDS, DEFAULT_STOP_TIMEOUT_SECONDS,
+    WEEKLY_HOUR_COUNT,
+};
+use crate::log_classification::{
+    line_has_error, parse_hex_color, CompiledHighlightRules, LogLineKind,
 };
-use crate::log_classification::contains_error_indicator;
+#[cfg(unix)]
+use crate::process_manager::ProcessSignal;
 use crate::process_manager::{
-    ProcessCounts, ProcessManager, ProcessResourceUsage, ProcessStatus, UiRuntimeSnapshot,
+    CombinedLogEntry, DockerStatus, HealthStatus, LifecycleEventKind, LogEntry, LogSource,
+    ProcessCounts, ProcessManager, ProcessResourceUsage, ProcessRuntimeInfo, ProcessStatus,
+    UiRuntimeSnapshot, LOG_SEARCH_RESULT_LIMIT,
 };
 use crate::rest_api::{build_agent_bootstrap, RestServerController, RestServerSnapshot};
 
-const SHELL_BG: Color32 = Color32::from_rgb(32, 32, 36); // Fixed shell / native caption chrome
-const BODY_BG: Color32 = Color32::from_rgb(24, 24, 24); // Content inset — neutral gray like Codex main pane
-const PANEL_BG: Color32 = Color32::from_rgb(26, 26, 29); // Dialogs, raised surfaces
-const BORDER: Color32 = Color32::from_rgb(45, 45, 48);
-const SHELL_HOVER_BG: Color32 = Color32::from_rgb(40, 40, 45);
-const SHELL_ACTIVE_BG: Color32 = Color32::from_rgb(47, 47, 53);
-const SHELL_STROKE: Color32 = Color32::from_rgb(56, 56, 62);
-const SHELL_SUBTLE_STROKE: Color32 = Color32::from_rgb(50, 50, 56);
-const PROCESS_ROW_SELECTED_BG: Color32 = Color32::from_rgb(43, 43, 48);
-const PROCESS_ROW_HOVER_BG: Color32 = Color32::from_rgb(37, 37, 42);
-const TAB_SELECTED_BG: Color32 = Color32::from_rgb(55, 71, 95);
-const TAB_SELECTED_HOVER_BG: Color32 = Color32::from_rgb(61, 79, 105);
-const TAB_SELECTED_ACTIVE_BG: Color32 = Color32::from_rgb(68, 88, 116);
-const TAB_SELECTED_STROKE: Color32 = Color32::from_rgb(112, 150, 204);
-const TEXT_MAIN: Color32 = Color32::from_rgb(237, 237, 237); // #EDEDED
-const TEXT_MUTED: Color32 = Color32::from_rgb(136, 136, 136); // #888888
-const TEXT_SOFT: Color32 = Color32::from_rgb(180, 180, 180);
-const RUNNING: Color32 = Color32::from_rgb(85, 184, 122);
-const WARNING: Color32 = Color32::from_rgb(214, 153, 77);
-const DANGER: Color32 = Color32::from_rgb(210, 95, 95);
-const STOPPED: Color32 = Color32::from_rgb(112, 118, 126);
-const TOOLBAR_TEXT: Color32 = Color32::from_rgb(186, 186, 186);
-const TOOLBAR_GREEN: Color32 = Color32::from_rgb(106, 188, 131);
-const TOOLBAR_YELLOW: Color32 = Color32::from_rgb(210, 164, 96);
-const TOOLBAR_RED: Color32 = Color32::from_rgb(208, 116, 116);
-const TOOLBAR_GRAY: Color32 = Color32::from_rgb(162, 162, 162);
-const ACCENT_SOFT: Color32 = Color32::from_rgb(86, 102, 126);
+const WINDOW_DEFAULT_SIZE: Vec2 = Vec2::new(1180.0, 760.0);
+const WINDOW_MIN_SIZE: Vec2 = Vec2::new(920.0, 560.0);
+const STRIP_MIN_SIZE: Vec2 = Vec2::new(120.0, 36.0);
+const STRIP_HEIGHT: f32 = 44.0;
+const STRIP_DOT_SIZE: f32 = 20.0;
 const SIDEBAR_WIDTH: f32 = 240.0;
 const SIDEBAR_MIN_WIDTH: f32 = 180.0;
 const SIDEBAR_MAX_WIDTH: f32 = 460.0;
 const PROCESS_LABEL_HOVER_DELAY_SECONDS: f64 = 0.75;
-const UI_LOG_LIMIT: usize = 1000;
 const WINDOW_CORNER_RADIUS: u8 = 8;
 const CONTENT_GUTTER_X: i8 = 16;
 const LOG_STICK_THRESHOLD_PX: f32 = 22.0;
-const FIELD_BG: Color32 = Color32::from_rgb(20, 20, 20);
-const FIELD_BG_HOVER: Color32 = Color32::from_rgb(24, 24, 24);
-const FIELD_BORDER: Color32 = Color32::from_gray(46);
-const FIELD_BORDER_FOCUS: Color32 = Color32::from_gray(72);
-const FIELD_BORDER_DISABLED: Color32 = Color32::from_gray(58);
+/// Lines shown in the sidebar's "All" view, newest across every process.
+const COMBINED_LOG_LIMIT: usize = 2000;
+/// ANSI colors cycled for `[name]` prefixes in the "All" view when an entry has no color label.
+const COMBINED_LOG_NAME_COLORS: [u8; 8] = [6, 3, 2, 5, 4, 14, 11, 13];
+pub(crate) const DOCKER_EXIT_STOP_TIMEOUT: Duration = Duration::from_secs(10);
+const CONFIG_WARNING_DURATION: Duration = Duration::from_secs(20);
+const CONFIG_SAVE_DEBOUNCE: Duration = Duration::from_millis(300);
+const ERROR_TOAST_DURATION: Duration = Duration::from_secs(6);
+const MAX_ERROR_TOASTS: usize = 4;
+const DOCKER_UNAVAILABLE_HINT: &str =
+    "Docker is not reachable. Start Docker Desktop or the Docker daemon; this recovers automatically.";
 const MODAL_FORM_WIDTH: f32 = 398.0;
+const MAX_START_RETRIES: u32 = 10;
+/// Starting color offered when a color label is first turned on.
+const DEFAULT_PROCESS_COLOR: [u8; 3] = [0x3b, 0x82, 0xf6];
 const MODAL_BUTTON_HEIGHT: f32 = 34.0;
 const PROCESS_DIALOG_HEIGHT: f32 = 520.0;
 const GLOBAL_SETTINGS_HEIGHT: f32 = PROCESS_DIALOG_HEIGHT;
@@ -77,6 +77,259 @@ const PROJECT_WEBSITE_URL: &str = "https://github.com/EnviralDesign/simple-rust-
 const PROJECT_GITHUB_ACCOUNT_URL: &str = "https://github.com/EnviralDesign";
 const PROJECT_GITHUB_ACCOUNT_HANDLE: &str = "@EnviralDesign";
 
+/// Every themed color. `palette()` returns the set for the active theme.
+struct Palette {
+    is_light: bool,
+    /// Fixed shell / native caption chrome
+    shell_bg: Color32,
+    /// Content inset — neutral gray like Codex main pane
+    body_bg: Color32,
+    /// Dialogs, raised surfaces
+    panel_bg: Color32,
+    border: Color32,
+    shell_hover_bg: Color32,
+    shell_active_bg: Color32,
+    shell_stroke: Color32,
+    shell_subtle_stroke: Color32,
+    process_row_selected_bg: Color32,
+    process_row_hover_bg: Color32,
+    tab_selected_bg: Color32,
+    tab_selected_hover_bg: Color32,
+    tab_selected_active_bg: Color32,
+    tab_selected_stroke: Color32,
+    /// #EDEDED
+    text_main: Color32,
+    /// #888888
+    text_muted: Color32,
+    text_soft: Color32,
+    running: Color32,
+    warning: Color32,
+    danger: Color32,
+    stopped: Color32,
+    toolbar_text: Color32,
+    toolbar_green: Color32,
+    toolbar_yellow: Color32,
+    toolbar_red: Color32,
+    toolbar_gray: Color32,
+    accent_soft: Color32,
+    field_bg: Color32,
+    field_bg_hover: Color32,
+    field_border: Color32,
+    field_border_focus: Color32,
+    field_border_disabled: Color32,
+    log_selected_bg: Color32,
+    log_match_bg: Color32,
+    log_match_current_bg: Color32,
+    system_log: Color32,
+    selection_bg: Color32,
+    selection_stroke: Color32,
+    scroll_track: Color32,
+    scroll_handle: Color32,
+    scroll_handle_hover: Color32,
+    scroll_handle_active: Color32,
+}
+
+const DARK_PALETTE: Palette = Palette {
+    is_light: false,
+    shell_bg: Color32::from_rgb(32, 32, 36),
+    body_bg: Color32::from_rgb(24, 24, 24),
+    panel_bg: Color32::from_rgb(26, 26, 29),
+    border: Color32::from_rgb(45, 45, 48),
+    shell_hover_bg: Color32::from_rgb(40, 40, 45),
+    shell_active_bg: Color32::from_rgb(47, 47, 53),
+    shell_stroke: Color32::from_rgb(56, 56, 62),
+    shell_subtle_stroke: Color32::from_rgb(50, 50, 56),
+    process_row_selected_bg: Color32::from_rgb(43, 43, 48),
+    process_row_hover_bg: Color32::from_rgb(37, 37, 42),
+    tab_selected_bg: Color32::from_rgb(55, 71, 95),
+    tab_selected_hover_bg: Color32::from_rgb(61, 79, 105),
+    tab_selected_active_bg: Color32::from_rgb(68, 88, 116),
+    tab_selected_stroke: Color32::from_rgb(112, 150, 204),
+    text_main: Color32::from_rgb(237, 237, 237),
+    text_muted: Color32::from_rgb(136, 136, 136),
+    text_soft: Color32::from_rgb(180, 180, 180),
+    running: Color32::from_rgb(85, 184, 122),
+    warning: Color32::from_rgb(214, 153, 77),
+    danger: Color32::from_rgb(210, 95, 95),
+    stopped: Color32::from_rgb(112, 118, 126),
+    toolbar_text: Color32::from_rgb(186, 186, 186),
+    toolbar_green: Color32::from_rgb(106, 188, 131),
+    toolbar_yellow: Color32::from_rgb(210, 164, 96),
+    toolbar_red: Color32::from_rgb(208, 116, 116),
+    toolbar_gray: Color32::from_rgb(162, 162, 162),
+    accent_soft: Color32::from_rgb(86, 102, 126),
+    field_bg: Color32::from_rgb(20, 20, 20),
+    field_bg_hover: Color32::from_rgb(24, 24, 24),
+    field_border: Color32::from_gray(46),
+    field_border_focus: Color32::from_gray(72),
+    field_border_disabled: Color32::from_gray(58),
+    log_selected_bg: Color32::from_rgb(42, 58, 82),
+    log_match_bg: Color32::from_rgb(92, 78, 32),
+    log_match_current_bg: Color32::from_rgb(168, 124, 28),
+    system_log: Color32::from_rgb(126, 147, 172),
+    selection_bg: Color32::from_rgb(56, 98, 158),
+    selection_stroke: Color32::from_rgb(86, 136, 198),
+    scroll_track: Color32::from_gray(38),
+    scroll_handle: Color32::from_gray(92),
+    scroll_handle_hover: Color32::from_gray(108),
+    scroll_handle_active: Color32::from_gray(116),
+};
+
+const LIGHT_PALETTE: Palette = Palette {
+    is_light: true,
+    shell_bg: Color32::from_rgb(243, 243, 245),
+    body_bg: Color32::from_rgb(255, 255, 255),
+    panel_bg: Color32::from_rgb(250, 250, 251),
+    border: Color32::from_rgb(218, 218, 222),
+    shell_hover_bg: Color32::from_rgb(232, 232, 236),
+    shell_active_bg: Color32::from_rgb(222, 222, 228),
+    shell_stroke: Color32::from_rgb(200, 200, 208),
+    shell_subtle_stroke: Color32::from_rgb(212, 212, 218),
+    process_row_selected_bg: Color32::from_rgb(226, 228, 234),
+    process_row_hover_bg: Color32::from_rgb(234, 235, 239),
+    tab_selected_bg: Color32::from_rgb(214, 226, 244),
+    tab_selected_hover_bg: Color32::from_rgb(204, 219, 240),
+    tab_selected_active_bg: Color32::from_rgb(192, 210, 236),
+    tab_selected_stroke: Color32::from_rgb(92, 130, 188),
+    text_main: Color32::from_rgb(28, 28, 30),
+    text_muted: Color32::from_rgb(110, 110, 116),
+    text_soft: Color32::from_rgb(70, 70, 76),
+    running: Color32::from_rgb(46, 150, 88),
+    warning: Color32::from_rgb(184, 120, 40),
+    danger: Color32::from_rgb(196, 64, 64),
+    stopped: Color32::from_rgb(140, 146, 154),
+    toolbar_text: Color32::from_rgb(70, 70, 74),
+    toolbar_green: Color32::from_rgb(38, 140, 72),
+    toolbar_yellow: Color32::from_rgb(170, 118, 40),
+    toolbar_red: Color32::from_rgb(184, 72, 72),
+    toolbar_gray: Color32::from_rgb(110, 110, 110),
+    accent_soft: Color32::from_rgb(182, 200, 226),
+    field_bg: Color32::from_rgb(255, 255, 255),
+    field_bg_hover: Color32::from_rgb(248, 248, 250),
+    field_border: Color32::from_gray(200),
+    field_border_focus: Color32::from_gray(150),
+    field_border_disabled: Color32::from_gray(215),
+    log_selected_bg: Color32::from_rgb(210, 224, 244),
+    log_match_bg: Color32::from_rgb(250, 232, 160),
+    log_match_current_bg: Color32::from_rgb(246, 190, 70),
+    system_log: Color32::from_rgb(84, 104, 130),
+    selection_bg: Color32::from_rgb(168, 200, 240),
+    selection_stroke: Color32::from_rgb(100, 140, 200),
+    scroll_track: Color32::from_gray(232),
+    scroll_handle: Color32::from_gray(190),
+    scroll_handle_hover: Color32::from_gray(170),
+    scroll_handle_active: Color32::from_gray(160),
+};
+
+static LIGHT_THEME_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+fn palette() -> &'static Palette {
+    if LIGHT_THEME_ACTIVE.load(Ordering::Relaxed) {
+        &LIGHT_PALETTE
+    } else {
+        &DARK_PALETTE
+    }
+}
+
+fn set_theme(theme: Theme) {
+    LIGHT_THEME_ACTIVE.store(theme == Theme::Light, Ordering::Relaxed);
+}
+
+/// Hairline separator tint that reads on either theme.
+fn divider_color(alpha: u8) -> Color32 {
+    if palette().is_light {
+        Color32::from_black_alpha(alpha)
+    } else {
+        Color32::from_white_alpha(alpha)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LogKindFilter {
+    All,
+    Errors,
+    Stdout,
+    Stderr,
+}
+
+impl LogKindFilter {
+    fn matches(self, line: &str, source: LogSource, rules: &CompiledHighlightRules) -> bool {
+        match self {
+            Self::All => true,
+            Self::Errors => line_has_error(line, rules),
+            Self::Stdout => source == LogSource::Stdout,
+            Self::Stderr => source == LogSource::Stderr,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum GroupAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+/// Presentational sidebar order; the stored order only changes through reordering.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ProcessSort {
+    ConfigOrder,
+    RunningFirst,
+    Name,
+    RecentlyStarted,
+}
+
+impl ProcessSort {
+    const ALL: [Self; 4] = [
+        Self::ConfigOrder,
+        Self::RunningFirst,
+        Self::Name,
+        Self::RecentlyStarted,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::ConfigOrder => "Config order",
+            Self::RunningFirst => "Running first",
+            Self::Name => "Name",
+            Self::RecentlyStarted => "Recently started",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BulkAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl BulkAction {
+    fn title(self) -> &'static str {
+        match self {
+            Self::Start => "Start All",
+            Self::Stop => "Stop All",
+            Self::Restart => "Restart All",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Start => "Start every entry that responds to Start All?",
+            Self::Stop => "Stop every entry that responds to Stop All? Running work will be interrupted.",
+            Self::Restart => "Restart every entry that responds to Restart All? Running work will be interrupted.",
+        }
+    }
+}
+
+/// Running entries named by the close confirmation, in sidebar order.
+struct ExitConfirmation {
+    /// Entries closing the window will stop.
+    stopping: Vec<String>,
+    /// Docker and Compose entries left running because `stop_docker_on_exit` is off.
+    left_running: Vec<String>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum CaptionSyncMode {
     Off,
@@ -260,8 +513,17 @@ pub fn run() -> eframe::Result<()> {
 
     let mut viewport = ViewportBuilder::default()
         .with_title("Process Manager")
-        .with_inner_size([1180.0, 760.0])
-        .with_min_inner_size([920.0, 560.0]);
+        .with_inner_size(WINDOW_DEFAULT_SIZE)
+        .with_min_inner_size(WINDOW_MIN_SIZE);
+
+    if let Some(window) = WindowState::load() {
+        viewport = viewport
+            .with_inner_size(Vec2::new(window.width, window.height).max(WINDOW_MIN_SIZE))
+            .with_maximized(window.maximized);
+        if window_position_is_visible(&window) {
+            viewport = viewport.with_position([window.x, window.y]);
+        }
+    }
 
     if let Some(icon) = load_icon_data() {
         viewport = viewport.with_icon(icon);
@@ -283,6 +545,28 @@ pub fn run() -> eframe::Result<()> {
     )
 }
 
+/// A saved position is only reused while some monitor still shows the window.
+#[cfg(windows)]
+fn window_position_is_visible(window: &WindowState) -> bool {
+    use windows_sys::Win32::Foundation::RECT;
+    use windows_sys::Win32::Graphics::Gdi::{MonitorFromRect, MONITOR_DEFAULTTONULL};
+
+    let rect = RECT {
+        left: window.x as i32,
+        top: window.y as i32,
+        right: (window.x + window.width) as i32,
+        bottom: (window.y + window.height) as i32,
+    };
+    !unsafe { MonitorFromRect(&rect, MONITOR_DEFAULTTONULL) }.is_null()
+}
+
+/// Other platforms have no cheap pre-window monitor query; their window managers keep new
+/// windows reachable, and the first frame still shrinks oversized windows.
+#[cfg(not(windows))]
+fn window_position_is_visible(_window: &WindowState) -> bool {
+    true
+}
+
 fn renderer_backend(profile: RendererProfile) -> eframe::Renderer {
     match profile {
         RendererProfile::Glow => eframe::Renderer::Glow,
@@ -334,49 +618,123 @@ fn load_icon_data() -> Option<egui::IconData> {
     })
 }
 
+/// Slot a background `validate_config` call fills in for the process dialog.
+type PendingTest = Arc<Mutex<Option<Result<String, String>>>>;
+
 #[derive(Clone)]
 struct ProcessDraft {
     name: String,
+    group: String,
+    use_color: bool,
+    color: [u8; 3],
     command: String,
+    compose_file: String,
+    docker_run_args: String,
+    /// Blank fetches the full log history.
+    docker_tail: String,
+    docker_since: String,
+    args: Vec<String>,
+    use_shell: bool,
+    /// Blank when no port is declared.
+    expected_port: String,
+    free_port_before_start: bool,
+    run_as: String,
+    pre_start: String,
+    post_stop: String,
     working_directory: String,
+    env: Vec<(String, String)>,
+    env_file: String,
+    inherit_env: bool,
+    /// One path per line.
+    watch_paths: String,
     process_type: ProcessType,
     auto_start: bool,
     startup_delay_seconds: String,
-    auto_restart: bool,
+    stop_timeout_secs: String,
+    hook_timeout_secs: String,
+    max_restart_attempts: String,
+    start_retries: String,
+    restart_policy: RestartPolicy,
     restart_schedule: ManagedRestartSchedule,
     scheduled_run: ScheduledRun,
     scheduled_run_hour: String,
     scheduled_run_interval_hours: String,
+    scheduled_run_interval_minutes: String,
     restart_schedule_editor_open: bool,
     scheduled_run_editor_open: bool,
+    health_check: HealthCheck,
+    health_check_expected_status: String,
+    health_check_interval_secs: String,
+    health_check_timeout_secs: String,
+    health_check_restart_after_failures: String,
     respond_to_start_all: bool,
     respond_to_stop_all: bool,
     respond_to_restart_all: bool,
+    depends_on: Vec<String>,
+    clear_logs_on_start: bool,
     log_to_disk: bool,
     log_rotation_count: String,
+    /// Outcome of the last Test click, shown in the dialog footer.
+    test_result: Option<Result<String, String>>,
+    /// Filled in by the background thread running a Test, which can block on Docker or sudo.
+    test_pending: Option<PendingTest>,
 }
 
 impl Default for ProcessDraft {
     fn default() -> Self {
         Self {
             name: String::new(),
+            group: String::new(),
+            use_color: false,
+            color: DEFAULT_PROCESS_COLOR,
             command: String::new(),
+            compose_file: String::new(),
+            docker_run_args: String::new(),
+            docker_tail: DEFAULT_DOCKER_TAIL.to_string(),
+            docker_since: String::new(),
+            args: Vec::new(),
+            use_shell: false,
+            expected_port: String::new(),
+            free_port_before_start: false,
+            run_as: String::new(),
+            pre_start: String::new(),
+            post_stop: String::new(),
             working_directory: String::new(),
+            env: Vec::new(),
+            env_file: String::new(),
+            inherit_env: true,
+            watch_paths: String::new(),
             process_type: ProcessType::Process,
             auto_start: false,
             startup_delay_seconds: DEFAULT_STARTUP_DELAY_SECONDS.to_string(),
-            auto_restart: false,
+            stop_timeout_secs: DEFAULT_STOP_TIMEOUT_SECONDS.to_string(),
+            hook_timeout_secs: DEFAULT_HOOK_TIMEOUT_SECONDS.to_string(),
+            max_restart_attempts: DEFAULT_MAX_RESTART_ATTEMPTS.to_string(),
+            start_retries: "0".to_string(),
+            restart_policy: RestartPolicy::Never,
             restart_schedule: ManagedRestartSchedule::default(),
             scheduled_run: ScheduledRun::default(),
             scheduled_run_hour: ScheduledRun::default().hour.to_string(),
             scheduled_run_interval_hours: ScheduledRun::default().interval_hours.to_string(),
+            scheduled_run_interval_minutes: ScheduledRun::default().interval_minutes.to_string(),
             restart_schedule_editor_open: false,
             scheduled_run_editor_open: false,
+            health_check: HealthCheck::default(),
+            health_check_expected_status: HealthCheck::default().expected_status.to_string(),
+            health_check_interval_secs: HealthCheck::default().interval_secs.to_string(),
+            health_check_timeout_secs: HealthCheck::default().timeout_secs.to_string(),
+            health_check_restart_after_failures: HealthCheck::default()
+                .restart_after_failures
+                .to_string(),
             respond_to_start_all: true,
             respond_to_stop_all: true,
             respond_to_restart_all: true,
+            depends_on: Vec::new(),
+            clear_logs_on_start: true,
             log_to_disk: false,
             log_rotation_count: DEFAULT_LOG_ROTATION_COUNT.to_string(),
+            test_result: None,
+            test_pending: None,
         }
     }
 }
@@ -385,24 +743,136 @@ impl ProcessDraft {
     fn from_process(process: &ProcessConfig) -> Self {
         Self {
             name: process.name.clone(),
+            group: process.group.clone().unwrap_or_default(),
+            use_color: process_color(process).is_some(),
+            color: process_color(process).unwrap_or(DEFAULT_PROCESS_COLOR),
             command: process.command.clone(),
+            compose_file: process.compose_file.clone(),
+            docker_run_args: process.docker_run_args.clone(),
+            docker_tail: process
+                .docker_tail
+                .map(|lines| lines.to_string())
+                .unwrap_or_default(),
+            docker_since: process.docker_since.clone(),
+            args: process.args.clone(),
+            use_shell: process.use_shell,
+            expected_port: process
+                .expected_port
+                .map(|port| port.to_string())
+                .unwrap_or_default(),
+            free_port_before_start: process.free_port_before_start,
+            run_as: process.run_as.clone(),
+            pre_start: process.pre_start.clone(),
+            post_stop: process.post_stop.clone(),
             working_directory: process.working_directory.clone(),
+            env: process.env.clone(),
+            env_file: process.env_file.clone(),
+            inherit_env: process.inherit_env,
+            watch_paths: process.watch_paths.join("\n"),
             process_type: process.process_type.clone(),
             auto_start: process.auto_start,
             startup_delay_seconds: process.startup_delay_seconds.to_string(),
-            auto_restart: process.auto_restart,
+            stop_timeout_secs: process.stop_timeout_secs.to_string(),
+            hook_timeout_secs: process.hook_timeout_secs.to_string(),
+            max_restart_attempts: process.max_restart_attempts.to_string(),
+            start_retries: process.start_retries.to_string(),
+            restart_policy: process.restart_policy,
             restart_schedule: process.restart_schedule.clone(),
             scheduled_run: process.scheduled_run.clone(),
             scheduled_run_hour: process.scheduled_run.hour.to_string(),
             scheduled_run_interval_hours: process.scheduled_run.interval_hours.to_string(),
+            scheduled_run_interval_minutes: process.scheduled_run.interval_minutes.to_string(),
             restart_schedule_editor_open: false,
             scheduled_run_editor_open: false,
+            health_check: process.health_check.clone(),
+            health_check_expected_status: process.health_check.expected_status.to_string(),
+            health_check_interval_secs: process.health_check.interval_secs.to_string(),
+            health_check_timeout_secs: process.health_check.timeout_secs.to_string(),
+            health_check_restart_after_failures: process
+                .health_check
+                .restart_after_failures
+                .to_string(),
             respond_to_start_all: process.respond_to_start_all,
             respond_to_stop_all: process.respond_to_stop_all,
             respond_to_restart_all: process.respond_to_restart_all,
+            depends_on: process.depends_on.clone(),
+            clear_logs_on_start: process.clear_logs_on_start,
             log_to_disk: process.log_to_disk,
             log_rotation_count: process.log_rotation_count.to_string(),
+            test_result: None,
+            test_pending: None,
+        }
+    }
+
+    /// The entry this form describes, with a fresh id. The first invalid field is the error.
+    fn build(&self) -> Result<ProcessConfig, String> {
+        if self.name.trim().is_empty() || self.command.trim().is_empty() {
+            return Err("Name and command are required.".to_string());
+        }
+        if self.process_type == ProcessType::Compose && self.compose_file.trim().is_empty() {
+            return Err("Compose entries need a compose file.".to_string());
         }
+
+        let mut process = ProcessConfig::new(
+            self.name.trim().to_string(),
+            self.command.trim().to_string(),
+            self.working_directory.trim().to_string(),
+            self.process_type.clone(),
+        );
+        process.group = build_group(&self.group);
+        process.color = build_color(self.use_color, self.color);
+        process.compose_file = self.compose_file.trim().to_string();
+        process.docker_run_args = self.docker_run_args.trim().to_string();
+        process.docker_tail = parse_docker_tail(&self.docker_tail)?;
+        process.docker_since = self.docker_since.trim().to_string();
+        process.args = build_args(&self.args);
+        process.use_shell = self.use_shell;
+        process.expected_port = parse_expected_port(&self.expected_port)?;
+        process.free_port_before_start = self.free_port_before_start;
+        process.run_as = self.run_as.trim().to_string();
+        process.pre_start = self.pre_start.trim().to_string();
+        process.post_stop = self.post_stop.trim().to_string();
+        process.env = build_env_vars(&self.env);
+        process.env_file = self.env_file.trim().to_string();
+        process.inherit_env = self.inherit_env;
+        process.watch_paths = pattern_lines(&self.watch_paths);
+        process.auto_start = self.auto_start;
+        process.startup_delay_seconds = parse_startup_delay_seconds(&self.startup_delay_seconds)?;
+        process.stop_timeout_secs = parse_stop_timeout_secs(&self.stop_timeout_secs)?;
+        process.hook_timeout_secs = parse_hook_timeout_secs(&self.hook_timeout_secs)?;
+        process.restart_policy = self.restart_policy;
+        process.max_restart_attempts = parse_max_restart_attempts(&self.max_restart_attempts)?;
+        process.start_retries = parse_start_retries(&self.start_retries)?;
+        process.restart_schedule = normalize_restart_schedule(self.restart_schedule.clone());
+        process.scheduled_run = build_scheduled_run(self)?;
+        process.health_check = build_health_check(self)?;
+        process.respond_to_start_all = self.respond_to_start_all;
+        process.respond_to_stop_all = self.respond_to_stop_all;
+        process.respond_to_restart_all = self.respond_to_restart_all;
+        process.depends_on = self.depends_on.clone();
+        process.clear_logs_on_start = self.clear_logs_on_start;
+        process.log_to_disk = self.log_to_disk;
+        process.log_rotation_count = parse_log_rotation_count(&self.log_rotation_count)?;
+        Ok(process)
+    }
+
+    /// The fields `validate_config` looks at, as they would be saved.
+    fn test_config(&self) -> ProcessConfig {
+        let mut process = ProcessConfig::new(
+            self.name.trim().to_string(),
+            self.command.trim().to_string(),
+            self.working_directory.trim().to_string(),
+            self.process_type.clone(),
+        );
+        process.compose_file = self.compose_file.trim().to_string();
+        process.docker_run_args = self.docker_run_args.trim().to_string();
+        process.args = build_args(&self.args);
+        process.use_shell = self.use_shell;
+        process.run_as = self.run_as.trim().to_string();
+        process.env = build_env_vars(&self.env);
+        process.env_file = self.env_file.trim().to_string();
+        process.inherit_env = self.inherit_env;
+        process
     }
 }
 
@@ -433,6 +903,24 @@ struct RestSettingsForm {
     port: String,
     log_directory: String,
     process_error_flash_seconds: String,
+    show_timestamps: bool,
+    start_all_delay_ms: String,
+    docker_poll_interval_ms: String,
+    max_log_lines: String,
+    max_output_lines_per_second: String,
+    silent_output_warning_secs: String,
+    silent_output_alert_secs: String,
+    stop_docker_on_exit: bool,
+    confirm_bulk_actions: bool,
+    confirm_exit_when_running: bool,
+    sidebar_log_preview: bool,
+    strip_always_on_top: bool,
+    builtin_highlight_keywords: bool,
+    /// One regex per line.
+    error_patterns: String,
+    warning_patterns: String,
+    /// One `#RRGGBB pattern` rule per line.
+    color_rules: String,
 }
 
 impl RestSettingsForm {
@@ -442,6 +930,28 @@ impl RestSettingsForm {
             port: config.remote_control.port.to_string(),
             log_directory: config.log_directory.clone(),
             process_error_flash_seconds: config.process_error_flash_seconds.to_string(),
+            show_timestamps: config.show_timestamps,
+            start_all_delay_ms: config.start_all_delay_ms.to_string(),
+            docker_poll_interval_ms: config.docker_poll_interval_ms.to_string(),
+            max_log_lines: config.max_log_lines.to_string(),
+            max_output_lines_per_second: config.max_output_lines_per_second.to_string(),
+            silent_output_warning_secs: config.silent_output_warning_secs.to_string(),
+            silent_output_alert_secs: config.silent_output_alert_secs.to_string(),
+            stop_docker_on_exit: config.stop_docker_on_exit,
+            confirm_bulk_actions: config.confirm_bulk_actions,
+            confirm_exit_when_running: config.confirm_exit_when_running,
+            sidebar_log_preview: config.sidebar_log_preview,
+            strip_always_on_top: config.strip_always_on_top,
+            builtin_highlight_keywords: config.log_highlighting.builtin_keywords,
+            error_patterns: config.log_highlighting.error_patterns.join("\n"),
+            warning_patterns: config.log_highlighting.warning_patterns.join("\n"),
+            color_rules: config
+                .log_highlighting
+                .color_rules
+                .iter()
+                .map(|rule| format!("{} {}", rule.color, rule.pattern))
+                .collect::<Vec<_>>()
+                .join("\n"),
         }
     }
 }
@@ -457,6 +967,14 @@ struct TimedFlash {
     until: Instant,
 }
 
+/// Corner notification for an entry that just entered `Error`.
+struct ErrorToast {
+    process_id: String,
+    name: String,
+    message: String,
+    until: Instant,
+}
+
 #[derive(Clone, Debug)]
 struct ProcessLabelHover {
     process_id: String,
@@ -486,6 +1004,107 @@ struct FrozenLogLine {
     index: usize,
 }
 
+/// Find bar state for the log view. `current` is the log index of the focused match.
+#[derive(Default)]
+struct LogSearch {
+    open: bool,
+    query: String,
+    regex: bool,
+    current: Option<usize>,
+    focus_pending: bool,
+    scroll_pending: bool,
+    /// Set when the query was filled in from elsewhere; the bar treats it as freshly typed.
+    query_pending: bool,
+}
+
+/// State of the Ctrl+Shift+F window that searches every process's logs at once.
+#[derive(Default)]
+struct GlobalLogSearch {
+    open: bool,
+    query: String,
+    /// Process id and matching lines, as returned by `ProcessManager::search_all_logs`.
+    results: Vec<(String, Vec<String>)>,
+    focus_pending: bool,
+}
+
+/// The compact status strip, and what to put back when the full window returns.
+struct StatusStrip {
+    restore_size: Vec2,
+    /// Window level last applied, so a settings change takes effect while the strip is open.
+    on_top: bool,
+}
+
+/// State of the window that edits the stack's JSON directly.
+struct RawConfigEditor {
+    text: String,
+    /// Validation error from the last save attempt, shown under the editor.
+    error: Option<String>,
+}
+
+/// Local copy of the selected process's log buffer, kept in step by fetching only new lines.
+/// Line indices used for selection are sequence numbers: `first_sequence` is the first line.
+#[derive(Default)]
+struct LogView {
+    process_id: Option<String>,
+    buffer_id: u64,
+    first_sequence: u64,
+    next_sequence: u64,
+    entries: Arc<VecDeque<LogEntry>>,
+    /// `ProcessManager::log_classification_generation` the entries were fetched under.
+    classification_generation: u64,
+}
+
+impl LogView {
+    fn sync(&mut self, manager: &ProcessManager, process_id: Option<&str>) {
+        let classification_generation = manager.log_classification_generation();
+        if self.process_id.as_deref() != process_id
+            || self.classification_generation != classification_generation
+        {
+            *self = Self {
+                process_id: process_id.map(str::to_string),
+                classification_generation,
+                ..Self::default()
+            };
+        }
+        let Some(delta) = process_id.and_then(|id| manager.get_logs_since(id, self.next_sequence))
+        else {
+            self.entries = Arc::default();
+            return;
+        };
+
+        let continues = delta.buffer_id == self.buffer_id
+            && (delta.first_sequence..=delta.next_sequence).contains(&self.next_sequence);
+        // Lines past the cursor were still open last time; unchanged ones need no rebuild.
+        let settled = (self.next_sequence - self.first_sequence) as usize;
+        if continues
+            && delta.first_sequence == self.first_sequence
+            && delta.next_sequence == self.next_sequence
+            && self.entries.len() >= settled
+            && self
+                .entries
+                .iter()
+                .skip(settled)
+                .map(|entry| &entry.text)
+                .eq(delta.entries.iter().map(|entry| &entry.text))
+        {
+            return;
+        }
+
+        let entries = Arc::make_mut(&mut self.entries);
+        if continues {
+            let stale = (delta.first_sequence - self.first_sequence) as usize;
+            entries.drain(..stale.min(entries.len()));
+            entries.truncate((self.next_sequence - delta.first_sequence) as usize);
+        } else {
+            entries.clear();
+        }
+        entries.extend(delta.entries);
+        self.buffer_id = delta.buffer_id;
+        self.first_sequence = delta.first_sequence;
+        self.next_sequence = delta.next_sequence;
+    }
+}
+
 pub struct ProcessManagerApp {
     toggles: RuntimeToggles,
     runtime: Runtime,
@@ -497,19 +1116,47 @@ pub struct ProcessManagerApp {
     process_dialog: Option<ProcessDialog>,
     delete_process_id: Option<String>,
     reload_processes_confirm_open: bool,
+    pending_bulk_action: Option<BulkAction>,
+    exit_confirmation: Option<ExitConfirmation>,
+    /// Set while the window is collapsed into the compact strip of status dots.
+    status_strip: Option<StatusStrip>,
+    /// Set once the user confirmed, so the repeated close request goes through.
+    exit_confirmed: bool,
+    lifecycle_events_open: bool,
+    pending_import: Option<AppConfig>,
+    raw_config_editor: Option<RawConfigEditor>,
     rest_settings_open: bool,
     global_settings_tab: usize,
     rest_settings_form: RestSettingsForm,
     rest_settings_error: Option<String>,
     stack_name_buffer: String,
+    /// When a scheduled config save should be written; see `schedule_config_save`.
+    config_save_due: Option<Instant>,
     banner: Option<(String, Instant)>,
     copy_feedback_until: Option<Instant>,
+    log_copy_feedback_until: Option<Instant>,
     stick_logs_to_bottom: bool,
+    /// Auto-scroll state of processes that are not selected, restored when they are selected
+    /// again. Their scroll offsets live in each log view's own scroll area state.
+    stick_logs_by_process: HashMap<String, bool>,
+    /// The sidebar's "All" entry is open in place of the selected process.
+    show_combined_logs: bool,
+    combined_logs: Arc<Vec<CombinedLogEntry>>,
+    stick_combined_logs_to_bottom: bool,
+    process_filter: String,
+    process_sort: ProcessSort,
+    collapsed_groups: HashSet<String>,
+    log_filter: String,
+    log_kind_filter: LogKindFilter,
+    exec_command: String,
     log_selection: Option<LogSelection>,
     frozen_log_line: Option<FrozenLogLine>,
+    log_search: LogSearch,
+    global_log_search: GlobalLogSearch,
     last_error_version: u64,
     last_process_error_versions: HashMap<String, u64>,
     process_row_flashes: HashMap<String, TimedFlash>,
+    error_toasts: Vec<ErrorToast>,
     process_label_hover: Option<ProcessLabelHover>,
     current_title: String,
     #[cfg(windows)]
@@ -527,16 +1174,19 @@ pub struct ProcessManagerApp {
     next_caption_probe: Instant,
     last_focus_state: Option<bool>,
     last_viewport_size: Option<Vec2>,
+    /// Geometry saved to `window.json` on exit.
+    window_state: Option<WindowState>,
+    window_size_clamped: bool,
     last_manager_version: u64,
     snapshot_selected_process: Option<String>,
     runtime_snapshot: UiRuntimeSnapshot,
+    log_view: LogView,
     diagnostics: DiagnosticsState,
 }
 
 impl ProcessManagerApp {
     fn new(cc: &eframe::CreationContext<'_>, toggles: RuntimeToggles) -> Self {
         configure_fonts(&cc.egui_ctx);
-        configure_visuals(&cc.egui_ctx);
 
         let runtime = tokio::runtime::Builder::new_multi_thread()
             .enable_all()
@@ -544,9 +1194,13 @@ impl ProcessManagerApp {
             .build()
             .expect("failed to build tokio runtime");
 
-        let config = AppConfig::load();
+        let (config, load_warning) = AppConfig::load();
+        set_theme(config.theme);
+        configure_visuals(&cc.egui_ctx);
         let manager = Arc::new(ProcessManager::new());
-        manager.set_log_directory(config.log_directory.clone());
+        if let Err(err) = manager.apply_settings(&config) {
+            eprintln!("[WARN] Using default log highlighting: {}", err);
+        }
         manager.init_from_config(&config.processes);
         manager.start_background_tasks();
 
@@ -557,8 +1211,14 @@ impl ProcessManagerApp {
         }
         manager.start_auto_start_processes();
 
-        let selected_process = config.processes.first().map(|process| process.id.clone());
-        let runtime_snapshot = manager.build_ui_snapshot(selected_process.as_deref(), UI_LOG_LIMIT);
+        let selected_process = config
+            .selected_process
+            .clone()
+            .filter(|id| config.get_process(id).is_some())
+            .or_else(|| config.processes.first().map(|process| process.id.clone()));
+        let runtime_snapshot = manager.build_ui_snapshot();
+        let mut log_view = LogView::default();
+        log_view.sync(&manager, selected_process.as_deref());
         let last_manager_version = manager.current_version();
         let current_title = window_title(&config.stack_name);
         let rest_settings_form = RestSettingsForm::from_config(&config);
@@ -589,19 +1249,41 @@ impl ProcessManagerApp {
             process_dialog: None,
             delete_process_id: None,
             reload_processes_confirm_open: false,
+            pending_bulk_action: None,
+            exit_confirmation: None,
+            status_strip: None,
+            exit_confirmed: false,
+            lifecycle_events_open: false,
+            pending_import: None,
+            raw_config_editor: None,
             rest_settings_open: false,
             global_settings_tab: 0,
             rest_settings_form,
             rest_settings_error: None,
             stack_name_buffer: String::new(),
-            banner: None,
+            config_save_due: None,
+            banner: load_warning.map(|message| (message, Instant::now() + CONFIG_WARNING_DURATION)),
             copy_feedback_until: None,
+            log_copy_feedback_until: None,
             stick_logs_to_bottom: true,
+            stick_logs_by_process: HashMap::new(),
+            show_combined_logs: false,
+            combined_logs: Arc::default(),
+            stick_combined_logs_to_bottom: true,
+            process_filter: String::new(),
+            process_sort: ProcessSort::ConfigOrder,
+            collapsed_groups: HashSet::new(),
+            log_filter: String::new(),
+            log_kind_filter: LogKindFilter::All,
+            exec_command: String::new(),
             log_selection: None,
             frozen_log_line: None,
+            log_search: LogSearch::default(),
+            global_log_search: GlobalLogSearch::default(),
             last_error_version: 0,
             last_process_error_versions,
             process_row_flashes: HashMap::new(),
+            error_toasts: Vec::new(),
             process_label_hover: None,
             current_title,
             #[cfg(windows)]
@@ -614,14 +1296,17 @@ impl ProcessManagerApp {
             taskbar_big_icon_handle: None,
             #[cfg(windows)]
             taskbar_small_icon_handle: None,
-            shell_bg: SHELL_BG,
+            shell_bg: palette().shell_bg,
             caption_color_initialized: false,
             next_caption_probe: Instant::now(),
             last_focus_state: None,
             last_viewport_size: None,
+            window_state: None,
+            window_size_clamped: false,
             last_manager_version,
             snapshot_selected_process: selected_process.clone(),
             runtime_snapshot,
+            log_view,
             diagnostics: DiagnosticsState {
                 session_label,
                 renderer_backend,
@@ -709,6 +1394,7 @@ impl ProcessManagerApp {
         response: &egui::Response,
         process: &ProcessConfig,
         status: &ProcessStatus,
+        health: Option<HealthStatus>,
         resource_usage: Option<ProcessResourceUsage>,
     ) {
         if response.hovered() {
@@ -735,9 +1421,13 @@ impl ProcessManagerApp {
 
             if elapsed >= delay {
                 let label = process_tab_label(process);
-                let details = resource_usage_text(resource_usage, status)
+                let mut details = resource_usage_text(resource_usage, status)
                     .map(|metrics| format!("{} | {}", status, metrics))
                     .unwrap_or_else(|| status.to_string());
+                if let Some(health) = health {
+                    details.push_str(" | ");
+                    details.push_str(health_label(health));
+                }
                 let mut tooltip = egui::Tooltip::always_open(
                     ui.ctx().clone(),
                     ui.layer_id(),
@@ -751,9 +1441,18 @@ impl ProcessManagerApp {
                     .width(360.0);
                 tooltip.show(|ui| {
                     ui.set_max_width(360.0);
-                    ui.label(RichText::new(label).color(TEXT_MAIN).size(13.0).strong());
+                    ui.label(
+                        RichText::new(label)
+                            .color(palette().text_main)
+                            .size(13.0)
+                            .strong(),
+                    );
                     ui.add_space(3.0);
-                    ui.label(RichText::new(details).color(TEXT_MUTED).size(11.5));
+                    ui.label(
+                        RichText::new(details)
+                            .color(palette().text_muted)
+                            .size(11.5),
+                    );
                 });
             } else {
                 ui.ctx().request_repaint_after(delay - elapsed);
@@ -777,11 +1476,36 @@ impl ProcessManagerApp {
                 .unwrap_or(0);
             if *current_version > previous_version {
                 self.trigger_process_flash(process_id);
+                self.push_error_toast(process_id);
             }
         }
         self.last_process_error_versions = current_versions;
     }
 
+    /// Error bumps also come from error-classified output, so only entries whose status is
+    /// actually `Error` get a toast.
+    fn push_error_toast(&mut self, process_id: &str) {
+        let Some(ProcessStatus::Error(message)) = self.manager.get_status(process_id) else {
+            return;
+        };
+        let name = self
+            .config
+            .get_process(process_id)
+            .map(|process| process.name.clone())
+            .unwrap_or_else(|| process_id.to_string());
+        self.error_toasts
+            .retain(|toast| toast.process_id != process_id);
+        self.error_toasts.push(ErrorToast {
+            process_id: process_id.to_string(),
+            name,
+            message,
+            until: Instant::now() + ERROR_TOAST_DURATION,
+        });
+        if self.error_toasts.len() > MAX_ERROR_TOASTS {
+            self.error_toasts.remove(0);
+        }
+    }
+
     fn rest_snapshot(&self) -> RestServerSnapshot {
         self.rest_controller.snapshot()
     }
@@ -802,11 +1526,27 @@ impl ProcessManagerApp {
     }
 
     fn persist_config(&mut self) {
+        self.config_save_due = None;
         if let Err(err) = self.config.save() {
             self.set_banner(err);
         }
     }
 
+    /// Save after a short quiet period instead of right away, so bursts of small edits such
+    /// as repeated reorders coalesce into one write.
+    fn schedule_config_save(&mut self) {
+        self.config_save_due = Some(Instant::now() + CONFIG_SAVE_DEBOUNCE);
+    }
+
+    fn flush_scheduled_config_save(&mut self, force: bool) {
+        if self
+            .config_save_due
+            .is_some_and(|due| force || Instant::now() >= due)
+        {
+            self.persist_config();
+        }
+    }
+
     fn apply_rest_config(&self) {
         let _guard = self.runtime.enter();
         self.rest_controller.apply_config(
@@ -901,15 +1641,63 @@ impl ProcessManagerApp {
         };
 
         self.set_banner("Stopping all processes and reloading from processes.json...");
-        self.manager.set_log_directory(config.log_directory.clone());
+        self.apply_manager_settings(&config);
         self.manager.reload_from_config(&config.processes);
+        self.adopt_config(config);
+        self.set_banner("Processes reloaded from processes.json.");
+    }
+
+    /// Push the stack-wide settings from `config` into the manager.
+    fn apply_manager_settings(&self, config: &AppConfig) {
+        if let Err(err) = self.manager.apply_settings(config) {
+            eprintln!("[WARN] Keeping previous log highlighting: {}", err);
+        }
+    }
+
+    /// Take over a config the manager has just been synced to.
+    fn adopt_config(&mut self, config: AppConfig) {
         self.config = config;
         self.apply_rest_config();
         self.last_process_error_versions = self.manager.error_versions();
         self.process_row_flashes.clear();
+        self.error_toasts.clear();
+        self.stick_logs_by_process.clear();
         self.ensure_valid_selection();
         self.refresh_runtime_snapshot(true);
-        self.set_banner("Processes reloaded from processes.json.");
+    }
+
+    fn open_raw_config_editor(&mut self) {
+        match self.config.to_json_text() {
+            Ok(text) => self.raw_config_editor = Some(RawConfigEditor { text, error: None }),
+            Err(err) => self.set_banner(err),
+        }
+    }
+
+    /// Validate and save the raw editor's text, then sync the manager to it. Only removed
+    /// entries are stopped; everything else keeps running.
+    fn save_raw_config(&mut self, text: &str) -> Result<(), String> {
+        let config = AppConfig::from_json_text(text)?;
+        config.save()?;
+        self.apply_manager_settings(&config);
+        self.manager.sync_with_config(&config.processes);
+        self.adopt_config(config);
+        Ok(())
+    }
+
+    /// Switch between the dark and light palettes without touching running processes.
+    fn toggle_theme(&mut self, ctx: &Context) {
+        self.config.theme = match self.config.theme {
+            Theme::Dark => Theme::Light,
+            Theme::Light => Theme::Dark,
+        };
+        set_theme(self.config.theme);
+        configure_visuals(ctx);
+        self.shell_bg = palette().shell_bg;
+        #[cfg(windows)]
+        {
+            self.native_caption_applied = false;
+        }
+        self.schedule_config_save();
     }
 
     fn toggle_api_enabled(&mut self) {
@@ -937,51 +1725,17 @@ impl ProcessManagerApp {
     fn apply_process_dialog(&mut self, dialog: ProcessDialog) {
         match dialog {
             ProcessDialog::Add(form) => {
-                if form.name.trim().is_empty() || form.command.trim().is_empty() {
-                    self.set_banner("Name and command are required.");
-                    return;
-                }
-
-                let log_rotation_count = match parse_log_rotation_count(&form.log_rotation_count) {
-                    Ok(value) => value,
-                    Err(err) => {
-                        self.set_banner(err);
-                        return;
-                    }
-                };
-                let scheduled_run = match build_scheduled_run(&form) {
-                    Ok(value) => value,
+                let process = match form.build() {
+                    Ok(process) => process,
                     Err(err) => {
                         self.set_banner(err);
                         return;
                     }
                 };
-                let startup_delay_seconds =
-                    match parse_startup_delay_seconds(&form.startup_delay_seconds) {
-                        Ok(value) => value,
-                        Err(err) => {
-                            self.set_banner(err);
-                            return;
-                        }
-                    };
-                let restart_schedule = normalize_restart_schedule(form.restart_schedule.clone());
-
-                let mut process = ProcessConfig::new(
-                    form.name.trim().to_string(),
-                    form.command.trim().to_string(),
-                    form.working_directory.trim().to_string(),
-                    form.process_type,
-                );
-                process.auto_start = form.auto_start;
-                process.startup_delay_seconds = startup_delay_seconds;
-                process.auto_restart = form.auto_restart;
-                process.restart_schedule = restart_schedule;
-                process.scheduled_run = scheduled_run;
-                process.respond_to_start_all = form.respond_to_start_all;
-                process.respond_to_stop_all = form.respond_to_stop_all;
-                process.respond_to_restart_all = form.respond_to_restart_all;
-                process.log_to_disk = form.log_to_disk;
-                process.log_rotation_count = log_rotation_count;
+                if self.config.name_exists(&process.name, None) {
+                    self.set_banner(duplicate_name_message(&process.name));
+                    return;
+                }
 
                 self.manager.add_process(process.clone());
                 self.config.add_process(process.clone());
@@ -990,34 +1744,20 @@ impl ProcessManagerApp {
                 self.set_banner("Process added.");
             }
             ProcessDialog::Edit { id, form } => {
-                if form.name.trim().is_empty() || form.command.trim().is_empty() {
-                    self.set_banner("Name and command are required.");
-                    return;
-                }
-
-                let log_rotation_count = match parse_log_rotation_count(&form.log_rotation_count) {
-                    Ok(value) => value,
-                    Err(err) => {
-                        self.set_banner(err);
-                        return;
-                    }
-                };
-                let scheduled_run = match build_scheduled_run(&form) {
-                    Ok(value) => value,
+                let updated = match form.build() {
+                    Ok(process) => ProcessConfig {
+                        id: id.clone(),
+                        ..process
+                    },
                     Err(err) => {
                         self.set_banner(err);
                         return;
                     }
                 };
-                let startup_delay_seconds =
-                    match parse_startup_delay_seconds(&form.startup_delay_seconds) {
-                        Ok(value) => value,
-                        Err(err) => {
-                            self.set_banner(err);
-                            return;
-                        }
-                    };
-                let restart_schedule = normalize_restart_schedule(form.restart_schedule.clone());
+                if self.config.name_exists(&updated.name, Some(&id)) {
+                    self.set_banner(duplicate_name_message(&updated.name));
+                    return;
+                }
 
                 if matches!(
                     self.manager.get_status(&id),
@@ -1029,24 +1769,6 @@ impl ProcessManagerApp {
                     wait_for_process_stop(&self.manager, &id);
                 }
 
-                let updated = ProcessConfig {
-                    id: id.clone(),
-                    name: form.name.trim().to_string(),
-                    command: form.command.trim().to_string(),
-                    working_directory: form.working_directory.trim().to_string(),
-                    process_type: form.process_type,
-                    auto_start: form.auto_start,
-                    startup_delay_seconds,
-                    auto_restart: form.auto_restart,
-                    restart_schedule,
-                    scheduled_run,
-                    respond_to_start_all: form.respond_to_start_all,
-                    respond_to_stop_all: form.respond_to_stop_all,
-                    respond_to_restart_all: form.respond_to_restart_all,
-                    log_to_disk: form.log_to_disk,
-                    log_rotation_count,
-                };
-
                 self.config.update_process(&id, updated.clone());
                 self.persist_config();
                 let _ = self.manager.update_process_config(updated);
@@ -1074,6 +1796,71 @@ impl ProcessManagerApp {
                 return;
             }
         };
+        let max_log_lines = match parse_max_log_lines(&self.rest_settings_form.max_log_lines) {
+            Ok(lines) => lines,
+            Err(err) => {
+                self.rest_settings_error = Some(err);
+                return;
+            }
+        };
+        let max_output_lines_per_second = match parse_max_output_lines_per_second(
+            &self.rest_settings_form.max_output_lines_per_second,
+        ) {
+            Ok(lines) => lines,
+            Err(err) => {
+                self.rest_settings_error = Some(err);
+                return;
+            }
+        };
+        let silent_output_warning_secs =
+            match parse_silent_output_secs(&self.rest_settings_form.silent_output_warning_secs) {
+                Ok(seconds) => seconds,
+                Err(err) => {
+                    self.rest_settings_error = Some(err);
+                    return;
+                }
+            };
+        let silent_output_alert_secs =
+            match parse_silent_output_secs(&self.rest_settings_form.silent_output_alert_secs) {
+                Ok(seconds) => seconds,
+                Err(err) => {
+                    self.rest_settings_error = Some(err);
+                    return;
+                }
+            };
+        let start_all_delay_ms =
+            match parse_start_all_delay_ms(&self.rest_settings_form.start_all_delay_ms) {
+                Ok(delay_ms) => delay_ms,
+                Err(err) => {
+                    self.rest_settings_error = Some(err);
+                    return;
+                }
+            };
+        let docker_poll_interval_ms =
+            match parse_docker_poll_interval_ms(&self.rest_settings_form.docker_poll_interval_ms) {
+                Ok(interval_ms) => interval_ms,
+                Err(err) => {
+                    self.rest_settings_error = Some(err);
+                    return;
+                }
+            };
+        let color_rules = match color_rule_lines(&self.rest_settings_form.color_rules) {
+            Ok(rules) => rules,
+            Err(err) => {
+                self.rest_settings_error = Some(err);
+                return;
+            }
+        };
+        let log_highlighting = LogHighlightRules {
+            builtin_keywords: self.rest_settings_form.builtin_highlight_keywords,
+            error_patterns: pattern_lines(&self.rest_settings_form.error_patterns),
+            warning_patterns: pattern_lines(&self.rest_settings_form.warning_patterns),
+            color_rules,
+        };
+        if let Err(err) = CompiledHighlightRules::compile(&log_highlighting) {
+            self.rest_settings_error = Some(err);
+            return;
+        }
 
         let trimmed = self.stack_name_buffer.trim();
         if !trimmed.is_empty() && trimmed != self.config.stack_name {
@@ -1084,20 +1871,124 @@ impl ProcessManagerApp {
         self.config.remote_control.port = parsed_port;
         self.config.log_directory = normalize_log_directory(&self.rest_settings_form.log_directory);
         self.config.process_error_flash_seconds = process_error_flash_seconds;
+        self.config.show_timestamps = self.rest_settings_form.show_timestamps;
+        self.config.max_log_lines = max_log_lines;
+        self.config.max_output_lines_per_second = max_output_lines_per_second;
+        self.config.silent_output_warning_secs = silent_output_warning_secs;
+        self.config.silent_output_alert_secs = silent_output_alert_secs;
+        self.config.log_highlighting = log_highlighting;
+        self.config.start_all_delay_ms = start_all_delay_ms;
+        self.config.docker_poll_interval_ms = docker_poll_interval_ms;
+        self.config.stop_docker_on_exit = self.rest_settings_form.stop_docker_on_exit;
+        self.config.confirm_bulk_actions = self.rest_settings_form.confirm_bulk_actions;
+        self.config.confirm_exit_when_running = self.rest_settings_form.confirm_exit_when_running;
+        self.config.sidebar_log_preview = self.rest_settings_form.sidebar_log_preview;
+        self.config.strip_always_on_top = self.rest_settings_form.strip_always_on_top;
         self.persist_config();
-        self.manager
-            .set_log_directory(self.config.log_directory.clone());
+        self.apply_manager_settings(&self.config);
         self.apply_rest_config();
         self.rest_settings_open = false;
         self.rest_settings_error = None;
         self.set_banner("Global settings saved.");
     }
 
+    /// Stop All waits on a background thread; the header shows progress while entries stop.
+    fn stop_all(&self) {
+        let manager = self.manager.clone();
+        let ids: Vec<String> = self
+            .config
+            .processes
+            .iter()
+            .map(|process| process.id.clone())
+            .collect();
+        std::thread::spawn(move || {
+            let timeout = manager.stop_wait_timeout(&ids);
+            if !manager.stop_all_blocking(timeout) {
+                eprintln!("[WARN] Stop All timed out waiting for processes to stop");
+            }
+        });
+    }
+
+    /// Flips the stack-wide restart pause; entries keep their saved restart policies.
+    fn toggle_managed_restarts_paused(&mut self) {
+        let paused = !self.manager.managed_restarts_paused();
+        self.manager.set_managed_restarts_paused(paused);
+        self.set_banner(if paused {
+            "Managed restarts paused. Crashed processes stay down until you resume."
+        } else {
+            "Managed restarts resumed."
+        });
+    }
+
+    fn set_all_restart_policy(&mut self, policy: RestartPolicy) {
+        let changed = self.config.set_all_restart_policy(policy);
+        self.manager.set_all_restart_policy(policy);
+        if changed > 0 {
+            self.schedule_config_save();
+        }
+        self.set_banner(format!(
+            "Restart policy set to {} for {} entries.",
+            policy, changed
+        ));
+    }
+
+    /// Runs a header bulk action, or queues it for confirmation when `confirm_bulk_actions` is on.
+    fn request_bulk_action(&mut self, action: BulkAction) {
+        if self.config.confirm_bulk_actions {
+            self.pending_bulk_action = Some(action);
+        } else {
+            self.run_bulk_action(action);
+        }
+    }
+
+    /// Cancels a window close and asks first when `confirm_exit_when_running` is on and the
+    /// close would stop running entries.
+    fn intercept_close_request(&mut self, ctx: &Context) {
+        if self.exit_confirmed || !self.config.confirm_exit_when_running {
+            return;
+        }
+
+        let mut confirmation = ExitConfirmation {
+            stopping: Vec::new(),
+            left_running: Vec::new(),
+        };
+        for process in &self.config.processes {
+            if !matches!(
+                self.manager.get_status(&process.id),
+                Some(ProcessStatus::Running | ProcessStatus::Starting)
+            ) {
+                continue;
+            }
+            if process.process_type == ProcessType::Process || self.config.stop_docker_on_exit {
+                confirmation.stopping.push(process.name.clone());
+            } else {
+                confirmation.left_running.push(process.name.clone());
+            }
+        }
+        if confirmation.stopping.is_empty() {
+            return;
+        }
+
+        ctx.send_viewport_cmd(ViewportCommand::CancelClose);
+        self.exit_confirmation = Some(confirmation);
+    }
+
+    fn run_bulk_action(&self, action: BulkAction) {
+        match action {
+            BulkAction::Start => self.manager.start_all(),
+            BulkAction::Stop => self.stop_all(),
+            BulkAction::Restart => self.manager.restart_all(),
+        }
+    }
+
     fn delete_process(&mut self, process_id: &str) {
         self.manager.remove_process(process_id);
         self.config.remove_process(process_id);
         self.last_process_error_versions.remove(process_id);
         self.process_row_flashes.remove(process_id);
+        self.error_toasts
+            .retain(|toast| toast.process_id != process_id);
+        self.stick_logs_by_process.remove(process_id);
         self.persist_config();
         if self.selected_process.as_deref() == Some(process_id) {
             self.selected_process = None;
@@ -1108,25 +1999,38 @@ impl ProcessManagerApp {
 
     fn move_process_up(&mut self, process_id: &str) {
         if self.config.move_process_up(process_id) {
-            self.persist_config();
+            self.sync_process_order();
+            self.schedule_config_save();
             self.set_banner("Process moved up.");
         }
     }
 
     fn move_process_down(&mut self, process_id: &str) {
         if self.config.move_process_down(process_id) {
-            self.persist_config();
+            self.sync_process_order();
+            self.schedule_config_save();
             self.set_banner("Process moved down.");
         }
     }
 
     fn move_process_to_index(&mut self, process_id: &str, target_index: usize) {
         if self.config.move_process_to_index(process_id, target_index) {
-            self.persist_config();
+            self.sync_process_order();
+            self.schedule_config_save();
             self.set_banner("Process reordered.");
         }
     }
 
+    fn sync_process_order(&self) {
+        self.manager.set_process_order(
+            self.config
+                .processes
+                .iter()
+                .map(|process| process.id.clone())
+                .collect(),
+        );
+    }
+
     fn draw_drag_insert_marker(&self, ui: &mut Ui, row_bounds: &[egui::Rect], insert_index: usize) {
         if row_bounds.is_empty() {
             return;
@@ -1150,7 +2054,7 @@ impl ProcessManagerApp {
 
         ui.painter().line_segment(
             [egui::pos2(left, y), egui::pos2(right, y)],
-            Stroke::new(2.0, TAB_SELECTED_STROKE),
+            Stroke::new(2.0, palette().tab_selected_stroke),
         );
     }
 
@@ -1168,15 +2072,37 @@ impl ProcessManagerApp {
         }
 
         if selected_changed {
-            self.stick_logs_to_bottom = true;
+            self.show_combined_logs = false;
+            if self.config.selected_process != self.selected_process {
+                self.config.selected_process = self.selected_process.clone();
+                self.schedule_config_save();
+            }
+            if let Some(previous) = self.snapshot_selected_process.clone() {
+                self.stick_logs_by_process
+                    .insert(previous, self.stick_logs_to_bottom);
+            }
+            self.stick_logs_to_bottom = self
+                .selected_process
+                .as_ref()
+                .and_then(|id| self.stick_logs_by_process.remove(id))
+                .unwrap_or(true);
+            self.log_kind_filter = LogKindFilter::All;
             self.log_selection = None;
             self.frozen_log_line = None;
         }
 
         let started = Instant::now();
-        self.runtime_snapshot = self
-            .manager
-            .build_ui_snapshot(self.selected_process.as_deref(), UI_LOG_LIMIT);
+        self.runtime_snapshot = self.manager.build_ui_snapshot();
+        if self.config.sidebar_log_preview {
+            self.runtime_snapshot.last_log_lines = self.manager.last_log_lines();
+        }
+        self.log_view
+            .sync(&self.manager, self.selected_process.as_deref());
+        self.combined_logs = if self.show_combined_logs {
+            Arc::new(self.manager.combined_logs(COMBINED_LOG_LIMIT))
+        } else {
+            Arc::default()
+        };
         self.last_manager_version = current_version;
         self.snapshot_selected_process = self.selected_process.clone();
         self.record_snapshot_refresh(started.elapsed());
@@ -1265,15 +2191,12 @@ impl ProcessManagerApp {
             return;
         };
 
-        let logs = &self.runtime_snapshot.selected_logs;
+        let logs = self.log_view.entries.clone();
         if logs.is_empty() {
             return;
         }
 
-        let visible_start = self
-            .runtime_snapshot
-            .selected_log_count
-            .saturating_sub(logs.len());
+        let visible_start = self.log_view.first_sequence as usize;
         let visible_end = visible_start + logs.len() - 1;
         if selection_end < visible_start || selection_start > visible_end {
             self.set_banner("Selected logs are no longer in memory.");
@@ -1285,7 +2208,11 @@ impl ProcessManagerApp {
         let copy_end = selection_end.min(visible_end);
         let start_offset = copy_start - visible_start;
         let end_offset = copy_end - visible_start;
-        let payload = logs[start_offset..=end_offset].join("\n");
+        let payload = logs
+            .range(start_offset..=end_offset)
+            .map(|entry| strip_ansi(&entry.text))
+            .collect::<Vec<_>>()
+            .join("\n");
 
         match copy_text_to_clipboard(&payload) {
             Ok(()) => self.set_banner(format!(
@@ -1296,6 +2223,161 @@ impl ProcessManagerApp {
         }
     }
 
+    fn copy_all_logs(&mut self, process_id: &str) {
+        let logs = self
+            .manager
+            .get_recent_logs(process_id, usize::MAX)
+            .unwrap_or_default();
+        if logs.is_empty() {
+            self.set_banner("No logs to copy.");
+            return;
+        }
+
+        match copy_text_to_clipboard(&logs.join("\n")) {
+            Ok(()) => {
+                self.log_copy_feedback_until = Some(Instant::now() + Duration::from_secs(2));
+            }
+            Err(err) => self.set_banner(err),
+        }
+    }
+
+    fn save_logs_to_file(&mut self, process_id: &str) {
+        let file_name = self
+            .manager
+            .suggested_log_export_name(process_id)
+            .unwrap_or_else(|| "logs.log".to_string());
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(file_name)
+            .add_filter("Log files", &["log", "txt"])
+            .save_file()
+        else {
+            return;
+        };
+
+        match self.manager.export_logs(process_id, &path) {
+            Ok(()) => self.set_banner(format!("Saved logs to {}.", path.display())),
+            Err(err) => self.set_banner(err),
+        }
+    }
+
+    fn export_all_logs_to_folder(&mut self) {
+        let Some(parent) = rfd::FileDialog::new().pick_folder() else {
+            return;
+        };
+        let dir = parent.join(format!(
+            "process-logs_{}",
+            chrono::Local::now().format("%Y-%m-%d_%H-%M-%S")
+        ));
+
+        match self.manager.export_all_logs(&dir) {
+            Ok(()) => self.set_banner(format!("Exported all logs to {}.", dir.display())),
+            Err(err) => self.set_banner(err),
+        }
+    }
+
+    fn export_config_to_file(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name("processes.json")
+            .add_filter("JSON", &["json"])
+            .save_file()
+        else {
+            return;
+        };
+
+        match self.config.save_to_path(&path) {
+            Ok(()) => self.set_banner(format!("Exported stack to {}.", path.display())),
+            Err(err) => self.set_banner(err),
+        }
+    }
+
+    fn import_config_from_file(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .pick_file()
+        else {
+            return;
+        };
+
+        match AppConfig::load_from_path(&path) {
+            Ok(mut config) => {
+                config.regenerate_process_ids();
+                self.pending_import = Some(config);
+            }
+            Err(err) => self.set_banner(format!("Import failed: {}", err)),
+        }
+    }
+
+    /// Swap the active stack profile. The manager stops the previous profile's local processes
+    /// in the background before starting the new profile's auto-start entries.
+    fn switch_profile(&mut self, index: usize) {
+        if let Err(err) = self.config.switch_profile(index) {
+            self.set_banner(err);
+            return;
+        }
+
+        self.manager.switch_stack(&self.config.processes);
+        self.persist_config();
+        self.apply_rest_config();
+        self.selected_process = None;
+        self.process_row_flashes.clear();
+        self.error_toasts.clear();
+        self.stick_logs_by_process.clear();
+        self.last_process_error_versions = self.manager.error_versions();
+        self.ensure_valid_selection();
+        self.refresh_runtime_snapshot(true);
+        self.set_banner(format!("Switched to stack '{}'.", self.config.stack_name));
+    }
+
+    fn add_stack_profile(&mut self) {
+        let name = format!("Stack {}", self.config.profile_names().len() + 1);
+        let index = self.config.add_profile(name);
+        self.switch_profile(index);
+    }
+
+    /// Merge appends the imported entries, renaming any whose name is already taken; replace
+    /// swaps the stack name and process list. Machine-local settings (API, log directory) are
+    /// kept either way.
+    fn apply_import(&mut self, imported: AppConfig, replace: bool) {
+        let count = imported.processes.len();
+        let mut renamed = Vec::new();
+        if replace {
+            self.set_banner("Stopping all processes and replacing the stack...");
+            self.config.stack_name = imported.stack_name;
+            self.config.processes = imported.processes;
+            self.manager.reload_from_config(&self.config.processes);
+            self.selected_process = None;
+            self.process_row_flashes.clear();
+            self.error_toasts.clear();
+            self.stick_logs_by_process.clear();
+        } else {
+            for mut process in imported.processes {
+                let name = self.config.unique_process_name(&process.name);
+                if name != process.name.trim() {
+                    renamed.push(format!("\"{}\" as \"{}\"", process.name.trim(), name));
+                    process.name = name;
+                }
+                self.manager.add_process(process.clone());
+                self.config.add_process(process);
+            }
+        }
+
+        self.persist_config();
+        self.last_process_error_versions = self.manager.error_versions();
+        self.ensure_valid_selection();
+        self.refresh_runtime_snapshot(true);
+        self.set_banner(if replace {
+            format!("Replaced the stack with {} imported processes.", count)
+        } else if renamed.is_empty() {
+            format!("Merged {} imported processes.", count)
+        } else {
+            format!(
+                "Merged {} imported processes. Renamed to avoid duplicate names: {}.",
+                count,
+                renamed.join(", ")
+            )
+        });
+    }
+
     fn handle_shortcuts(&mut self, ctx: &Context) {
         if ctx.wants_keyboard_input() {
             return;
@@ -1307,6 +2389,8 @@ impl ProcessManagerApp {
         let mut restart_all = false;
         let mut copy_logs = false;
         let mut clear_logs = false;
+        let mut open_find = false;
+        let mut open_global_search = false;
 
         ctx.input(|input| {
             if input.modifiers.ctrl && input.key_pressed(Key::N) {
@@ -1324,11 +2408,27 @@ impl ProcessManagerApp {
             if input.modifiers.ctrl && input.key_pressed(Key::C) && self.frozen_log_line.is_none() {
                 copy_logs = true;
             }
+            if input.modifiers.ctrl && input.key_pressed(Key::F) {
+                if input.modifiers.shift {
+                    open_global_search = true;
+                } else {
+                    open_find = true;
+                }
+            }
             if input.key_pressed(Key::Escape) {
                 clear_logs = true;
             }
         });
 
+        if open_find {
+            self.log_search.open = true;
+            self.log_search.focus_pending = true;
+        }
+        if open_global_search {
+            self.global_log_search.open = true;
+            self.global_log_search.focus_pending = true;
+        }
+
         if open_add {
             self.open_add_process();
         }
@@ -1339,13 +2439,13 @@ impl ProcessManagerApp {
             self.clear_log_selection();
         }
         if start_all {
-            self.manager.start_all();
+            self.request_bulk_action(BulkAction::Start);
         }
         if stop_all {
-            self.manager.stop_all();
+            self.request_bulk_action(BulkAction::Stop);
         }
         if restart_all {
-            self.manager.restart_all();
+            self.request_bulk_action(BulkAction::Restart);
         }
     }
 
@@ -1427,11 +2527,18 @@ impl ProcessManagerApp {
         }
 
         let now = Instant::now();
+        if let Some(due) = self.config_save_due {
+            return Some(due.saturating_duration_since(now));
+        }
         if self.banner.as_ref().is_some_and(|(_, until)| now < *until) {
             return Some(Duration::from_millis(100));
         }
 
-        if self.copy_feedback_until.is_some_and(|until| now < until) {
+        if self.copy_feedback_until.is_some_and(|until| now < until)
+            || self
+                .log_copy_feedback_until
+                .is_some_and(|until| now < until)
+        {
             return Some(Duration::from_millis(100));
         }
 
@@ -1439,6 +2546,10 @@ impl ProcessManagerApp {
             return Some(Duration::from_millis(100));
         }
 
+        if let Some(until) = self.error_toasts.iter().map(|toast| toast.until).min() {
+            return Some(until.saturating_duration_since(now));
+        }
+
         if matches!(self.toggles.caption_sync, CaptionSyncMode::Continuous) {
             return Some(Duration::from_secs(2));
         }
@@ -1453,7 +2564,7 @@ impl ProcessManagerApp {
             .config
             .processes
             .iter()
-            .any(|process| matches!(process.process_type, ProcessType::Docker))
+            .any(|process| process.process_type.is_container())
         {
             return Some(Duration::from_millis(750));
         }
@@ -1575,10 +2686,7 @@ impl ProcessManagerApp {
                     self.diagnostics.last_motion_move_events,
                     self.diagnostics.last_motion_resize_events
                 ));
-                ui.label(format!(
-                    "selected logs: {}",
-                    self.runtime_snapshot.selected_log_count
-                ));
+                ui.label(format!("selected logs: {}", self.log_view.entries.len()));
                 if let Some(path) = &self.diagnostics.log_path {
                     ui.label(format!("log: {}", path.display()));
                 }
@@ -1586,7 +2694,7 @@ impl ProcessManagerApp {
     }
 
     fn refresh_shell_bg_from_windows_caption(&mut self, focused: bool) -> bool {
-        if self.toggles.caption_sync == CaptionSyncMode::Off {
+        if self.toggles.caption_sync == CaptionSyncMode::Off || palette().is_light {
             return false;
         }
 
@@ -1658,14 +2766,79 @@ impl ProcessManagerApp {
                     ui.set_height(28.0);
                     ui.spacing_mut().item_spacing.x = 6.0;
 
+                    let mut switch_to = None;
+                    let mut add_profile = false;
+                    egui::ComboBox::from_id_salt("stack_profile")
+                        .selected_text(
+                            RichText::new(&self.config.stack_name)
+                                .color(palette().toolbar_text)
+                                .size(12.0),
+                        )
+                        .show_ui(ui, |ui| {
+                            for (index, name) in self.config.profile_names().iter().enumerate() {
+                                let active = self.config.profiles.is_empty()
+                                    || index == self.config.active_profile;
+                                if ui.selectable_label(active, name).clicked() && !active {
+                                    switch_to = Some(index);
+                                }
+                            }
+                            ui.separator();
+                            if ui.selectable_label(false, "+ New Profile").clicked() {
+                                add_profile = true;
+                            }
+                        })
+                        .response
+                        .on_hover_text("Switch stack profile");
+                    if let Some(index) = switch_to {
+                        self.switch_profile(index);
+                    } else if add_profile {
+                        self.add_stack_profile();
+                    }
+
                     ui.label(
                         RichText::new(stack_summary(&counts))
-                            .color(TEXT_MUTED)
+                            .color(palette().text_muted)
                             .size(11.0),
                     );
+                    if counts.starting > 0 {
+                        ui.add_space(6.0);
+                        ui.label(
+                            RichText::new(format!(
+                                "Starting {} process{}…",
+                                counts.starting,
+                                if counts.starting == 1 { "" } else { "es" }
+                            ))
+                            .color(pulse_color(ctx, palette().running))
+                            .size(11.0),
+                        );
+                    }
+                    if counts.stopping > 0 {
+                        ui.add_space(6.0);
+                        ui.label(
+                            RichText::new(format!(
+                                "Stopping {} process{}…",
+                                counts.stopping,
+                                if counts.stopping == 1 { "" } else { "es" }
+                            ))
+                            .color(pulse_color(ctx, palette().warning))
+                            .size(11.0),
+                        );
+                    }
+                    let docker_indicator = match self.runtime_snapshot.docker {
+                        DockerStatus::Unknown => None,
+                        DockerStatus::Available => Some((palette().running, "Docker is running.")),
+                        DockerStatus::Unavailable => {
+                            Some((palette().danger, DOCKER_UNAVAILABLE_HINT))
+                        }
+                    };
+                    if let Some((color, hint)) = docker_indicator {
+                        ui.add_space(6.0);
+                        ui.label(RichText::new("● Docker").color(color).size(11.0))
+                            .on_hover_text(hint);
+                    }
                     if let Some(message) = self.visible_banner() {
                         ui.add_space(6.0);
-                        ui.label(RichText::new(message).color(TEXT_SOFT).size(11.0));
+                        ui.label(RichText::new(message).color(palette().text_soft).size(11.0));
                     }
 
                     ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
@@ -1675,44 +2848,95 @@ impl ProcessManagerApp {
                         if chrome_text_button(
                             ui,
                             "⟳ Restart All",
-                            TOOLBAR_TEXT,
+                            palette().toolbar_text,
                             Vec2::new(0.0, 28.0),
                             12.0,
                             false,
                         )
                         .clicked()
                         {
-                            self.manager.restart_all();
+                            self.request_bulk_action(BulkAction::Restart);
                         }
                         if chrome_text_button(
                             ui,
                             "■ Stop All",
-                            TOOLBAR_TEXT,
+                            palette().toolbar_text,
                             Vec2::new(0.0, 28.0),
                             12.0,
                             false,
                         )
                         .clicked()
                         {
-                            self.manager.stop_all();
+                            self.request_bulk_action(BulkAction::Stop);
                         }
                         if chrome_text_button(
                             ui,
                             "▶ Start All",
-                            TOOLBAR_GREEN,
+                            palette().toolbar_green,
                             Vec2::new(0.0, 28.0),
                             12.0,
                             false,
                         )
                         .clicked()
                         {
-                            self.manager.start_all();
+                            self.request_bulk_action(BulkAction::Start);
                         }
 
+                        let restarts_paused = self.manager.managed_restarts_paused();
+                        let restarts_response = chrome_text_button(
+                            ui,
+                            if restarts_paused {
+                                "⏸ Restarts Paused"
+                            } else {
+                                "⏸ Restarts"
+                            },
+                            if restarts_paused {
+                                palette().toolbar_yellow
+                            } else {
+                                palette().toolbar_text
+                            },
+                            Vec2::new(0.0, 28.0),
+                            12.0,
+                            restarts_paused,
+                        )
+                        .on_hover_text(if restarts_paused {
+                            "Managed restarts are paused; crashed processes stay down"
+                        } else {
+                            "Pause managed restarts, or set every entry's restart policy"
+                        });
+                        egui::Popup::menu(&restarts_response).show(|ui| {
+                            if ui
+                                .button(if restarts_paused {
+                                    "Resume managed restarts"
+                                } else {
+                                    "Pause all restarts"
+                                })
+                                .on_hover_text(
+                                    "Lasts until you resume or quit; saved policies are not changed",
+                                )
+                                .clicked()
+                            {
+                                self.toggle_managed_restarts_paused();
+                            }
+                            ui.separator();
+                            for policy in [
+                                RestartPolicy::Never,
+                                RestartPolicy::OnFailure,
+                                RestartPolicy::Always,
+                            ] {
+                                if ui
+                                    .button(format!("Set every entry to {}", policy))
+                                    .clicked()
+                                {
+                                    self.set_all_restart_policy(policy);
+                                }
+                            }
+                        });
+
                         if chrome_text_button(
                             ui,
                             "⟳ Reload",
-                            TOOLBAR_YELLOW,
+                            palette().toolbar_yellow,
                             Vec2::new(0.0, 28.0),
                             12.0,
                             false,
@@ -1725,21 +2949,155 @@ impl ProcessManagerApp {
                             self.request_processes_reload();
                         }
 
+                        if counts.total > 0 {
+                            ui.add_space(4.0);
+                            let badge_color = if counts.running == counts.total {
+                                palette().running
+                            } else if counts.running > 0 {
+                                palette().warning
+                            } else {
+                                palette().text_muted
+                            };
+                            ui.label(
+                                RichText::new(format!(
+                                    "{}/{} running",
+                                    counts.running, counts.total
+                                ))
+                                .color(badge_color)
+                                .size(12.0)
+                                .strong(),
+                            )
+                            .on_hover_text(stack_summary(&counts));
+                        }
+
                         ui.add_space(4.0);
                         let (sep_rect, _) =
                             ui.allocate_exact_size(Vec2::new(1.0, 18.0), egui::Sense::hover());
                         ui.painter().vline(
                             sep_rect.center().x,
                             sep_rect.y_range(),
-                            Stroke::new(1.0, Color32::from_white_alpha(15)),
+                            Stroke::new(1.0, divider_color(15)),
                         );
                         ui.add_space(4.0);
 
                         // Group 2: Utilities
+                        if chrome_text_button(
+                            ui,
+                            "🔍 Search",
+                            palette().toolbar_text,
+                            Vec2::new(0.0, 28.0),
+                            12.0,
+                            self.global_log_search.open,
+                        )
+                        .on_hover_text("Search the logs of every process (Ctrl+Shift+F)")
+                        .clicked()
+                        {
+                            self.global_log_search.open = !self.global_log_search.open;
+                            self.global_log_search.focus_pending = self.global_log_search.open;
+                        }
+                        if chrome_text_button(
+                            ui,
+                            "🕘 Events",
+                            palette().toolbar_text,
+                            Vec2::new(0.0, 28.0),
+                            12.0,
+                            self.lifecycle_events_open,
+                        )
+                        .on_hover_text("Show what the manager did, across all processes")
+                        .clicked()
+                        {
+                            self.lifecycle_events_open = !self.lifecycle_events_open;
+                        }
+                        if chrome_text_button(
+                            ui,
+                            "▭ Strip",
+                            palette().toolbar_text,
+                            Vec2::new(0.0, 28.0),
+                            12.0,
+                            false,
+                        )
+                        .on_hover_text(
+                            "Collapse the window into a thin strip of status dots; click a dot to come back",
+                        )
+                        .clicked()
+                        {
+                            self.enter_status_strip(ui.ctx());
+                        }
+                        if chrome_text_button(
+                            ui,
+                            "💾 All Logs",
+                            palette().toolbar_text,
+                            Vec2::new(0.0, 28.0),
+                            12.0,
+                            false,
+                        )
+                        .on_hover_text(
+                            "Save every process's logs, statuses and lifecycle events to a folder",
+                        )
+                        .clicked()
+                        {
+                            self.export_all_logs_to_folder();
+                        }
+                        if chrome_text_button(
+                            ui,
+                            "⇩ Import",
+                            palette().toolbar_text,
+                            Vec2::new(0.0, 28.0),
+                            12.0,
+                            false,
+                        )
+                        .on_hover_text("Import a stack from a JSON config file")
+                        .clicked()
+                        {
+                            self.import_config_from_file();
+                        }
+                        if chrome_text_button(
+                            ui,
+                            "⇧ Export",
+                            palette().toolbar_text,
+                            Vec2::new(0.0, 28.0),
+                            12.0,
+                            false,
+                        )
+                        .on_hover_text("Export this stack to a JSON config file")
+                        .clicked()
+                        {
+                            self.export_config_to_file();
+                        }
+                        if chrome_text_button(
+                            ui,
+                            "{ } Raw Config",
+                            palette().toolbar_text,
+                            Vec2::new(0.0, 28.0),
+                            12.0,
+                            self.raw_config_editor.is_some(),
+                        )
+                        .on_hover_text("Edit processes.json as text")
+                        .clicked()
+                        {
+                            self.open_raw_config_editor();
+                        }
+                        let theme_label = match self.config.theme {
+                            Theme::Dark => "☀ Light",
+                            Theme::Light => "☾ Dark",
+                        };
+                        if chrome_text_button(
+                            ui,
+                            theme_label,
+                            palette().toolbar_text,
+                            Vec2::new(0.0, 28.0),
+                            12.0,
+                            false,
+                        )
+                        .on_hover_text("Switch the color theme")
+                        .clicked()
+                        {
+                            self.toggle_theme(ctx);
+                        }
                         if chrome_text_button(
                             ui,
                             "📋 Copy Agent Skill",
-                            TOOLBAR_TEXT,
+                            palette().toolbar_text,
                             Vec2::new(0.0, 28.0),
                             12.0,
                             false,
@@ -1758,9 +3116,9 @@ impl ProcessManagerApp {
                             }
                         );
                         let api_color = if self.config.remote_control.enabled {
-                            TOOLBAR_GREEN
+                            palette().toolbar_green
                         } else {
-                            TOOLBAR_GRAY
+                            palette().toolbar_gray
                         };
                         if chrome_text_button(
                             ui,
@@ -1809,7 +3167,7 @@ impl ProcessManagerApp {
                             ui.set_height(24.0);
                             ui.label(
                                 RichText::new("PROCESSES")
-                                    .color(TEXT_MUTED)
+                                    .color(palette().text_muted)
                                     .size(11.0)
                                     .strong(),
                             );
@@ -1817,7 +3175,7 @@ impl ProcessManagerApp {
                                 if chrome_text_button(
                                     ui,
                                     "+ Add",
-                                    TEXT_MUTED,
+                                    palette().text_muted,
                                     Vec2::new(0.0, 22.0),
                                     11.0,
                                     true,
@@ -1826,29 +3184,87 @@ impl ProcessManagerApp {
                                 {
                                     self.open_add_process();
                                 }
+                                egui::ComboBox::from_id_salt("process_sort")
+                                    .width(110.0)
+                                    .selected_text(
+                                        RichText::new(self.process_sort.label())
+                                            .color(palette().text_muted)
+                                            .size(11.0),
+                                    )
+                                    .show_ui(ui, |ui| {
+                                        for sort in ProcessSort::ALL {
+                                            ui.selectable_value(
+                                                &mut self.process_sort,
+                                                sort,
+                                                sort.label(),
+                                            );
+                                        }
+                                    })
+                                    .response
+                                    .on_hover_text("Sort the sidebar");
                             });
                         });
 
                         ui.add_space(10.0);
 
+                        if !self.config.processes.is_empty() {
+                            ui.horizontal(|ui| {
+                                let clear_width = if self.process_filter.is_empty() {
+                                    0.0
+                                } else {
+                                    34.0
+                                };
+                                inline_text_edit(
+                                    ui,
+                                    &mut self.process_filter,
+                                    "Filter processes",
+                                    (ui.available_width() - clear_width).max(80.0),
+                                );
+                                if !self.process_filter.is_empty()
+                                    && chrome_text_button(
+                                        ui,
+                                        "✕",
+                                        palette().toolbar_gray,
+                                        Vec2::new(28.0, 28.0),
+                                        12.0,
+                                        false,
+                                    )
+                                    .on_hover_text("Clear filter")
+                                    .clicked()
+                                {
+                                    self.process_filter.clear();
+                                }
+                            });
+                            ui.add_space(8.0);
+                        }
+
                         if self.config.processes.is_empty() {
                             ui.add_space(20.0);
                             ui.vertical_centered(|ui| {
                                 ui.label(
                                     RichText::new("No processes yet")
-                                        .color(TEXT_SOFT)
+                                        .color(palette().text_soft)
                                         .size(15.0),
                                 );
                                 ui.add_space(6.0);
                                 ui.label(
                                     RichText::new("Add one with the + button.")
-                                        .color(TEXT_MUTED)
+                                        .color(palette().text_muted)
                                         .size(12.0),
                                 );
                             });
                             return;
                         }
 
+                        if draw_combined_logs_row(ui, self.show_combined_logs)
+                            .on_hover_text("Every process's output interleaved by arrival time")
+                            .clicked()
+                        {
+                            self.show_combined_logs = true;
+                            self.refresh_runtime_snapshot(true);
+                        }
+                        ui.add_space(2.0);
+
                         ScrollArea::vertical()
                             .auto_shrink([false, false])
                             .show(ui, |ui| {
@@ -1856,98 +3272,259 @@ impl ProcessManagerApp {
                                 let mut move_up_id: Option<String> = None;
                                 let mut move_down_id: Option<String> = None;
                                 let mut reload_process_id: Option<String> = None;
+                                let mut copy_request: Option<(String, &'static str)> = None;
                                 let mut reorder_to: Option<(String, usize)> = None;
                                 let mut drag_insert_index: Option<usize> = None;
                                 let mut row_bounds: Vec<egui::Rect> =
                                     Vec::with_capacity(process_count);
-
-                                for (index, process) in
-                                    self.config.processes.clone().into_iter().enumerate()
-                                {
-                                    let row_process =
-                                        self.process_config(&process.id).unwrap_or(process.clone());
-                                    let status = self
-                                        .runtime_snapshot
-                                        .statuses
-                                        .get(&row_process.id)
-                                        .cloned()
-                                        .unwrap_or(ProcessStatus::Stopped);
-                                    let resource_usage = self
-                                        .runtime_snapshot
-                                        .resource_usage
-                                        .get(&row_process.id)
-                                        .copied();
-                                    let is_selected = self.selected_process.as_deref()
-                                        == Some(process.id.as_str());
-                                    let flash_intensity =
-                                        self.process_row_flash_intensity(ctx, &row_process.id);
-                                    let row_response = draw_process_row(
-                                        ui,
-                                        &row_process,
-                                        &status,
-                                        resource_usage,
-                                        is_selected,
-                                        flash_intensity,
-                                    );
-                                    self.update_process_label_hover(
-                                        ui,
-                                        &row_response,
-                                        &row_process,
-                                        &status,
-                                        resource_usage,
-                                    );
-                                    let row_clicked = row_response.clicked();
-                                    if row_response.drag_started() {
-                                        self.dragged_process = Some(process.id.clone());
-                                        self.selected_process = Some(process.id.clone());
-                                        self.refresh_runtime_snapshot(true);
+                                // Filtering only hides rows and sorting only changes how they
+                                // are shown; drag reordering is disabled while either is active.
+                                let name_filter = self.process_filter.trim().to_lowercase();
+                                let filtering = !name_filter.is_empty();
+                                let reorder_locked =
+                                    filtering || self.process_sort != ProcessSort::ConfigOrder;
+                                let mut shown_count = 0;
+                                // Headers only appear once some entry has a group; drops are
+                                // limited to rows in the dragged entry's own group.
+                                let processes = self.config.processes.clone();
+                                let grouped =
+                                    processes.iter().any(|process| process.group.is_some());
+                                let dragged_group = self
+                                    .dragged_process
+                                    .as_deref()
+                                    .and_then(|id| {
+                                        processes.iter().find(|process| process.id == id)
+                                    })
+                                    .map(|process| process.group_name().to_string());
+                                let mut row_indices: Vec<usize> = Vec::with_capacity(process_count);
+                                let mut toggled_group: Option<String> = None;
+                                let mut group_action: Option<(String, GroupAction)> = None;
+
+                                for (group, group_indices) in process_sections(&processes) {
+                                    let mut visible: Vec<usize> = group_indices
+                                        .iter()
+                                        .copied()
+                                        .filter(|&index| {
+                                            !filtering
+                                                || processes[index]
+                                                    .name
+                                                    .to_lowercase()
+                                                    .contains(&name_filter)
+                                        })
+                                        .collect();
+                                    if visible.is_empty() {
+                                        continue;
                                     }
-                                    if let Some(dragged_id) = self.dragged_process.clone() {
-                                        if dragged_id != process.id
-                                            && row_response.hovered()
-                                            && ctx.input(|input| input.pointer.any_released())
-                                        {
-                                            reorder_to = Some((dragged_id.clone(), index));
+                                    sort_process_indices(
+                                        &mut visible,
+                                        &processes,
+                                        self.process_sort,
+                                        &self.runtime_snapshot,
+                                    );
+                                    shown_count += visible.len();
+                                    if grouped {
+                                        let running = visible
+                                            .iter()
+                                            .filter(|&&index| {
+                                                self.runtime_snapshot
+                                                    .statuses
+                                                    .get(&processes[index].id)
+                                                    == Some(&ProcessStatus::Running)
+                                            })
+                                            .count();
+                                        let collapsed =
+                                            !filtering && self.collapsed_groups.contains(&group);
+                                        let (header_response, action) = draw_process_group_header(
+                                            ui,
+                                            &group,
+                                            collapsed,
+                                            running,
+                                            visible.len(),
+                                        );
+                                        if header_response.clicked() {
+                                            toggled_group = Some(group.clone());
                                         }
-                                        if dragged_id != process.id && row_response.hovered() {
-                                            drag_insert_index = Some(index);
+                                        if let Some(action) = action {
+                                            group_action = Some((group.clone(), action));
+                                        }
+                                        if collapsed {
+                                            continue;
                                         }
                                     }
-                                    row_bounds.push(row_response.rect);
-                                    row_response.context_menu(|ui| {
-                                        let can_move_up = index > 0;
-                                        let can_move_down = index + 1 < process_count;
-
-                                        if ui
-                                            .add_enabled(can_move_up, Button::new("Move up"))
-                                            .clicked()
-                                        {
-                                            move_up_id = Some(process.id.clone());
-                                            ui.close();
+                                    let accepts_drop =
+                                        dragged_group.as_deref() == Some(group.as_str());
+
+                                    for index in visible {
+                                        let process = processes[index].clone();
+                                        let row_process = self
+                                            .process_config(&process.id)
+                                            .unwrap_or(process.clone());
+                                        let status = self
+                                            .runtime_snapshot
+                                            .statuses
+                                            .get(&row_process.id)
+                                            .cloned()
+                                            .unwrap_or(ProcessStatus::Stopped);
+                                        let resource_usage = self
+                                            .runtime_snapshot
+                                            .resource_usage
+                                            .get(&row_process.id)
+                                            .copied();
+                                        let health = self
+                                            .runtime_snapshot
+                                            .health
+                                            .get(&row_process.id)
+                                            .copied()
+                                            .filter(|_| status == ProcessStatus::Running);
+                                        let is_selected = !self.show_combined_logs
+                                            && self.selected_process.as_deref()
+                                                == Some(process.id.as_str());
+                                        let flash_intensity =
+                                            self.process_row_flash_intensity(ctx, &row_process.id);
+                                        let preview = self.config.sidebar_log_preview.then(|| {
+                                            self.runtime_snapshot
+                                                .last_log_lines
+                                                .get(&row_process.id)
+                                                .map(String::as_str)
+                                                .unwrap_or("")
+                                        });
+                                        let row_response = draw_process_row(
+                                            ui,
+                                            &row_process,
+                                            &status,
+                                            health,
+                                            resource_usage,
+                                            is_selected,
+                                            flash_intensity,
+                                            preview,
+                                        );
+                                        self.update_process_label_hover(
+                                            ui,
+                                            &row_response,
+                                            &row_process,
+                                            &status,
+                                            health,
+                                            resource_usage,
+                                        );
+                                        let row_clicked = row_response.clicked();
+                                        if row_response.drag_started() && !reorder_locked {
+                                            self.dragged_process = Some(process.id.clone());
+                                            self.selected_process = Some(process.id.clone());
+                                            self.refresh_runtime_snapshot(true);
                                         }
-                                        if ui
-                                            .add_enabled(can_move_down, Button::new("Move down"))
-                                            .clicked()
+                                        if let Some(dragged_id) =
+                                            self.dragged_process.clone().filter(|_| accepts_drop)
                                         {
-                                            move_down_id = Some(process.id.clone());
-                                            ui.close();
+                                            if dragged_id != process.id
+                                                && row_response.hovered()
+                                                && ctx.input(|input| input.pointer.any_released())
+                                            {
+                                                reorder_to = Some((dragged_id.clone(), index));
+                                            }
+                                            if dragged_id != process.id && row_response.hovered() {
+                                                drag_insert_index = Some(index);
+                                            }
                                         }
-                                        if ui.button("Reload").clicked() {
-                                            reload_process_id = Some(process.id.clone());
-                                            ui.close();
+                                        row_bounds.push(row_response.rect);
+                                        row_indices.push(index);
+                                        row_response.context_menu(|ui| {
+                                            let can_move_up = group_indices.first() != Some(&index);
+                                            let can_move_down =
+                                                group_indices.last() != Some(&index);
+
+                                            if ui
+                                                .add_enabled(can_move_up, Button::new("Move up"))
+                                                .clicked()
+                                            {
+                                                move_up_id = Some(process.id.clone());
+                                                ui.close();
+                                            }
+                                            if ui
+                                                .add_enabled(
+                                                    can_move_down,
+                                                    Button::new("Move down"),
+                                                )
+                                                .clicked()
+                                            {
+                                                move_down_id = Some(process.id.clone());
+                                                ui.close();
+                                            }
+                                            if ui.button("Reload").clicked() {
+                                                reload_process_id = Some(process.id.clone());
+                                                ui.close();
+                                            }
+                                            ui.separator();
+                                            let pid = self
+                                                .manager
+                                                .get_runtime_info(&process.id)
+                                                .and_then(|info| info.pid);
+                                            if ui
+                                                .add_enabled(pid.is_some(), Button::new("Copy PID"))
+                                                .on_disabled_hover_text("Not running")
+                                                .clicked()
+                                            {
+                                                if let Some(pid) = pid {
+                                                    copy_request =
+                                                        Some((pid.to_string(), "PID copied."));
+                                                }
+                                                ui.close();
+                                            }
+                                            let copy_label = match row_process.process_type {
+                                                ProcessType::Process => "Copy command",
+                                                ProcessType::Docker => "Copy container name",
+                                                ProcessType::Compose => "Copy service name",
+                                            };
+                                            if ui.button(copy_label).clicked() {
+                                                copy_request = Some((
+                                                    process_command_line(&row_process),
+                                                    "Copied to clipboard.",
+                                                ));
+                                                ui.close();
+                                            }
+                                        });
+                                        if row_clicked {
+                                            self.show_combined_logs = false;
+                                            self.selected_process = Some(process.id.clone());
+                                            self.refresh_runtime_snapshot(true);
                                         }
-                                    });
-                                    if row_clicked {
-                                        self.selected_process = Some(process.id.clone());
-                                        self.refresh_runtime_snapshot(true);
+                                        ui.add_space(2.0);
+                                    }
+                                }
+
+                                if let Some(group) = toggled_group {
+                                    if !self.collapsed_groups.remove(&group) {
+                                        self.collapsed_groups.insert(group);
+                                    }
+                                }
+                                match group_action {
+                                    Some((group, GroupAction::Start)) => {
+                                        self.manager.start_group(&group)
                                     }
-                                    ui.add_space(2.0);
+                                    Some((group, GroupAction::Stop)) => {
+                                        self.manager.stop_group(&group)
+                                    }
+                                    Some((group, GroupAction::Restart)) => {
+                                        self.manager.restart_group(&group)
+                                    }
+                                    None => {}
+                                }
+
+                                if filtering && shown_count == 0 {
+                                    ui.add_space(12.0);
+                                    ui.vertical_centered(|ui| {
+                                        ui.label(
+                                            RichText::new("No matching processes")
+                                                .color(palette().text_muted)
+                                                .size(12.0),
+                                        );
+                                    });
                                 }
 
                                 if self.dragged_process.is_some()
                                     && ctx.input(|input| input.pointer.primary_down())
                                 {
-                                    let can_place_at_end = !row_bounds.is_empty()
+                                    let can_place_at_end = !grouped
+                                        && !row_bounds.is_empty()
                                         && process_count > 0
                                         && row_bounds.last().is_some_and(|last_rect| {
                                             ctx.input(|input| {
@@ -1961,7 +3538,15 @@ impl ProcessManagerApp {
                                         drag_insert_index = Some(process_count);
                                     }
                                     if let Some(insert_index) = drag_insert_index {
-                                        self.draw_drag_insert_marker(ui, &row_bounds, insert_index);
+                                        let marker_position = row_indices
+                                            .iter()
+                                            .position(|&row| row == insert_index)
+                                            .unwrap_or(row_bounds.len());
+                                        self.draw_drag_insert_marker(
+                                            ui,
+                                            &row_bounds,
+                                            marker_position,
+                                        );
                                     }
                                 }
                                 if let Some((process_id, target_index)) = reorder_to {
@@ -1976,6 +3561,12 @@ impl ProcessManagerApp {
                                 } else if let Some(process_id) = reload_process_id {
                                     self.reload_process_from_disk(&process_id);
                                 }
+                                if let Some((text, message)) = copy_request {
+                                    match copy_text_to_clipboard(&text) {
+                                        Ok(()) => self.set_banner(message),
+                                        Err(err) => self.set_banner(err),
+                                    }
+                                }
                             });
                     });
             });
@@ -2002,10 +3593,13 @@ impl ProcessManagerApp {
                     se: 0,
                 };
 
-                ui.painter().rect_filled(inset_rect, inset_radius, BODY_BG);
+                ui.painter()
+                    .rect_filled(inset_rect, inset_radius, palette().body_bg);
 
                 ui.scope_builder(UiBuilder::new().max_rect(inset_rect), |ui| {
-                    if let Some(process) = self.selected_process_config() {
+                    if self.show_combined_logs {
+                        self.draw_combined_logs(ui);
+                    } else if let Some(process) = self.selected_process_config() {
                         self.draw_process_detail(ui, &process);
                     } else {
                         self.draw_empty_state(ui);
@@ -2021,14 +3615,14 @@ impl ProcessManagerApp {
                 ui.vertical_centered(|ui| {
                     ui.label(
                         RichText::new("Let's build")
-                            .color(TEXT_MAIN)
+                            .color(palette().text_main)
                             .size(32.0)
                             .strong(),
                     );
                     ui.add_space(12.0);
                     ui.label(
                         RichText::new("Select a process or add a new one.")
-                            .color(TEXT_MUTED)
+                            .color(palette().text_muted)
                             .size(16.0),
                     );
                 });
@@ -2037,15 +3631,12 @@ impl ProcessManagerApp {
     }
 
     fn draw_process_detail(&mut self, ui: &mut Ui, process: &ProcessConfig) {
-        let logs = self.runtime_snapshot.selected_logs.clone();
-        let visible_log_start = self
-            .runtime_snapshot
-            .selected_log_count
-            .saturating_sub(logs.len());
+        let logs = self.log_view.entries.clone();
+        let visible_log_start = self.log_view.first_sequence as usize;
         let selected_log_count =
             self.visible_selected_log_count(&process.id, visible_log_start, logs.len());
         let auto_start = if process.auto_start { "ON" } else { "OFF" };
-        let managed_restart = if process.auto_restart { "ON" } else { "OFF" };
+        let managed_restart = process.restart_policy.to_string();
         let global_controls = global_controls_summary(process);
         let status = self
             .runtime_snapshot
@@ -2060,26 +3651,73 @@ impl ProcessManagerApp {
             .copied();
         let resource_summary =
             resource_usage_text(resource_usage, &status).unwrap_or_else(|| "--".to_string());
+        let runtime_summary = self
+            .manager
+            .get_runtime_info(&process.id)
+            .and_then(|info| runtime_info_text(&info))
+            .map(|summary| format!(" | {}", summary))
+            .unwrap_or_default();
+        let schedule_summary = process
+            .scheduled_run
+            .next_run_after(chrono::Local::now().naive_local())
+            .map(|next| format!(" | next run {}", next.format("%a %H:%M")))
+            .unwrap_or_default();
         let metadata = format!(
-            "{} | {} | {} | auto-start {} | delay {}s | restart {} | global {}",
+            "{} | {} | {}{} | auto-start {} | delay {}s | restart {} | global {}{}",
             match &process.process_type {
                 ProcessType::Process => "Process",
                 ProcessType::Docker => "Docker",
+                ProcessType::Compose => "Compose",
             },
-            &process.command,
+            process.command_line(),
             resource_summary,
+            runtime_summary,
             auto_start,
             process.startup_delay_seconds,
             managed_restart,
-            global_controls
+            global_controls,
+            schedule_summary
         );
+        // Manager lines such as "[Started with PID 42]" are not output.
+        let silence = logs
+            .iter()
+            .rev()
+            .find(|entry| entry.source != LogSource::Manager)
+            .map(|entry| {
+                SystemTime::now()
+                    .duration_since(entry.at)
+                    .unwrap_or_default()
+            });
         let mut action_start = false;
         let mut action_stop = false;
         let mut action_restart = false;
         let mut action_edit = false;
         let mut action_delete = false;
         let mut action_copy_logs = false;
+        let mut action_save_logs = false;
+        let mut action_clear_logs = false;
+        let mut action_copy_all_logs = false;
+        let copy_all_label = if self
+            .log_copy_feedback_until
+            .is_some_and(|until| Instant::now() < until)
+        {
+            "✔ Copied"
+        } else {
+            "📋 Copy All"
+        };
         let mut action_clear_log_selection = false;
+        let mut action_open_folder = false;
+        let working_directory = process.working_directory.trim();
+        let show_open_folder =
+            process.process_type == ProcessType::Process && !working_directory.is_empty();
+        let working_directory_exists =
+            show_open_folder && std::path::Path::new(working_directory).is_dir();
+        let docker_unavailable = process.process_type.is_container()
+            && self.runtime_snapshot.docker == DockerStatus::Unavailable;
+        let mut action_exec = false;
+        #[cfg(unix)]
+        let mut action_signal: Option<ProcessSignal> = None;
+        let show_signal = cfg!(unix) && process.process_type == ProcessType::Process;
 
         // Single compact header row: process actions left, metadata uses the remaining space.
         egui::Frame::default()
@@ -2088,57 +3726,133 @@ impl ProcessManagerApp {
             .inner_margin(egui::Margin::symmetric(CONTENT_GUTTER_X, 10))
             .show(ui, |ui| {
                 ui.horizontal(|ui| {
-                    let action_width = ui.available_width().min(if selected_log_count > 0 {
-                        520.0
-                    } else {
-                        360.0
-                    });
+                    let folder_width = if show_open_folder { 90.0 } else { 0.0 };
+                    let signal_width = if show_signal { 84.0 } else { 0.0 };
+                    let action_width = ui.available_width().min(
+                        folder_width
+                            + signal_width
+                            + if selected_log_count > 0 { 820.0 } else { 660.0 },
+                    );
                     ui.allocate_ui_with_layout(
                         Vec2::new(action_width, 28.0),
                         Layout::left_to_right(Align::Center),
                         |ui| {
                             ui.spacing_mut().item_spacing.x = 4.0;
+                            if ui
+                                .add_enabled_ui(!docker_unavailable, |ui| {
+                                    chrome_text_button(
+                                        ui,
+                                        "▶ Start",
+                                        palette().toolbar_green,
+                                        Vec2::new(0.0, 28.0),
+                                        12.0,
+                                        false,
+                                    )
+                                })
+                                .inner
+                                .on_disabled_hover_text(DOCKER_UNAVAILABLE_HINT)
+                                .clicked()
+                            {
+                                action_start = true;
+                            }
                             if chrome_text_button(
                                 ui,
-                                "▶ Start",
-                                TOOLBAR_GREEN,
+                                "■ Stop",
+                                palette().toolbar_gray,
                                 Vec2::new(0.0, 28.0),
                                 12.0,
                                 false,
                             )
                             .clicked()
                             {
-                                action_start = true;
+                                action_stop = true;
                             }
                             if chrome_text_button(
                                 ui,
-                                "■ Stop",
-                                TOOLBAR_GRAY,
+                                "⟳ Restart",
+                                palette().toolbar_yellow,
                                 Vec2::new(0.0, 28.0),
                                 12.0,
                                 false,
                             )
                             .clicked()
                             {
-                                action_stop = true;
+                                action_restart = true;
+                            }
+                            #[cfg(unix)]
+                            if show_signal {
+                                let response = ui
+                                    .add_enabled_ui(status == ProcessStatus::Running, |ui| {
+                                        chrome_text_button(
+                                            ui,
+                                            "⚡ Signal",
+                                            palette().toolbar_text,
+                                            Vec2::new(0.0, 28.0),
+                                            12.0,
+                                            false,
+                                        )
+                                    })
+                                    .inner
+                                    .on_hover_text("Send a signal without stopping the process")
+                                    .on_disabled_hover_text(
+                                        "Only running processes can be signalled",
+                                    );
+                                egui::Popup::menu(&response).show(|ui| {
+                                    for signal in ProcessSignal::ALL {
+                                        if ui
+                                            .button(signal.to_string())
+                                            .on_hover_text(signal.description())
+                                            .clicked()
+                                        {
+                                            action_signal = Some(signal);
+                                        }
+                                    }
+                                });
                             }
                             if chrome_text_button(
                                 ui,
-                                "⟳ Restart",
-                                TOOLBAR_YELLOW,
+                                "🗑 Clear Logs",
+                                palette().toolbar_gray,
                                 Vec2::new(0.0, 28.0),
                                 12.0,
                                 false,
                             )
+                            .on_hover_text("Clear the log buffer for this process")
                             .clicked()
                             {
-                                action_restart = true;
+                                action_clear_logs = true;
+                            }
+                            if chrome_text_button(
+                                ui,
+                                "💾 Save Logs",
+                                palette().toolbar_text,
+                                Vec2::new(0.0, 28.0),
+                                12.0,
+                                false,
+                            )
+                            .on_hover_text("Save the log buffer to a file")
+                            .clicked()
+                            {
+                                action_save_logs = true;
+                            }
+                            if chrome_text_button(
+                                ui,
+                                copy_all_label,
+                                palette().toolbar_text,
+                                Vec2::new(0.0, 28.0),
+                                12.0,
+                                false,
+                            )
+                            .on_hover_text("Copy every buffered log line to the clipboard")
+                            .clicked()
+                            {
+                                action_copy_all_logs = true;
                             }
                             if selected_log_count > 0 {
                                 if chrome_text_button(
                                     ui,
                                     "📋 Copy Logs",
-                                    TOOLBAR_TEXT,
+                                    palette().toolbar_text,
                                     Vec2::new(0.0, 28.0),
                                     12.0,
                                     false,
@@ -2153,7 +3867,7 @@ impl ProcessManagerApp {
                                 if chrome_text_button(
                                     ui,
                                     "Clear",
-                                    TOOLBAR_GRAY,
+                                    palette().toolbar_gray,
                                     Vec2::new(0.0, 28.0),
                                     12.0,
                                     false,
@@ -2164,19 +3878,38 @@ impl ProcessManagerApp {
                                     action_clear_log_selection = true;
                                 }
                             }
+                            if show_open_folder
+                                && ui
+                                    .add_enabled_ui(working_directory_exists, |ui| {
+                                        chrome_text_button(
+                                            ui,
+                                            "📂 Folder",
+                                            palette().toolbar_text,
+                                            Vec2::new(0.0, 28.0),
+                                            12.0,
+                                            false,
+                                        )
+                                    })
+                                    .inner
+                                    .on_hover_text("Open the working directory in the file manager")
+                                    .on_disabled_hover_text("The working directory does not exist")
+                                    .clicked()
+                            {
+                                action_open_folder = true;
+                            }
                             ui.add_space(2.0);
                             let (sep_rect, _) =
                                 ui.allocate_exact_size(Vec2::new(1.0, 18.0), egui::Sense::hover());
                             ui.painter().vline(
                                 sep_rect.center().x,
                                 sep_rect.y_range(),
-                                Stroke::new(1.0, Color32::from_white_alpha(15)),
+                                Stroke::new(1.0, divider_color(15)),
                             );
                             ui.add_space(2.0);
                             if chrome_text_button(
                                 ui,
                                 "⚙ Edit",
-                                TOOLBAR_TEXT,
+                                palette().toolbar_text,
                                 Vec2::new(0.0, 28.0),
                                 12.0,
                                 false,
@@ -2188,7 +3921,7 @@ impl ProcessManagerApp {
                             if chrome_text_button(
                                 ui,
                                 "✕ Delete",
-                                TOOLBAR_RED,
+                                palette().toolbar_red,
                                 Vec2::new(0.0, 28.0),
                                 12.0,
                                 false,
@@ -2202,11 +3935,31 @@ impl ProcessManagerApp {
 
                     ui.add_space(10.0);
 
+                    if let Some(silence) = silence {
+                        let color = if status == ProcessStatus::Running {
+                            silent_output_color(
+                                silence,
+                                self.config.silent_output_warning_secs,
+                                self.config.silent_output_alert_secs,
+                            )
+                        } else {
+                            palette().text_muted
+                        };
+                        ui.label(
+                            RichText::new(format!("last output {} ago", format_uptime(silence)))
+                                .color(color)
+                                .size(11.5),
+                        )
+                        .on_hover_text("Time since the process last wrote to stdout or stderr");
+                        ui.add_space(10.0);
+                        ui.ctx().request_repaint_after(Duration::from_secs(1));
+                    }
+
                     let metadata_response = ui.add_sized(
                         Vec2::new(ui.available_width().max(0.0), 28.0),
                         egui::Label::new(
                             RichText::new(metadata.as_str())
-                                .color(TEXT_MUTED)
+                                .color(palette().text_muted)
                                 .size(11.5),
                         )
                         .truncate(),
@@ -2223,7 +3976,7 @@ impl ProcessManagerApp {
                 ui.painter().hline(
                     rect.x_range(),
                     rect.center().y,
-                    Stroke::new(1.0, Color32::from_white_alpha(10)),
+                    Stroke::new(1.0, divider_color(10)),
                 );
             });
 
@@ -2231,16 +3984,135 @@ impl ProcessManagerApp {
             .fill(Color32::TRANSPARENT)
             .inner_margin(egui::Margin::symmetric(CONTENT_GUTTER_X, 12))
             .show(ui, |ui| {
+                if process.process_type.is_container() {
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = 6.0;
+                        let running = status == ProcessStatus::Running;
+                        let response = inline_text_edit(
+                            ui,
+                            &mut self.exec_command,
+                            "Command to run in the container",
+                            360.0,
+                        );
+                        let submitted = response.lost_focus()
+                            && ui.input(|input| input.key_pressed(egui::Key::Enter));
+                        let clicked = ui
+                            .add_enabled_ui(running, |ui| {
+                                chrome_text_button(
+                                    ui,
+                                    "Exec",
+                                    palette().toolbar_text,
+                                    Vec2::new(0.0, 28.0),
+                                    12.0,
+                                    true,
+                                )
+                                .on_hover_text(
+                                    "Run with docker exec; output is appended to the log",
+                                )
+                                .on_disabled_hover_text("Start the container to run commands in it")
+                                .clicked()
+                            })
+                            .inner;
+                        action_exec = running && (clicked || submitted);
+                    });
+                    ui.add_space(8.0);
+                }
+
+                if !logs.is_empty() || !self.log_filter.is_empty() {
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = 6.0;
+                        modal_tab_button(ui, &mut self.log_kind_filter, LogKindFilter::All, "All");
+                        modal_tab_button(
+                            ui,
+                            &mut self.log_kind_filter,
+                            LogKindFilter::Errors,
+                            "Errors",
+                        );
+                        modal_tab_button(
+                            ui,
+                            &mut self.log_kind_filter,
+                            LogKindFilter::Stdout,
+                            "stdout",
+                        );
+                        modal_tab_button(
+                            ui,
+                            &mut self.log_kind_filter,
+                            LogKindFilter::Stderr,
+                            "stderr",
+                        );
+                        ui.add_space(4.0);
+                        inline_text_edit(ui, &mut self.log_filter, "Filter logs", 280.0);
+                        if !self.log_filter.is_empty()
+                            && chrome_text_button(
+                                ui,
+                                "✕",
+                                palette().toolbar_gray,
+                                Vec2::new(28.0, 28.0),
+                                12.0,
+                                false,
+                            )
+                            .on_hover_text("Clear filter")
+                            .clicked()
+                        {
+                            self.log_filter.clear();
+                        }
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            let mut wrap = self.config.wrap_log_lines;
+                            if modal_tab_button(ui, &mut wrap, true, "Wrap")
+                                .on_hover_text(
+                                    "Wrap long lines; turn off to scroll wide output horizontally",
+                                )
+                                .clicked()
+                            {
+                                self.config.wrap_log_lines = !self.config.wrap_log_lines;
+                                self.schedule_config_save();
+                            }
+                        });
+                    });
+                    ui.add_space(8.0);
+                }
+
+                let filter = self.log_filter.trim().to_lowercase();
+                let kind_filter = self.log_kind_filter;
+                let highlight_rules = self.manager.highlight_rules();
+                let visible_logs: Vec<(usize, &LogEntry)> = logs
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, entry)| {
+                        if kind_filter == LogKindFilter::All && filter.is_empty() {
+                            return true;
+                        }
+                        let plain = strip_ansi(&entry.text);
+                        kind_filter.matches(&plain, entry.source, &highlight_rules)
+                            && (filter.is_empty() || plain.to_lowercase().contains(&filter))
+                    })
+                    .map(|(offset, line)| (visible_log_start + offset, line))
+                    .collect();
+
+                let search_regex = if self.log_search.open {
+                    self.draw_log_search_bar(ui, &visible_logs)
+                } else {
+                    None
+                };
+
                 let remaining_height = ui.available_height();
                 if logs.is_empty() {
                     ui.set_min_height(remaining_height.max(0.0));
                     ui.label(
                         RichText::new("No output yet. Start the process to see logs.")
-                            .color(TEXT_SOFT)
+                            .color(palette().text_soft)
+                            .monospace(),
+                    );
+                } else if visible_logs.is_empty() {
+                    ui.set_min_height(remaining_height.max(0.0));
+                    ui.label(
+                        RichText::new("No log lines match the current filters.")
+                            .color(palette().text_soft)
                             .monospace(),
                     );
                 } else {
-                    let output = ScrollArea::vertical()
+                    let wrap = self.config.wrap_log_lines;
+                    let mut output = ScrollArea::new([!wrap, true])
                         .id_salt(("process_logs", &process.id))
                         .auto_shrink([false, false])
                         .max_height(remaining_height.max(0.0))
@@ -2248,9 +4120,9 @@ impl ProcessManagerApp {
                         .show(ui, |ui| {
                             ui.spacing_mut().item_spacing = Vec2::new(0.0, 4.0);
 
-                            for (offset, line) in logs.iter().enumerate() {
-                                let log_index = visible_log_start + offset;
-                                let style = classify_log_line(line);
+                            for (log_index, entry) in visible_logs.iter().copied() {
+                                let line = entry.text.as_str();
+                                let style = log_line_style(entry);
                                 let frozen = self.is_frozen_log_line(&process.id, log_index);
                                 let text_selection_frozen =
                                     self.is_log_text_selection_frozen(&process.id);
@@ -2258,7 +4130,32 @@ impl ProcessManagerApp {
                                     || self.selected_log_range(&process.id).is_some_and(
                                         |(start, end)| (start..=end).contains(&log_index),
                                     );
-                                let response = draw_log_line(ui, line, style, selected, frozen);
+                                let is_current_match = self.log_search.current == Some(log_index);
+                                let match_ranges: Vec<std::ops::Range<usize>> = search_regex
+                                    .as_ref()
+                                    .map(|regex| {
+                                        regex
+                                            .find_iter(&strip_ansi(line))
+                                            .map(|found| found.range())
+                                            .filter(|range| !range.is_empty())
+                                            .collect()
+                                    })
+                                    .unwrap_or_default();
+                                let highlight = (!match_ranges.is_empty()).then(|| {
+                                    let color = if is_current_match {
+                                        palette().log_match_current_bg
+                                    } else {
+                                        palette().log_match_bg
+                                    };
+                                    (match_ranges.as_slice(), color)
+                                });
+                                let response = draw_log_line(
+                                    ui, line, style, selected, frozen, wrap, highlight,
+                                );
+                                if is_current_match && self.log_search.scroll_pending {
+                                    response.scroll_to_me(Some(Align::Center));
+                                    self.log_search.scroll_pending = false;
+                                }
                                 if frozen && response.clicked_elsewhere() {
                                     self.clear_log_selection();
                                 } else if response.double_clicked() {
@@ -2270,9 +4167,7 @@ impl ProcessManagerApp {
                             }
                         });
 
-                    let max_offset = (output.content_size.y - output.inner_rect.height()).max(0.0);
-                    let distance_from_bottom = (max_offset - output.state.offset.y).max(0.0);
-                    self.stick_logs_to_bottom = distance_from_bottom <= LOG_STICK_THRESHOLD_PX;
+                    self.stick_logs_to_bottom = follow_log_scroll(ui, &mut output);
                 }
             });
 
@@ -2285,25 +4180,314 @@ impl ProcessManagerApp {
         if action_restart {
             self.manager.restart_process(&process.id);
         }
+        #[cfg(unix)]
+        if let Some(signal) = action_signal {
+            if let Err(err) = self.manager.send_signal(&process.id, signal) {
+                self.set_banner(err);
+            }
+        }
         if action_copy_logs {
             self.copy_selected_logs();
         }
+        if action_save_logs {
+            self.save_logs_to_file(&process.id);
+        }
+        if action_copy_all_logs {
+            self.copy_all_logs(&process.id);
+        }
+        if action_clear_logs {
+            self.clear_log_selection();
+            self.manager.clear_logs(&process.id);
+        }
         if action_clear_log_selection {
             self.clear_log_selection();
         }
+        if action_open_folder {
+            if let Err(err) = open_in_file_manager(working_directory) {
+                self.set_banner(err);
+            }
+        }
         if action_stop {
             self.manager.stop_process(&process.id);
         }
         if action_start {
             self.manager.start_process(&process.id);
         }
+        if action_exec {
+            match self.manager.docker_exec(&process.id, &self.exec_command) {
+                Ok(()) => {
+                    self.exec_command.clear();
+                    self.stick_logs_to_bottom = true;
+                }
+                Err(err) => self.set_banner(err),
+            }
+        }
+    }
+
+    /// The sidebar's "All" view: recent output of every process, interleaved by arrival time
+    /// and prefixed with the process name, with its own auto-scroll.
+    fn draw_combined_logs(&mut self, ui: &mut Ui) {
+        let lines = self.combined_logs.clone();
+        let process_count = lines
+            .iter()
+            .map(|line| line.process_id.as_str())
+            .collect::<HashSet<_>>()
+            .len();
+        let name_colors: HashMap<String, Color32> = self
+            .config
+            .processes
+            .iter()
+            .enumerate()
+            .map(|(index, process)| {
+                let color = match process_color(process) {
+                    Some([r, g, b]) => Color32::from_rgb(r, g, b),
+                    None => ansi_palette_color(
+                        COMBINED_LOG_NAME_COLORS[index % COMBINED_LOG_NAME_COLORS.len()],
+                    ),
+                };
+                (process.id.clone(), color)
+            })
+            .collect();
+
+        egui::Frame::default()
+            .fill(Color32::TRANSPARENT)
+            .stroke(Stroke::NONE)
+            .inner_margin(egui::Margin::symmetric(CONTENT_GUTTER_X, 10))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.set_height(28.0);
+                    ui.label(
+                        RichText::new("All processes")
+                            .color(palette().text_main)
+                            .size(14.0)
+                            .strong(),
+                    );
+                    ui.add_space(10.0);
+                    ui.label(
+                        RichText::new(format!(
+                            "{} recent lines from {} processes, in the order they arrived",
+                            lines.len(),
+                            process_count
+                        ))
+                        .color(palette().text_muted)
+                        .size(11.5),
+                    );
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        let mut wrap = self.config.wrap_log_lines;
+                        if modal_tab_button(ui, &mut wrap, true, "Wrap")
+                            .on_hover_text(
+                                "Wrap long lines; turn off to scroll wide output horizontally",
+                            )
+                            .clicked()
+                        {
+                            self.config.wrap_log_lines = !self.config.wrap_log_lines;
+                            self.schedule_config_save();
+                        }
+                    });
+                });
+
+                ui.add_space(8.0);
+                let (rect, _) = ui.allocate_exact_size(
+                    Vec2::new(ui.available_width(), 1.0),
+                    egui::Sense::hover(),
+                );
+                ui.painter().hline(
+                    rect.x_range(),
+                    rect.center().y,
+                    Stroke::new(1.0, divider_color(10)),
+                );
+            });
+
+        egui::Frame::default()
+            .fill(Color32::TRANSPARENT)
+            .inner_margin(egui::Margin::symmetric(CONTENT_GUTTER_X, 12))
+            .show(ui, |ui| {
+                let remaining_height = ui.available_height();
+                if lines.is_empty() {
+                    ui.set_min_height(remaining_height.max(0.0));
+                    ui.label(
+                        RichText::new("No output yet. Start a process to see logs.")
+                            .color(palette().text_soft)
+                            .monospace(),
+                    );
+                    return;
+                }
+
+                let wrap = self.config.wrap_log_lines;
+                let mut output = ScrollArea::new([!wrap, true])
+                    .id_salt("combined_logs")
+                    .auto_shrink([false, false])
+                    .max_height(remaining_height.max(0.0))
+                    .stick_to_bottom(self.stick_combined_logs_to_bottom)
+                    .show(ui, |ui| {
+                        ui.spacing_mut().item_spacing = Vec2::new(0.0, 4.0);
+                        for line in lines.iter() {
+                            let name_color = name_colors
+                                .get(&line.process_id)
+                                .copied()
+                                .unwrap_or(palette().text_muted);
+                            draw_combined_log_line(ui, line, name_color, wrap);
+                        }
+                    });
+                self.stick_combined_logs_to_bottom = follow_log_scroll(ui, &mut output);
+            });
+    }
+
+    /// Find bar above the log view. Returns the compiled search for highlighting, if any.
+    /// Navigating to a match pauses auto-scroll; clearing or closing the search resumes it.
+    fn draw_log_search_bar(
+        &mut self,
+        ui: &mut Ui,
+        visible_logs: &[(usize, &LogEntry)],
+    ) -> Option<Regex> {
+        let mut step: Option<bool> = None;
+        let mut close = false;
+        let mut compiled = None;
+
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 6.0;
+            let response = inline_text_edit(ui, &mut self.log_search.query, "Find in logs", 280.0);
+            if self.log_search.focus_pending {
+                response.request_focus();
+                self.log_search.focus_pending = false;
+            }
+            let mut query_changed =
+                response.changed() || std::mem::take(&mut self.log_search.query_pending);
+            if response.lost_focus() && ui.input(|input| input.key_pressed(Key::Enter)) {
+                step = Some(!ui.input(|input| input.modifiers.shift));
+                response.request_focus();
+            }
+            let mut use_regex = self.log_search.regex;
+            if modal_tab_button(ui, &mut use_regex, true, ".*")
+                .on_hover_text("Treat the search as a regular expression")
+                .clicked()
+            {
+                self.log_search.regex = !self.log_search.regex;
+                query_changed = true;
+            }
+
+            compiled = log_search_regex(&self.log_search.query, self.log_search.regex);
+            let matches: Vec<usize> = match &compiled {
+                Some(Ok(regex)) => visible_logs
+                    .iter()
+                    .filter(|(_, entry)| regex.is_match(&strip_ansi(&entry.text)))
+                    .map(|(index, _)| *index)
+                    .collect(),
+                _ => Vec::new(),
+            };
+            if query_changed {
+                self.log_search.current = matches.first().copied();
+                if self.log_search.current.is_some() {
+                    self.log_search.scroll_pending = true;
+                    self.stick_logs_to_bottom = false;
+                } else if self.log_search.query.is_empty() {
+                    self.stick_logs_to_bottom = true;
+                }
+            }
+            if let Some(forward) = step.take() {
+                self.log_search.current =
+                    step_log_search(&matches, self.log_search.current, forward);
+                if self.log_search.current.is_some() {
+                    self.log_search.scroll_pending = true;
+                    self.stick_logs_to_bottom = false;
+                }
+            }
+
+            let (summary, color) = match &compiled {
+                None => (String::new(), palette().text_muted),
+                Some(Err(_)) => ("Invalid regex".to_string(), palette().danger),
+                Some(Ok(_)) if matches.is_empty() => {
+                    ("No matches".to_string(), palette().text_muted)
+                }
+                Some(Ok(_)) => {
+                    let position = self
+                        .log_search
+                        .current
+                        .and_then(|current| matches.iter().position(|&index| index == current));
+                    let summary = match position {
+                        Some(position) => format!("{} of {}", position + 1, matches.len()),
+                        None => format!("{} matches", matches.len()),
+                    };
+                    (summary, palette().text_muted)
+                }
+            };
+            ui.label(RichText::new(summary).color(color).size(11.5));
+
+            for (label, hint, forward) in [
+                ("▲", "Previous match (Shift+Enter)", false),
+                ("▼", "Next match (Enter)", true),
+            ] {
+                if chrome_text_button(
+                    ui,
+                    label,
+                    palette().toolbar_gray,
+                    Vec2::new(28.0, 28.0),
+                    12.0,
+                    false,
+                )
+                .on_hover_text(hint)
+                .clicked()
+                {
+                    self.log_search.current =
+                        step_log_search(&matches, self.log_search.current, forward);
+                    if self.log_search.current.is_some() {
+                        self.log_search.scroll_pending = true;
+                        self.stick_logs_to_bottom = false;
+                    }
+                }
+            }
+            if chrome_text_button(
+                ui,
+                "✕",
+                palette().toolbar_gray,
+                Vec2::new(28.0, 28.0),
+                12.0,
+                false,
+            )
+            .on_hover_text("Close search")
+            .clicked()
+            {
+                close = true;
+            }
+        });
+        ui.add_space(8.0);
+
+        if close {
+            self.log_search = LogSearch::default();
+            self.stick_logs_to_bottom = true;
+            return None;
+        }
+        compiled.and_then(Result::ok)
     }
 
     fn draw_process_dialog(&mut self, ctx: &Context) {
         let mut close_dialog = false;
         let mut submit_dialog = false;
+        let mut test_dialog = false;
+
+        let editing_id = match &self.process_dialog {
+            Some(ProcessDialog::Edit { id, .. }) => Some(id.clone()),
+            _ => None,
+        };
+        let dependency_candidates: Vec<(String, String)> = self
+            .config
+            .processes
+            .iter()
+            .filter(|process| Some(&process.id) != editing_id.as_ref())
+            .map(|process| (process.id.clone(), process.name.clone()))
+            .collect();
 
         if let Some(dialog) = self.process_dialog.as_mut() {
+            let form = dialog.form_mut();
+            let finished = form
+                .test_pending
+                .as_ref()
+                .and_then(|pending| pending.lock().unwrap().take());
+            if let Some(result) = finished {
+                form.test_result = Some(result);
+                form.test_pending = None;
+            }
+
             let mut open = true;
             Window::new(dialog.title())
                 .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
@@ -2312,8 +4496,8 @@ impl ProcessManagerApp {
                 .fixed_size([430.0, PROCESS_DIALOG_HEIGHT])
                 .frame(
                     egui::Frame::window(&ctx.style())
-                        .fill(PANEL_BG)
-                        .stroke(Stroke::new(1.0, BORDER)),
+                        .fill(palette().panel_bg)
+                        .stroke(Stroke::new(1.0, palette().border)),
                 )
                 .open(&mut open)
                 .show(ctx, |ui| {
@@ -2340,10 +4524,10 @@ impl ProcessManagerApp {
                                 scroll.interact_background_opacity = 1.0;
                                 scroll.interact_handle_opacity = 1.0;
                                 let visuals = ui.visuals_mut();
-                                visuals.extreme_bg_color = Color32::from_gray(38);
-                                visuals.widgets.inactive.bg_fill = Color32::from_gray(92);
-                                visuals.widgets.hovered.bg_fill = Color32::from_gray(108);
-                                visuals.widgets.active.bg_fill = Color32::from_gray(116);
+                                visuals.extreme_bg_color = palette().scroll_track;
+                                visuals.widgets.inactive.bg_fill = palette().scroll_handle;
+                                visuals.widgets.hovered.bg_fill = palette().scroll_handle_hover;
+                                visuals.widgets.active.bg_fill = palette().scroll_handle_active;
 
                                 ScrollArea::vertical()
                                     .id_salt("process_dialog_scroll")
@@ -2361,42 +4545,160 @@ impl ProcessManagerApp {
                                             "Frontend Dev Server",
                                             MODAL_FORM_WIDTH,
                                         );
+                                        if self.config.name_exists(&form.name, editing_id.as_deref()) {
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new(duplicate_name_message(&form.name))
+                                                    .color(palette().danger)
+                                                    .size(11.5),
+                                            );
+                                        }
 
                                         ui.add_space(14.0);
-                                        ui.label(field_label("Type"));
-                                        ui.horizontal(|ui| {
-                                            modal_tab_button(
-                                                ui,
-                                                &mut form.process_type,
-                                                ProcessType::Process,
-                                                "Process",
-                                            );
-                                            modal_tab_button(
+                                        ui.label(field_label("Group"));
+                                        modal_text_edit(
+                                            ui,
+                                            &mut form.group,
+                                            DEFAULT_GROUP_NAME,
+                                            MODAL_FORM_WIDTH,
+                                        );
+                                        ui.add_space(6.0);
+                                        ui.label(
+                                            RichText::new("Entries with the same group are listed together in the sidebar. Leave empty for General.")
+                                                .color(palette().text_muted)
+                                                .size(11.5),
+                                        );
+                                        ui.add_space(14.0);
+                                        modal_checkbox_row(
+                                            ui,
+                                            &mut form.use_color,
+                                            "Color label",
+                                            Some("Ring the sidebar status dot in this color, e.g. blue for databases and green for web servers."),
+                                        );
+                                        if form.use_color {
+                                            ui.add_space(6.0);
+                                            ui.color_edit_button_srgb(&mut form.color);
+                                        }
+
+                                        ui.add_space(14.0);
+                                        ui.label(field_label("Type"));
+                                        ui.horizontal(|ui| {
+                                            modal_tab_button(
+                                                ui,
+                                                &mut form.process_type,
+                                                ProcessType::Process,
+                                                "Process",
+                                            );
+                                            modal_tab_button(
                                                 ui,
                                                 &mut form.process_type,
                                                 ProcessType::Docker,
                                                 "Docker",
                                             );
+                                            modal_tab_button(
+                                                ui,
+                                                &mut form.process_type,
+                                                ProcessType::Compose,
+                                                "Compose",
+                                            );
                                         });
 
                                         ui.add_space(14.0);
-                                        ui.label(field_label(if form.process_type == ProcessType::Docker {
-                                            "Container Name"
-                                        } else {
-                                            "Command"
+                                        ui.label(field_label(match form.process_type {
+                                            ProcessType::Docker => "Container Name",
+                                            ProcessType::Compose => "Service Name",
+                                            ProcessType::Process if form.args.is_empty() => "Command",
+                                            ProcessType::Process => "Program",
                                         }));
                                         modal_text_edit(
                                             ui,
                                             &mut form.command,
-                                            if form.process_type == ProcessType::Docker {
-                                                "my-postgres-container"
-                                            } else {
-                                                "npm run dev"
+                                            match form.process_type {
+                                                ProcessType::Docker => "my-postgres-container",
+                                                ProcessType::Compose => "db",
+                                                ProcessType::Process => "npm run dev",
                                             },
                                             MODAL_FORM_WIDTH,
                                         );
 
+                                        if form.process_type == ProcessType::Compose {
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Compose File"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.compose_file,
+                                                "C:/projects/my-app/docker-compose.yml",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("Passed to docker compose -f. Start runs up -d for the service; stop runs compose stop.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+                                        }
+
+                                        if form.process_type == ProcessType::Docker {
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Create With (docker run arguments)"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.docker_run_args,
+                                                "-p 5432:5432 postgres:16",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("Optional. If the container does not exist yet, Start runs docker run -d --name <container> with these arguments. Put the image last; quote values that contain spaces.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+                                        }
+
+                                        if form.process_type != ProcessType::Process {
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Log Tail (lines)"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.docker_tail,
+                                                "all",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Logs Since"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.docker_since,
+                                                "5m",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("How much backlog to fetch when attaching to the logs. Leave the tail blank for the full history; since takes a duration like 5m or a timestamp.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+                                        }
+
                                         if form.process_type == ProcessType::Process {
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Arguments"));
+                                            draw_arg_rows(ui, &mut form.args);
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("When arguments are listed, the command is used as the program path as-is. Leave empty to split the command line.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+
+                                            ui.add_space(14.0);
+                                            modal_checkbox_row(
+                                                ui,
+                                                &mut form.use_shell,
+                                                "Run through shell",
+                                                Some("Uses sh -c (cmd /C on Windows) so pipes and redirects work. Shell operators are no longer validated."),
+                                            );
+
                                             ui.add_space(14.0);
                                             ui.label(field_label("Working Directory"));
                                             modal_text_edit(
@@ -2405,6 +4707,152 @@ impl ProcessManagerApp {
                                                 "C:/projects/my-app",
                                                 MODAL_FORM_WIDTH,
                                             );
+                                            let working_directory = form.working_directory.trim();
+                                            if !working_directory.is_empty()
+                                                && !std::path::Path::new(working_directory).is_dir()
+                                            {
+                                                ui.add_space(6.0);
+                                                ui.label(
+                                                    RichText::new("This folder does not exist. The process will fail to start.")
+                                                        .color(palette().warning)
+                                                        .size(11.5),
+                                                );
+                                            }
+
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Environment Variables"));
+                                            draw_env_var_rows(ui, &mut form.env);
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new(if form.inherit_env {
+                                                    "Applied on top of the inherited environment. Rows without a key are ignored."
+                                                } else {
+                                                    "The only variables besides a minimal PATH. Rows without a key are ignored."
+                                                })
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+                                            ui.add_space(8.0);
+                                            modal_checkbox_row(
+                                                ui,
+                                                &mut form.inherit_env,
+                                                "Inherit the manager's environment",
+                                                Some("Turn off to start from a clean environment with a minimal PATH, the env file and the variables above."),
+                                            );
+
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Env File"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.env_file,
+                                                ".env",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("Optional dotenv file, relative to the working directory. Variables above override values from the file.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Expected Port"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.expected_port,
+                                                "3000",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("Checked before launch; if something already holds it, a warning naming the holder is logged.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+                                            if !form.expected_port.trim().is_empty() {
+                                                ui.add_space(8.0);
+                                                modal_checkbox_row(
+                                                    ui,
+                                                    &mut form.free_port_before_start,
+                                                    "Kill the port holder before start",
+                                                    Some("Force-kills whatever process is listening on the port, such as a leftover dev server."),
+                                                );
+                                            }
+
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Pre-Start Hook"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.pre_start,
+                                                "npm run migrate",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("Runs to completion in the working directory before each launch. If it fails, the start is aborted.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Post-Stop Hook"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.post_stop,
+                                                "rm -f app.pid",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("Runs after the process has stopped or exited. Hook output is added to the log.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Hook Timeout (seconds)"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.hook_timeout_secs,
+                                                "60",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("A hook still running after this long is killed and counts as failed. A stop stays in progress until the post-stop hook is done.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Run As User"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.run_as,
+                                                "www-data",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("Unix only. Launches through `sudo -n -u`, so it needs a passwordless sudo rule; otherwise the entry fails with sudo's message. Leave blank to run as yourself.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Watch Paths"));
+                                            modal_multiline_edit(
+                                                ui,
+                                                &mut form.watch_paths,
+                                                "src",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("One file or folder per line, relative to the working directory. Changes restart the running process; node_modules, target, and .git are ignored.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
                                         }
 
                                         ui.add_space(16.0);
@@ -2429,20 +4877,85 @@ impl ProcessManagerApp {
                                         ui.add_space(6.0);
                                         ui.label(
                                             RichText::new("Wait before launching this entry after any start request. Use 0 to start immediately.")
-                                                .color(TEXT_MUTED)
+                                                .color(palette().text_muted)
                                                 .size(11.5),
                                         );
 
+                                        if form.process_type == ProcessType::Process {
+                                            ui.add_space(14.0);
+                                            ui.label(field_label("Stop Timeout (seconds)"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.stop_timeout_secs,
+                                                "5",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("Time allowed for a graceful exit after a stop request before the process is force-killed.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+                                        }
+
                                         ui.add_space(14.0);
-                                        modal_checkbox_row(
+                                        ui.label(field_label("Start Retries"));
+                                        modal_text_edit(
                                             ui,
-                                            &mut form.auto_restart,
-                                            "Managed restart",
-                                            Some("Automatically restart this entry if it goes down."),
+                                            &mut form.start_retries,
+                                            "0",
+                                            MODAL_FORM_WIDTH,
+                                        );
+                                        ui.add_space(6.0);
+                                        ui.label(
+                                            RichText::new("Extra launch attempts, 1s apart, when the start itself fails (for example a locked file or a Docker daemon still starting). Crashes after a successful start are handled by Managed Restart.")
+                                                .color(palette().text_muted)
+                                                .size(11.5),
+                                        );
+
+                                        ui.add_space(14.0);
+                                        ui.label(field_label("Managed Restart"));
+                                        ui.horizontal(|ui| {
+                                            for policy in [
+                                                RestartPolicy::Never,
+                                                RestartPolicy::OnFailure,
+                                                RestartPolicy::Always,
+                                            ] {
+                                                modal_tab_button(
+                                                    ui,
+                                                    &mut form.restart_policy,
+                                                    policy,
+                                                    &policy.to_string(),
+                                                );
+                                            }
+                                        });
+                                        ui.add_space(6.0);
+                                        ui.label(
+                                            RichText::new(match form.restart_policy {
+                                                RestartPolicy::Never => "Exits are left alone.",
+                                                RestartPolicy::OnFailure => "Restart when the entry exits with a non-zero code. Clean exits and stop requests are left alone.",
+                                                RestartPolicy::Always => "Restart whenever the entry goes down without a stop request.",
+                                            })
+                                            .color(palette().text_muted)
+                                            .size(11.5),
                                         );
 
                                         ui.add_space(14.0);
-                                        if form.auto_restart {
+                                        if form.restart_policy.is_enabled() {
+                                            ui.label(field_label("Max Restart Attempts"));
+                                            modal_text_edit(
+                                                ui,
+                                                &mut form.max_restart_attempts,
+                                                "0",
+                                                MODAL_FORM_WIDTH,
+                                            );
+                                            ui.add_space(6.0);
+                                            ui.label(
+                                                RichText::new("Consecutive restarts (1s, 2s, 4s... up to 30s apart) before the entry is marked as errored. Use 0 to retry forever.")
+                                                    .color(palette().text_muted)
+                                                    .size(11.5),
+                                            );
+                                            ui.add_space(14.0);
                                             draw_restart_schedule_summary(ui, form);
                                             ui.add_space(14.0);
                                         } else {
@@ -2461,9 +4974,40 @@ impl ProcessManagerApp {
                                             ui.add_space(14.0);
                                         }
 
+                                        modal_checkbox_row(
+                                            ui,
+                                            &mut form.health_check.enabled,
+                                            "HTTP health check",
+                                            Some("Poll a URL while running and show whether the app is actually serving."),
+                                        );
+                                        ui.add_space(14.0);
+                                        if form.health_check.enabled {
+                                            draw_health_check_fields(ui, form);
+                                            ui.add_space(14.0);
+                                        }
+
                                         draw_stack_control_group(ui, form);
 
+                                        if !dependency_candidates.is_empty() {
+                                            ui.add_space(14.0);
+                                            draw_dependency_group(
+                                                ui,
+                                                form,
+                                                &dependency_candidates,
+                                            );
+                                        }
+
                                         ui.add_space(14.0);
+                                        modal_checkbox_row(
+                                            ui,
+                                            &mut form.clear_logs_on_start,
+                                            "Clear logs on start",
+                                            Some(
+                                                "Turn off to keep earlier output, such as the crash before a managed restart; each run then starts with a separator line.",
+                                            ),
+                                        );
+
+                                        ui.add_space(8.0);
                                         modal_checkbox_row(
                                             ui,
                                             &mut form.log_to_disk,
@@ -2492,7 +5036,7 @@ impl ProcessManagerApp {
                                         ui.add_space(6.0);
                                         ui.label(
                                             RichText::new("Number of session log files to retain for this process.")
-                                                .color(TEXT_MUTED)
+                                                .color(palette().text_muted)
                                                 .size(11.5),
                                         );
                                     });
@@ -2500,13 +5044,38 @@ impl ProcessManagerApp {
                         },
                     );
 
+                    let test_result = form.test_result.clone();
+                    let testing = form.test_pending.is_some();
                     modal_footer(ui, |ui| {
-                        if subtle_action_button(ui, "Save", Some(ACCENT_SOFT)).clicked() {
+                        if subtle_action_button(ui, "Save", Some(palette().accent_soft)).clicked() {
                             submit_dialog = true;
                         }
                         if shell_button(ui, "Cancel").clicked() {
                             close_dialog = true;
                         }
+                        if shell_button(ui, "Test")
+                            .on_hover_text("Check that the command and working directory resolve, or that the container exists, without starting anything")
+                            .clicked()
+                        {
+                            test_dialog = true;
+                        }
+                        if testing {
+                            ui.label(
+                                RichText::new("Testing...")
+                                    .color(palette().text_muted)
+                                    .size(11.5),
+                            );
+                        } else if let Some(result) = &test_result {
+                            let (text, color) = match result {
+                                Ok(found) => (format!("✔ {}", found), palette().running),
+                                Err(err) => (format!("✕ {}", err), palette().danger),
+                            };
+                            ui.add(
+                                egui::Label::new(RichText::new(&text).color(color).size(11.5))
+                                    .truncate(),
+                            )
+                            .on_hover_text(text);
+                        }
                     });
                 });
 
@@ -2517,6 +5086,23 @@ impl ProcessManagerApp {
             }
         }
 
+        if test_dialog {
+            if let Some(dialog) = self.process_dialog.as_mut() {
+                let form = dialog.form_mut();
+                if form.test_pending.is_none() {
+                    let pending = Arc::new(Mutex::new(None));
+                    form.test_pending = Some(pending.clone());
+                    let manager = self.manager.clone();
+                    let config = form.test_config();
+                    let ctx = ctx.clone();
+                    std::thread::spawn(move || {
+                        let result = manager.validate_config(&config);
+                        *pending.lock().unwrap() = Some(result);
+                        ctx.request_repaint();
+                    });
+                }
+            }
+        }
         if submit_dialog {
             if let Some(dialog) = self.process_dialog.take() {
                 self.apply_process_dialog(dialog);
@@ -2546,8 +5132,8 @@ impl ProcessManagerApp {
             .fixed_size([420.0, GLOBAL_SETTINGS_HEIGHT])
             .frame(
                 egui::Frame::window(&ctx.style())
-                    .fill(PANEL_BG)
-                    .stroke(Stroke::new(1.0, BORDER)),
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
             )
             .open(&mut open)
             .show(ctx, |ui| {
@@ -2571,7 +5157,13 @@ impl ProcessManagerApp {
                                 1usize,
                                 "Local API",
                             );
-                            modal_tab_button(ui, &mut self.global_settings_tab, 2usize, "About");
+                            modal_tab_button(
+                                ui,
+                                &mut self.global_settings_tab,
+                                2usize,
+                                "Highlighting",
+                            );
+                            modal_tab_button(ui, &mut self.global_settings_tab, 3usize, "About");
                         });
                         ui.add_space(10.0);
                         let (rect, _) = ui.allocate_exact_size(
@@ -2581,7 +5173,7 @@ impl ProcessManagerApp {
                         ui.painter().hline(
                             rect.x_range(),
                             rect.center().y,
-                            Stroke::new(1.0, Color32::from_white_alpha(10)),
+                            Stroke::new(1.0, divider_color(10)),
                         );
                         ui.add_space(14.0);
 
@@ -2611,7 +5203,7 @@ impl ProcessManagerApp {
                                     RichText::new(
                                         "Process Manager creates 'Process Manager logs' here. '.' resolves next to the executable.",
                                     )
-                                    .color(TEXT_MUTED)
+                                    .color(palette().text_muted)
                                     .size(11.5),
                                 );
                                 ui.add_space(14.0);
@@ -2627,66 +5219,244 @@ impl ProcessManagerApp {
                                     RichText::new(
                                         "Softly flashes the Processes sidebar when a new error arrives. Use 0 to disable.",
                                     )
-                                    .color(TEXT_MUTED)
+                                    .color(palette().text_muted)
                                     .size(11.5),
                                 );
-                            } else if self.global_settings_tab == 1 {
-                                modal_checkbox_row(
+                                ui.add_space(14.0);
+                                ui.label(field_label("Start All Stagger (ms)"));
+                                modal_text_edit(
                                     ui,
-                                    &mut self.rest_settings_form.enabled,
-                                    "Enable localhost REST control",
-                                    Some("Expose a loopback-only API for local tooling."),
+                                    &mut self.rest_settings_form.start_all_delay_ms,
+                                    "0",
+                                    MODAL_FORM_WIDTH,
+                                );
+                                ui.add_space(6.0);
+                                ui.label(
+                                    RichText::new(
+                                        "Pause between launches when using Start All. Stop All and Restart All stay immediate. Use 0 to start everything at once.",
+                                    )
+                                    .color(palette().text_muted)
+                                    .size(11.5),
                                 );
                                 ui.add_space(14.0);
-                                ui.label(field_label("Host"));
-                                modal_disabled_text_edit(ui, &mut host_text, MODAL_FORM_WIDTH);
+                                ui.label(field_label("Docker Status Poll (ms)"));
+                                modal_text_edit(
+                                    ui,
+                                    &mut self.rest_settings_form.docker_poll_interval_ms,
+                                    "2000",
+                                    MODAL_FORM_WIDTH,
+                                );
+                                ui.add_space(6.0);
+                                ui.label(
+                                    RichText::new(
+                                        "How often Docker and Compose entries are checked. Polls run faster right after a status change, slower once everything has been stable for a minute, and pause while the window is minimized.",
+                                    )
+                                    .color(palette().text_muted)
+                                    .size(11.5),
+                                );
                                 ui.add_space(14.0);
-                                ui.label(field_label("Port"));
+                                ui.label(field_label("Log Buffer (lines)"));
                                 modal_text_edit(
                                     ui,
-                                    &mut self.rest_settings_form.port,
-                                    "3000",
+                                    &mut self.rest_settings_form.max_log_lines,
+                                    "1000",
                                     MODAL_FORM_WIDTH,
                                 );
                                 ui.add_space(6.0);
                                 ui.label(
-                                    RichText::new("The API binds only to 127.0.0.1.")
-                                        .color(TEXT_MUTED)
-                                        .size(11.5),
+                                    RichText::new(
+                                        "Output lines kept in memory per process. Older lines are dropped first; disk logs keep everything.",
+                                    )
+                                    .color(palette().text_muted)
+                                    .size(11.5),
                                 );
-                            } else if self.global_settings_tab == 2 {
+                                ui.add_space(14.0);
+                                ui.label(field_label("Max Output Rate (lines/s)"));
+                                modal_text_edit(
+                                    ui,
+                                    &mut self.rest_settings_form.max_output_lines_per_second,
+                                    "10000",
+                                    MODAL_FORM_WIDTH,
+                                );
+                                ui.add_space(6.0);
                                 ui.label(
-                                    RichText::new("Project Information")
-                                        .color(TEXT_MAIN)
-                                        .size(14.0)
-                                        .strong(),
+                                    RichText::new(
+                                        "Lines a process may log per second before the rest are dropped and counted in a [N lines suppressed] marker, so a runaway process cannot freeze the window. 0 disables the limit.",
+                                    )
+                                    .color(palette().text_muted)
+                                    .size(11.5),
                                 );
                                 ui.add_space(14.0);
-
-                                for field in about_fields() {
-                                    draw_about_field(ui, &field);
-                                    ui.add_space(10.0);
-                                }
-
-                            }
-
-                            if let Some(error) = &self.rest_settings_error {
-                                ui.add_space(12.0);
-                                ui.label(RichText::new(error).color(DANGER).size(12.0));
-                            }
-                        });
-                    },
-                );
-
-                modal_footer(ui, |ui| {
-                    if self.global_settings_tab != 2
-                        && subtle_action_button(ui, "Save", Some(ACCENT_SOFT)).clicked()
-                    {
-                        save = true;
-                    }
-                    let close_label = if self.global_settings_tab == 2 {
-                        "Close"
-                    } else {
+                                ui.label(field_label("Quiet Output Warning / Alert (seconds)"));
+                                ui.horizontal(|ui| {
+                                    let width = (MODAL_FORM_WIDTH - 8.0) / 2.0;
+                                    modal_text_edit(
+                                        ui,
+                                        &mut self.rest_settings_form.silent_output_warning_secs,
+                                        "300",
+                                        width,
+                                    );
+                                    modal_text_edit(
+                                        ui,
+                                        &mut self.rest_settings_form.silent_output_alert_secs,
+                                        "900",
+                                        width,
+                                    );
+                                });
+                                ui.add_space(6.0);
+                                ui.label(
+                                    RichText::new(
+                                        "The \"last output\" age in a running process's header turns amber, then red, after this long without output. 0 turns a tint off.",
+                                    )
+                                    .color(palette().text_muted)
+                                    .size(11.5),
+                                );
+                                ui.add_space(14.0);
+                                modal_checkbox_row(
+                                    ui,
+                                    &mut self.rest_settings_form.show_timestamps,
+                                    "Timestamp log lines",
+                                    Some("Prefix captured output with its local arrival time, like [14:03:22.145]."),
+                                );
+                                ui.add_space(14.0);
+                                modal_checkbox_row(
+                                    ui,
+                                    &mut self.rest_settings_form.stop_docker_on_exit,
+                                    "Stop Docker containers on exit",
+                                    Some("Run docker stop for Docker and Compose entries when the window closes, waiting up to 10 seconds."),
+                                );
+                                ui.add_space(14.0);
+                                modal_checkbox_row(
+                                    ui,
+                                    &mut self.rest_settings_form.confirm_bulk_actions,
+                                    "Confirm Start / Stop / Restart All",
+                                    Some("Ask before a header button or shortcut acts on the whole stack."),
+                                );
+                                ui.add_space(14.0);
+                                modal_checkbox_row(
+                                    ui,
+                                    &mut self.rest_settings_form.confirm_exit_when_running,
+                                    "Confirm exit while processes run",
+                                    Some("Ask before closing the window would stop running processes, listing them first."),
+                                );
+                                ui.add_space(8.0);
+                                modal_checkbox_row(
+                                    ui,
+                                    &mut self.rest_settings_form.sidebar_log_preview,
+                                    "Show latest log line in the sidebar",
+                                    Some("Adds a muted one-line preview of each process's newest output under its name."),
+                                );
+                                ui.add_space(8.0);
+                                modal_checkbox_row(
+                                    ui,
+                                    &mut self.rest_settings_form.strip_always_on_top,
+                                    "Keep the status strip on top",
+                                    Some("The compact strip of status dots stays above other windows while it is open."),
+                                );
+                            } else if self.global_settings_tab == 1 {
+                                modal_checkbox_row(
+                                    ui,
+                                    &mut self.rest_settings_form.enabled,
+                                    "Enable localhost REST control",
+                                    Some("Expose a loopback-only API for local tooling."),
+                                );
+                                ui.add_space(14.0);
+                                ui.label(field_label("Host"));
+                                modal_disabled_text_edit(ui, &mut host_text, MODAL_FORM_WIDTH);
+                                ui.add_space(14.0);
+                                ui.label(field_label("Port"));
+                                modal_text_edit(
+                                    ui,
+                                    &mut self.rest_settings_form.port,
+                                    "3000",
+                                    MODAL_FORM_WIDTH,
+                                );
+                                ui.add_space(6.0);
+                                ui.label(
+                                    RichText::new("The API binds only to 127.0.0.1.")
+                                        .color(palette().text_muted)
+                                        .size(11.5),
+                                );
+                            } else if self.global_settings_tab == 2 {
+                                modal_checkbox_row(
+                                    ui,
+                                    &mut self.rest_settings_form.builtin_highlight_keywords,
+                                    "Built-in keywords",
+                                    Some("Flag error, critical, fatal, panic, traceback, and exception lines as errors and lines containing warn as warnings."),
+                                );
+                                ui.add_space(14.0);
+                                ui.label(field_label("Error Patterns"));
+                                modal_multiline_edit(
+                                    ui,
+                                    &mut self.rest_settings_form.error_patterns,
+                                    "level=(error|fatal)",
+                                    MODAL_FORM_WIDTH,
+                                );
+                                ui.add_space(14.0);
+                                ui.label(field_label("Warning Patterns"));
+                                modal_multiline_edit(
+                                    ui,
+                                    &mut self.rest_settings_form.warning_patterns,
+                                    "(?i)^warning\\b",
+                                    MODAL_FORM_WIDTH,
+                                );
+                                ui.add_space(6.0);
+                                ui.label(
+                                    RichText::new(
+                                        "One regular expression per line, matched anywhere in the line. Add (?i) to ignore case. Error lines also drive the error flash and the Errors filter.",
+                                    )
+                                    .color(palette().text_muted)
+                                    .size(11.5),
+                                );
+                                ui.add_space(14.0);
+                                ui.label(field_label("Line Colors"));
+                                modal_multiline_edit(
+                                    ui,
+                                    &mut self.rest_settings_form.color_rules,
+                                    "#22c55e GET /api",
+                                    MODAL_FORM_WIDTH,
+                                );
+                                ui.add_space(6.0);
+                                ui.label(
+                                    RichText::new(
+                                        "One rule per line: a #RRGGBB color, a space, then a regular expression. The first matching rule colors the line; unmatched lines keep the error, warning, and system colors.",
+                                    )
+                                    .color(palette().text_muted)
+                                    .size(11.5),
+                                );
+                            } else if self.global_settings_tab == 3 {
+                                ui.label(
+                                    RichText::new("Project Information")
+                                        .color(palette().text_main)
+                                        .size(14.0)
+                                        .strong(),
+                                );
+                                ui.add_space(14.0);
+
+                                for field in about_fields() {
+                                    draw_about_field(ui, &field);
+                                    ui.add_space(10.0);
+                                }
+
+                            }
+
+                            if let Some(error) = &self.rest_settings_error {
+                                ui.add_space(12.0);
+                                ui.label(RichText::new(error).color(palette().danger).size(12.0));
+                            }
+                        });
+                    },
+                );
+
+                modal_footer(ui, |ui| {
+                    if self.global_settings_tab != 3
+                        && subtle_action_button(ui, "Save", Some(palette().accent_soft)).clicked()
+                    {
+                        save = true;
+                    }
+                    let close_label = if self.global_settings_tab == 3 {
+                        "Close"
+                    } else {
                         "Cancel"
                     };
                     if shell_button(ui, close_label).clicked() {
@@ -2730,8 +5500,8 @@ impl ProcessManagerApp {
             .resizable(false)
             .frame(
                 egui::Frame::window(&ctx.style())
-                    .fill(PANEL_BG)
-                    .stroke(Stroke::new(1.0, BORDER)),
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
             )
             .open(&mut open)
             .show(ctx, |ui| {
@@ -2739,19 +5509,19 @@ impl ProcessManagerApp {
                 ui.set_min_height(180.0);
                 ui.label(
                     RichText::new("Delete Process")
-                        .color(TEXT_MAIN)
+                        .color(palette().text_main)
                         .size(16.0)
                         .strong(),
                 );
                 ui.add_space(8.0);
                 ui.label(
                     RichText::new(format!("Delete {}? This cannot be undone.", process_name))
-                        .color(TEXT_SOFT)
+                        .color(palette().text_soft)
                         .size(13.0),
                 );
 
                 modal_footer(ui, |ui| {
-                    if subtle_action_button(ui, "Delete", Some(DANGER)).clicked() {
+                    if subtle_action_button(ui, "Delete", Some(palette().danger)).clicked() {
                         confirm = true;
                     }
                     if shell_button(ui, "Cancel").clicked() {
@@ -2770,6 +5540,399 @@ impl ProcessManagerApp {
         }
     }
 
+    fn draw_bulk_action_dialog(&mut self, ctx: &Context) {
+        let Some(action) = self.pending_bulk_action else {
+            return;
+        };
+
+        let mut open = true;
+        let mut confirm = false;
+
+        Window::new(action.title())
+            .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
+            .collapsible(false)
+            .resizable(false)
+            .frame(
+                egui::Frame::window(&ctx.style())
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
+            )
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.set_width(360.0);
+                ui.set_min_height(180.0);
+                ui.label(
+                    RichText::new(action.title())
+                        .color(palette().text_main)
+                        .size(16.0)
+                        .strong(),
+                );
+                ui.add_space(8.0);
+                ui.label(
+                    RichText::new(action.description())
+                        .color(palette().text_soft)
+                        .size(13.0),
+                );
+
+                modal_footer(ui, |ui| {
+                    let accent = match action {
+                        BulkAction::Start => palette().toolbar_green,
+                        BulkAction::Stop | BulkAction::Restart => palette().danger,
+                    };
+                    if subtle_action_button(ui, action.title(), Some(accent)).clicked() {
+                        confirm = true;
+                    }
+                    if shell_button(ui, "Cancel").clicked() {
+                        self.pending_bulk_action = None;
+                    }
+                });
+            });
+
+        if !open {
+            self.pending_bulk_action = None;
+        }
+
+        if confirm {
+            self.run_bulk_action(action);
+            self.pending_bulk_action = None;
+        }
+    }
+
+    fn draw_exit_confirmation_dialog(&mut self, ctx: &Context) {
+        let Some(confirmation) = self.exit_confirmation.as_ref() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut cancel = false;
+        let mut confirm = false;
+
+        Window::new("Quit Process Manager")
+            .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
+            .collapsible(false)
+            .resizable(false)
+            .frame(
+                egui::Frame::window(&ctx.style())
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
+            )
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.set_width(380.0);
+                ui.set_min_height(180.0);
+                ui.label(
+                    RichText::new("Quit Process Manager?")
+                        .color(palette().text_main)
+                        .size(16.0)
+                        .strong(),
+                );
+                ui.add_space(8.0);
+                ui.label(
+                    RichText::new("Closing the window will stop:")
+                        .color(palette().text_soft)
+                        .size(13.0),
+                );
+                for name in &confirmation.stopping {
+                    ui.label(
+                        RichText::new(format!("• {}", name))
+                            .color(palette().text_main)
+                            .size(13.0),
+                    );
+                }
+                if !confirmation.left_running.is_empty() {
+                    ui.add_space(8.0);
+                    ui.label(
+                        RichText::new("These containers are left running:")
+                            .color(palette().text_soft)
+                            .size(13.0),
+                    );
+                    for name in &confirmation.left_running {
+                        ui.label(
+                            RichText::new(format!("• {}", name))
+                                .color(palette().text_muted)
+                                .size(13.0),
+                        );
+                    }
+                }
+
+                modal_footer(ui, |ui| {
+                    if subtle_action_button(ui, "Quit", Some(palette().danger)).clicked() {
+                        confirm = true;
+                    }
+                    if shell_button(ui, "Cancel").clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+
+        if confirm {
+            self.exit_confirmation = None;
+            self.exit_confirmed = true;
+            ctx.send_viewport_cmd(ViewportCommand::Close);
+        } else if cancel || !open {
+            self.exit_confirmation = None;
+        }
+    }
+
+    fn draw_global_log_search_window(&mut self, ctx: &Context) {
+        if !self.global_log_search.open {
+            return;
+        }
+
+        let mut open = true;
+        let mut jump_to: Option<String> = None;
+
+        Window::new("Search All Logs")
+            .default_size([640.0, 460.0])
+            .collapsible(false)
+            .resizable(true)
+            .frame(
+                egui::Frame::window(&ctx.style())
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
+            )
+            .open(&mut open)
+            .show(ctx, |ui| {
+                let search = &mut self.global_log_search;
+                let mut run_search = false;
+                ui.horizontal(|ui| {
+                    let response = inline_text_edit(
+                        ui,
+                        &mut search.query,
+                        "Search every process's logs",
+                        (ui.available_width() - 90.0).max(120.0),
+                    );
+                    if search.focus_pending {
+                        response.request_focus();
+                        search.focus_pending = false;
+                    }
+                    run_search = response.changed();
+                    if shell_button(ui, "Refresh")
+                        .on_hover_text("Search again, including lines logged since")
+                        .clicked()
+                    {
+                        run_search = true;
+                    }
+                });
+                if run_search {
+                    search.results = self.manager.search_all_logs(&search.query);
+                }
+
+                let total: usize = search.results.iter().map(|(_, lines)| lines.len()).sum();
+                let summary = if search.query.trim().is_empty() {
+                    "Case-insensitive; click a result to open that process.".to_string()
+                } else if total >= LOG_SEARCH_RESULT_LIMIT {
+                    format!(
+                        "Showing the newest {} matches. Refine the search to see the rest.",
+                        LOG_SEARCH_RESULT_LIMIT
+                    )
+                } else {
+                    format!(
+                        "{} matching lines in {} processes",
+                        total,
+                        search.results.len()
+                    )
+                };
+                ui.add_space(4.0);
+                ui.label(
+                    RichText::new(summary)
+                        .color(palette().text_muted)
+                        .size(12.0),
+                );
+                ui.add_space(6.0);
+
+                ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for (process_id, lines) in &search.results {
+                            let name = self
+                                .config
+                                .get_process(process_id)
+                                .map(|process| process.name.clone())
+                                .unwrap_or_else(|| process_id.clone());
+                            let header = ui.add(
+                                egui::Label::new(
+                                    RichText::new(format!("{} ({})", name, lines.len()))
+                                        .color(palette().text_main)
+                                        .strong(),
+                                )
+                                .sense(egui::Sense::click()),
+                            );
+                            if header.clicked() {
+                                jump_to = Some(process_id.clone());
+                            }
+                            for line in lines {
+                                let row = ui.add(
+                                    egui::Label::new(
+                                        RichText::new(line).color(palette().text_soft).monospace(),
+                                    )
+                                    .truncate()
+                                    .sense(egui::Sense::click()),
+                                );
+                                if row.clicked() {
+                                    jump_to = Some(process_id.clone());
+                                }
+                            }
+                            ui.add_space(8.0);
+                        }
+                    });
+            });
+
+        if !open {
+            self.global_log_search.open = false;
+        }
+        if let Some(process_id) = jump_to {
+            // Hand the query to the find bar so the matches are highlighted in place.
+            self.selected_process = Some(process_id);
+            self.log_filter.clear();
+            self.log_search.open = true;
+            self.log_search.regex = false;
+            self.log_search.query = self.global_log_search.query.clone();
+            self.log_search.current = None;
+            self.log_search.query_pending = true;
+        }
+    }
+
+    /// Stack of recent failures in the bottom-right corner. Clicking a toast opens that
+    /// entry; each one dismisses itself after `ERROR_TOAST_DURATION`.
+    fn draw_error_toasts(&mut self, ctx: &Context) {
+        let now = Instant::now();
+        self.error_toasts.retain(|toast| now < toast.until);
+        if self.error_toasts.is_empty() {
+            return;
+        }
+
+        let mut dismissed: Option<usize> = None;
+        let mut jump_to: Option<String> = None;
+        egui::Area::new(egui::Id::new("error_toasts"))
+            .order(egui::Order::Foreground)
+            .anchor(Align2::RIGHT_BOTTOM, Vec2::new(-16.0, -16.0))
+            .show(ctx, |ui| {
+                for (index, toast) in self.error_toasts.iter().enumerate() {
+                    egui::Frame::window(&ctx.style())
+                        .fill(palette().panel_bg)
+                        .stroke(Stroke::new(1.0, palette().danger))
+                        .show(ui, |ui| {
+                            ui.set_width(300.0);
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    RichText::new(format!("{} failed", toast.name))
+                                        .color(palette().danger)
+                                        .strong(),
+                                );
+                                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                    if ui.small_button("✕").on_hover_text("Dismiss").clicked() {
+                                        dismissed = Some(index);
+                                    }
+                                });
+                            });
+                            let body = ui.add(
+                                egui::Label::new(
+                                    RichText::new(&toast.message)
+                                        .color(palette().text_soft)
+                                        .size(12.0),
+                                )
+                                .wrap()
+                                .sense(egui::Sense::click()),
+                            );
+                            if body.on_hover_text("Open this process").clicked() {
+                                jump_to = Some(toast.process_id.clone());
+                                dismissed = Some(index);
+                            }
+                        });
+                    ui.add_space(6.0);
+                }
+            });
+
+        if let Some(index) = dismissed {
+            self.error_toasts.remove(index);
+        }
+        if let Some(process_id) = jump_to {
+            self.selected_process = Some(process_id);
+        }
+    }
+
+    fn draw_lifecycle_events_window(&mut self, ctx: &Context) {
+        if !self.lifecycle_events_open {
+            return;
+        }
+
+        let events = self.manager.lifecycle_events();
+        let mut open = true;
+        let mut clear = false;
+
+        Window::new("Lifecycle Events")
+            .default_size([560.0, 420.0])
+            .collapsible(false)
+            .resizable(true)
+            .frame(
+                egui::Frame::window(&ctx.style())
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
+            )
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(format!("{} events", events.len()))
+                            .color(palette().text_muted)
+                            .size(12.0),
+                    );
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        if shell_button(ui, "Clear").clicked() {
+                            clear = true;
+                        }
+                    });
+                });
+                ui.add_space(4.0);
+
+                if events.is_empty() {
+                    ui.label(
+                        RichText::new("Nothing has happened yet.")
+                            .color(palette().text_soft)
+                            .size(13.0),
+                    );
+                    return;
+                }
+
+                ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        for event in &events {
+                            let color = match event.kind {
+                                LifecycleEventKind::Started => palette().running,
+                                LifecycleEventKind::Failed(_)
+                                | LifecycleEventKind::Exited(Some(1..)) => palette().danger,
+                                LifecycleEventKind::RestartScheduled { .. } => palette().warning,
+                                _ => palette().text_soft,
+                            };
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    RichText::new(event.at.format("%H:%M:%S%.3f").to_string())
+                                        .color(palette().text_muted)
+                                        .monospace(),
+                                );
+                                ui.label(
+                                    RichText::new(&event.process_name)
+                                        .color(palette().text_main)
+                                        .strong(),
+                                )
+                                .on_hover_text(&event.process_id);
+                                ui.label(RichText::new(event.kind.to_string()).color(color));
+                            });
+                        }
+                    });
+            });
+
+        if !open {
+            self.lifecycle_events_open = false;
+        }
+        if clear {
+            self.manager.clear_lifecycle_events();
+        }
+    }
+
     fn draw_reload_dialog(&mut self, ctx: &Context) {
         if !self.reload_processes_confirm_open {
             return;
@@ -2784,8 +5947,8 @@ impl ProcessManagerApp {
             .resizable(false)
             .frame(
                 egui::Frame::window(&ctx.style())
-                    .fill(PANEL_BG)
-                    .stroke(Stroke::new(1.0, BORDER)),
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
             )
             .open(&mut open)
             .show(ctx, |ui| {
@@ -2793,7 +5956,7 @@ impl ProcessManagerApp {
                 ui.set_min_height(220.0);
                 ui.label(
                     RichText::new("Reload processes.json")
-                        .color(TEXT_MAIN)
+                        .color(palette().text_main)
                         .size(16.0)
                         .strong(),
                 );
@@ -2803,36 +5966,174 @@ impl ProcessManagerApp {
                         "This will stop all managed processes first, including those not opted into Stop All."
                             .to_string(),
                     )
-                    .color(TEXT_SOFT)
+                    .color(palette().text_soft)
                     .size(13.0),
                 );
                 ui.add_space(6.0);
                 ui.label(
                     RichText::new(
-                        "After that, the local processes.json file will be reloaded and the process list will be rebuilt."
-                            .to_string(),
+                        "After that, the local processes.json file will be reloaded and the process list will be rebuilt."
+                            .to_string(),
+                    )
+                    .color(palette().text_soft)
+                    .size(13.0),
+                );
+
+                modal_footer(ui, |ui| {
+                    if subtle_action_button(ui, "Reload", Some(palette().toolbar_yellow)).clicked() {
+                        confirm = true;
+                    }
+                    if shell_button(ui, "Cancel").clicked() {
+                        self.cancel_reload_processes_confirmation();
+                    }
+                });
+            });
+
+        if !open {
+            self.cancel_reload_processes_confirmation();
+        }
+
+        if confirm {
+            self.reload_processes_from_disk();
+            self.reload_processes_confirm_open = false;
+        }
+    }
+
+    fn draw_import_dialog(&mut self, ctx: &Context) {
+        let Some(imported) = self.pending_import.as_ref() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut cancel = false;
+        let mut choice = None;
+        let summary = format!(
+            "'{}' contains {} processes.",
+            imported.stack_name,
+            imported.processes.len()
+        );
+
+        Window::new("Import stack")
+            .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
+            .collapsible(false)
+            .resizable(false)
+            .frame(
+                egui::Frame::window(&ctx.style())
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
+            )
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.set_width(420.0);
+                ui.set_min_height(220.0);
+                ui.label(
+                    RichText::new("Import stack")
+                        .color(palette().text_main)
+                        .size(16.0)
+                        .strong(),
+                );
+                ui.add_space(8.0);
+                ui.label(RichText::new(summary).color(palette().text_soft).size(13.0));
+                ui.add_space(6.0);
+                ui.label(
+                    RichText::new(
+                        "Merge adds them next to your current processes. Replace stops every managed process first and swaps in the imported stack name and process list.",
+                    )
+                    .color(palette().text_soft)
+                    .size(13.0),
+                );
+
+                modal_footer(ui, |ui| {
+                    if subtle_action_button(ui, "Replace", Some(palette().toolbar_yellow)).clicked() {
+                        choice = Some(true);
+                    }
+                    if subtle_action_button(ui, "Merge", Some(palette().accent_soft)).clicked() {
+                        choice = Some(false);
+                    }
+                    if shell_button(ui, "Cancel").clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+
+        if let Some(replace) = choice {
+            if let Some(imported) = self.pending_import.take() {
+                self.apply_import(imported, replace);
+            }
+        } else if cancel || !open {
+            self.pending_import = None;
+        }
+    }
+
+    fn draw_raw_config_editor(&mut self, ctx: &Context) {
+        let Some(editor) = self.raw_config_editor.as_mut() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut cancel = false;
+        let mut save = false;
+
+        Window::new("Edit Raw Config")
+            .default_size([640.0, 560.0])
+            .collapsible(false)
+            .resizable(true)
+            .frame(
+                egui::Frame::window(&ctx.style())
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
+            )
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(
+                        "Saving validates the JSON, writes processes.json, stops and removes deleted entries, and adds new ones stopped. Entries without an \"id\" get one. Edits to running entries apply on their next start.",
                     )
-                    .color(TEXT_SOFT)
-                    .size(13.0),
+                    .color(palette().text_muted)
+                    .size(11.5),
                 );
+                ui.add_space(8.0);
+                ScrollArea::vertical()
+                    .max_height((ui.available_height() - 90.0).max(160.0))
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        modal_multiline_edit(ui, &mut editor.text, "{}", ui.available_width());
+                    });
+                if let Some(error) = &editor.error {
+                    ui.add_space(6.0);
+                    ui.label(
+                        RichText::new(error)
+                            .color(palette().danger)
+                            .size(12.0)
+                            .monospace(),
+                    );
+                }
 
                 modal_footer(ui, |ui| {
-                    if subtle_action_button(ui, "Reload", Some(TOOLBAR_YELLOW)).clicked() {
-                        confirm = true;
+                    if subtle_action_button(ui, "Save", Some(palette().accent_soft)).clicked() {
+                        save = true;
                     }
                     if shell_button(ui, "Cancel").clicked() {
-                        self.cancel_reload_processes_confirmation();
+                        cancel = true;
                     }
                 });
             });
 
-        if !open {
-            self.cancel_reload_processes_confirmation();
-        }
-
-        if confirm {
-            self.reload_processes_from_disk();
-            self.reload_processes_confirm_open = false;
+        if save {
+            let text = editor.text.clone();
+            match self.save_raw_config(&text) {
+                Ok(()) => {
+                    self.raw_config_editor = None;
+                    self.set_banner("Saved processes.json and synced the process list.");
+                }
+                Err(err) => {
+                    if let Some(editor) = self.raw_config_editor.as_mut() {
+                        editor.error = Some(err);
+                    }
+                }
+            }
+        } else if cancel || !open {
+            self.raw_config_editor = None;
         }
     }
 
@@ -2850,6 +6151,153 @@ impl ProcessManagerApp {
         ));
     }
 
+    fn enter_status_strip(&mut self, ctx: &Context) {
+        if self.status_strip.is_some() {
+            return;
+        }
+        let restore_size = self
+            .last_viewport_size
+            .filter(|size| size.x >= WINDOW_MIN_SIZE.x && size.y >= WINDOW_MIN_SIZE.y)
+            .unwrap_or(WINDOW_DEFAULT_SIZE);
+        let width = 64.0 + self.config.processes.len() as f32 * (STRIP_DOT_SIZE + 4.0);
+        let on_top = self.config.strip_always_on_top;
+        ctx.send_viewport_cmd(ViewportCommand::Maximized(false));
+        ctx.send_viewport_cmd(ViewportCommand::MinInnerSize(STRIP_MIN_SIZE));
+        ctx.send_viewport_cmd(ViewportCommand::InnerSize(Vec2::new(
+            width.max(STRIP_MIN_SIZE.x),
+            STRIP_HEIGHT,
+        )));
+        if on_top {
+            ctx.send_viewport_cmd(ViewportCommand::WindowLevel(egui::WindowLevel::AlwaysOnTop));
+        }
+        self.status_strip = Some(StatusStrip {
+            restore_size,
+            on_top,
+        });
+    }
+
+    fn leave_status_strip(&mut self, ctx: &Context) {
+        let Some(strip) = self.status_strip.take() else {
+            return;
+        };
+        if strip.on_top {
+            ctx.send_viewport_cmd(ViewportCommand::WindowLevel(egui::WindowLevel::Normal));
+        }
+        ctx.send_viewport_cmd(ViewportCommand::MinInnerSize(WINDOW_MIN_SIZE));
+        ctx.send_viewport_cmd(ViewportCommand::InnerSize(strip.restore_size));
+    }
+
+    /// The whole window while the strip is open: a restore button and one status dot per
+    /// entry in sidebar order. Clicking a dot restores the window with that entry selected.
+    fn draw_status_strip(&mut self, ctx: &Context) {
+        if let Some(strip) = self.status_strip.as_mut() {
+            if strip.on_top != self.config.strip_always_on_top {
+                strip.on_top = self.config.strip_always_on_top;
+                ctx.send_viewport_cmd(ViewportCommand::WindowLevel(if strip.on_top {
+                    egui::WindowLevel::AlwaysOnTop
+                } else {
+                    egui::WindowLevel::Normal
+                }));
+            }
+        }
+
+        let mut restore = false;
+        let mut focus: Option<String> = None;
+        CentralPanel::default()
+            .frame(
+                egui::Frame::default()
+                    .fill(self.shell_bg)
+                    .inner_margin(egui::Margin::symmetric(10, 6)),
+            )
+            .show(ctx, |ui| {
+                ui.horizontal_centered(|ui| {
+                    ui.spacing_mut().item_spacing.x = 4.0;
+                    if chrome_text_button(
+                        ui,
+                        "⤢",
+                        palette().toolbar_text,
+                        Vec2::new(28.0, 24.0),
+                        12.0,
+                        false,
+                    )
+                    .on_hover_text("Restore the full window")
+                    .clicked()
+                    {
+                        restore = true;
+                    }
+                    ui.add_space(4.0);
+                    for process in &self.config.processes {
+                        let status = self
+                            .runtime_snapshot
+                            .statuses
+                            .get(&process.id)
+                            .cloned()
+                            .unwrap_or(ProcessStatus::Stopped);
+                        let health = self
+                            .runtime_snapshot
+                            .health
+                            .get(&process.id)
+                            .copied()
+                            .filter(|_| status == ProcessStatus::Running);
+                        if draw_strip_dot(ui, process, &status, health).clicked() {
+                            focus = Some(process.id.clone());
+                        }
+                    }
+                });
+            });
+
+        if let Some(process_id) = focus {
+            self.leave_status_strip(ctx);
+            self.show_combined_logs = false;
+            self.selected_process = Some(process_id);
+            self.refresh_runtime_snapshot(true);
+        } else if restore {
+            self.leave_status_strip(ctx);
+        }
+    }
+
+    /// Remember the restored geometry, and shrink a restored window that no longer fits its monitor.
+    fn track_window_state(
+        &mut self,
+        ctx: &Context,
+        position: Option<egui::Pos2>,
+        size: Vec2,
+        maximized: bool,
+        monitor_size: Option<Vec2>,
+    ) {
+        if !self.window_size_clamped {
+            if let Some(monitor_size) = monitor_size {
+                self.window_size_clamped = true;
+                let clamped = size.min(monitor_size).max(WINDOW_MIN_SIZE);
+                if !maximized && clamped != size {
+                    ctx.send_viewport_cmd(ViewportCommand::InnerSize(clamped));
+                }
+            }
+        }
+
+        // The strip is smaller than a normal window may be; keep the geometry it will restore.
+        if self.status_strip.is_some() || size.x < WINDOW_MIN_SIZE.x || size.y < WINDOW_MIN_SIZE.y {
+            return;
+        }
+
+        if maximized {
+            if let Some(state) = self.window_state.as_mut() {
+                state.maximized = true;
+            }
+            return;
+        }
+
+        if let Some(position) = position.filter(|_| size.x > 0.0 && size.y > 0.0) {
+            self.window_state = Some(WindowState {
+                x: position.x,
+                y: position.y,
+                width: size.x,
+                height: size.y,
+                maximized: false,
+            });
+        }
+    }
+
     #[cfg(windows)]
     fn ensure_windows_native_caption(&mut self) {
         if self.native_caption_applied {
@@ -2928,20 +6376,32 @@ impl ProcessManagerApp {
 impl eframe::App for ProcessManagerApp {
     fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
         let update_started = Instant::now();
+        if ctx.input(|input| input.viewport().close_requested()) {
+            self.intercept_close_request(ctx);
+        }
         self.ensure_valid_selection();
         self.update_title(ctx);
         self.ensure_windows_native_caption();
         self.ensure_windows_taskbar_icon();
         let focused = ctx.input(|input| input.viewport().focused).unwrap_or(true);
-        let (viewport_pos, viewport_size) = ctx.input(|input| {
+        let minimized = ctx.input(|input| input.viewport().minimized.unwrap_or(false));
+        self.manager
+            .set_docker_polling_paused(minimized && !focused);
+        let (viewport_pos, viewport_size, maximized, monitor_size) = ctx.input(|input| {
             let viewport = input.viewport();
             let viewport_pos = viewport.outer_rect.map(|rect| rect.min);
             let viewport_size = viewport
                 .inner_rect
                 .map(|rect| rect.size())
                 .unwrap_or_default();
-            (viewport_pos, viewport_size)
+            (
+                viewport_pos,
+                viewport_size,
+                viewport.maximized.unwrap_or(false),
+                viewport.monitor_size,
+            )
         });
+        self.track_window_state(ctx, viewport_pos, viewport_size, maximized, monitor_size);
         let viewport_pos_changed = self
             .diagnostics
             .last_viewport_pos
@@ -2961,13 +6421,14 @@ impl eframe::App for ProcessManagerApp {
         self.handle_shortcuts(ctx);
         self.maybe_request_attention(ctx);
         self.refresh_runtime_snapshot(false);
+        self.flush_scheduled_config_save(false);
 
         // Keep global panel_fill in sync with the live shell_bg from caption probe
         ctx.style_mut(|style| {
             style.visuals.panel_fill = self.shell_bg;
-            style.visuals.window_fill = PANEL_BG;
-            style.visuals.faint_bg_color = PANEL_BG;
-            style.visuals.extreme_bg_color = BODY_BG;
+            style.visuals.window_fill = palette().panel_bg;
+            style.visuals.faint_bg_color = palette().panel_bg;
+            style.visuals.extreme_bg_color = palette().body_bg;
         });
 
         if caption_changed || viewport_pos_changed || viewport_size_changed {
@@ -2977,13 +6438,30 @@ impl eframe::App for ProcessManagerApp {
             ctx.request_repaint_after(delay);
         }
 
+        if self.status_strip.is_some() {
+            // The exit confirmation needs the full window.
+            if self.exit_confirmation.is_none() {
+                self.draw_status_strip(ctx);
+                self.record_update_timing(update_started.elapsed());
+                return;
+            }
+            self.leave_status_strip(ctx);
+        }
+
         self.draw_sidebar(ctx);
         self.draw_header(ctx);
         self.draw_content(ctx);
         self.draw_process_dialog(ctx);
         self.draw_rest_settings_dialog(ctx);
         self.draw_delete_dialog(ctx);
+        self.draw_bulk_action_dialog(ctx);
+        self.draw_exit_confirmation_dialog(ctx);
+        self.draw_lifecycle_events_window(ctx);
+        self.draw_global_log_search_window(ctx);
+        self.draw_error_toasts(ctx);
         self.draw_reload_dialog(ctx);
+        self.draw_import_dialog(ctx);
+        self.draw_raw_config_editor(ctx);
         self.draw_diagnostics_overlay(ctx);
         self.record_update_timing(update_started.elapsed());
     }
@@ -3005,32 +6483,49 @@ impl Drop for ProcessManagerApp {
             }
         }
 
+        self.flush_scheduled_config_save(true);
+        if let Some(window_state) = self.window_state {
+            let _ = window_state.save();
+        }
         self.rest_controller.shutdown();
+        if self.config.stop_docker_on_exit {
+            let pending = self.manager.stop_docker_entries(DOCKER_EXIT_STOP_TIMEOUT);
+            if !pending.is_empty() {
+                eprintln!(
+                    "[WARN] Containers still stopping at exit: {}",
+                    pending.join(", ")
+                );
+            }
+        }
         self.manager.stop_non_docker();
     }
 }
 
 fn configure_visuals(ctx: &Context) {
-    let mut visuals = egui::Visuals::dark();
+    let mut visuals = if palette().is_light {
+        egui::Visuals::light()
+    } else {
+        egui::Visuals::dark()
+    };
     // Do NOT set override_text_color — it prevents selected text from being visible
-    visuals.panel_fill = SHELL_BG;
-    visuals.window_fill = PANEL_BG;
-    visuals.extreme_bg_color = BODY_BG;
-    visuals.faint_bg_color = PANEL_BG;
-    visuals.widgets.noninteractive.bg_fill = SHELL_BG;
+    visuals.panel_fill = palette().shell_bg;
+    visuals.window_fill = palette().panel_bg;
+    visuals.extreme_bg_color = palette().body_bg;
+    visuals.faint_bg_color = palette().panel_bg;
+    visuals.widgets.noninteractive.bg_fill = palette().shell_bg;
     visuals.widgets.noninteractive.bg_stroke = Stroke::NONE;
-    visuals.widgets.noninteractive.fg_stroke = Stroke::new(1.0, TEXT_SOFT);
-    visuals.widgets.inactive.bg_fill = SHELL_BG;
+    visuals.widgets.noninteractive.fg_stroke = Stroke::new(1.0, palette().text_soft);
+    visuals.widgets.inactive.bg_fill = palette().shell_bg;
     visuals.widgets.inactive.bg_stroke = Stroke::NONE;
-    visuals.widgets.inactive.fg_stroke = Stroke::new(1.0, TEXT_MAIN);
-    visuals.widgets.hovered.bg_fill = SHELL_HOVER_BG;
+    visuals.widgets.inactive.fg_stroke = Stroke::new(1.0, palette().text_main);
+    visuals.widgets.hovered.bg_fill = palette().shell_hover_bg;
     visuals.widgets.hovered.bg_stroke = Stroke::NONE;
-    visuals.widgets.hovered.fg_stroke = Stroke::new(1.0, TEXT_MAIN);
-    visuals.widgets.active.bg_fill = SHELL_ACTIVE_BG;
+    visuals.widgets.hovered.fg_stroke = Stroke::new(1.0, palette().text_main);
+    visuals.widgets.active.bg_fill = palette().shell_active_bg;
     visuals.widgets.active.bg_stroke = Stroke::NONE;
-    visuals.widgets.active.fg_stroke = Stroke::new(1.0, TEXT_MAIN);
-    visuals.selection.bg_fill = Color32::from_rgb(56, 98, 158);
-    visuals.selection.stroke = Stroke::new(1.0, Color32::from_rgb(86, 136, 198));
+    visuals.widgets.active.fg_stroke = Stroke::new(1.0, palette().text_main);
+    visuals.selection.bg_fill = palette().selection_bg;
+    visuals.selection.stroke = Stroke::new(1.0, palette().selection_stroke);
     visuals.window_shadow.color = Color32::TRANSPARENT;
     ctx.set_visuals(visuals);
 
@@ -3056,7 +6551,7 @@ fn apply_windows_caption_theme(hwnd: windows_sys::Win32::Foundation::HWND, color
 
     let dark_mode_enabled: i32 = (!is_light_color(color)) as i32;
     let corner_preference = DWMWCP_ROUND;
-    let border_color = color_to_colorref(BORDER);
+    let border_color = color_to_colorref(palette().border);
     let caption_color = color_to_colorref(color);
     let text_color = color_to_colorref(if is_light_color(color) {
         Color32::from_rgb(24, 24, 24)
@@ -3215,7 +6710,7 @@ fn about_fields() -> Vec<AboutField> {
 fn draw_about_field(ui: &mut Ui, field: &AboutField) {
     egui::Frame::default()
         .fill(Color32::TRANSPARENT)
-        .stroke(Stroke::new(1.0, FIELD_BORDER))
+        .stroke(Stroke::new(1.0, palette().field_border))
         .corner_radius(8.0)
         .inner_margin(egui::Margin::symmetric(12, 10))
         .show(ui, |ui| {
@@ -3225,11 +6720,17 @@ fn draw_about_field(ui: &mut Ui, field: &AboutField) {
 
             if let Some(link) = field.link {
                 ui.hyperlink_to(
-                    RichText::new(&field.value).color(TEXT_MAIN).size(13.0),
+                    RichText::new(&field.value)
+                        .color(palette().text_main)
+                        .size(13.0),
                     link,
                 );
             } else {
-                ui.label(RichText::new(&field.value).color(TEXT_MAIN).size(13.0));
+                ui.label(
+                    RichText::new(&field.value)
+                        .color(palette().text_main)
+                        .size(13.0),
+                );
             }
         });
 }
@@ -3258,7 +6759,7 @@ fn toolbar_button(
     chrome_text_button(
         ui,
         label,
-        accent.unwrap_or(TEXT_MAIN),
+        accent.unwrap_or(palette().text_main),
         min_size,
         12.0,
         false,
@@ -3271,7 +6772,14 @@ fn chrome_button(
     accent: Option<Color32>,
     min_size: Vec2,
 ) -> egui::Response {
-    chrome_text_button(ui, label, accent.unwrap_or(TEXT_MAIN), min_size, 12.0, true)
+    chrome_text_button(
+        ui,
+        label,
+        accent.unwrap_or(palette().text_main),
+        min_size,
+        12.0,
+        true,
+    )
 }
 
 fn chrome_text_button(
@@ -3284,18 +6792,18 @@ fn chrome_text_button(
 ) -> egui::Response {
     ui.scope(|ui| {
         let visuals = &mut ui.style_mut().visuals;
-        visuals.widgets.inactive.bg_fill = SHELL_BG;
+        visuals.widgets.inactive.bg_fill = palette().shell_bg;
         visuals.widgets.inactive.bg_stroke = if show_idle_stroke {
-            Stroke::new(1.0, SHELL_STROKE)
+            Stroke::new(1.0, palette().shell_stroke)
         } else {
             Stroke::NONE
         };
-        visuals.widgets.hovered.bg_fill = SHELL_HOVER_BG;
-        visuals.widgets.hovered.bg_stroke = Stroke::new(1.0, SHELL_STROKE);
-        visuals.widgets.active.bg_fill = SHELL_ACTIVE_BG;
-        visuals.widgets.active.bg_stroke = Stroke::new(1.0, SHELL_STROKE);
-        visuals.widgets.open.bg_fill = SHELL_ACTIVE_BG;
-        visuals.widgets.open.bg_stroke = Stroke::new(1.0, SHELL_STROKE);
+        visuals.widgets.hovered.bg_fill = palette().shell_hover_bg;
+        visuals.widgets.hovered.bg_stroke = Stroke::new(1.0, palette().shell_stroke);
+        visuals.widgets.active.bg_fill = palette().shell_active_bg;
+        visuals.widgets.active.bg_stroke = Stroke::new(1.0, palette().shell_stroke);
+        visuals.widgets.open.bg_fill = palette().shell_active_bg;
+        visuals.widgets.open.bg_stroke = Stroke::new(1.0, palette().shell_stroke);
 
         ui.add(
             Button::new(RichText::new(label).color(text_color).size(font_size))
@@ -3308,36 +6816,125 @@ fn chrome_text_button(
     .inner
 }
 
+/// One entry in the status strip: its status dot, ringed in red while its health check fails
+/// or else in its color label. The name and status are in the tooltip.
+fn draw_strip_dot(
+    ui: &mut Ui,
+    process: &ProcessConfig,
+    status: &ProcessStatus,
+    health: Option<HealthStatus>,
+) -> egui::Response {
+    let (rect, response) =
+        ui.allocate_exact_size(Vec2::splat(STRIP_DOT_SIZE), egui::Sense::click());
+    if response.hovered() {
+        ui.painter()
+            .rect_filled(rect, 4.0, palette().process_row_hover_bg);
+    }
+    let center = rect.center();
+    ui.painter()
+        .circle_filled(center, 5.0, status_color(status, ui.ctx()));
+    let ring = if health == Some(HealthStatus::Unhealthy) {
+        Some(palette().danger)
+    } else {
+        process_color(process).map(|[r, g, b]| Color32::from_rgb(r, g, b))
+    };
+    if let Some(color) = ring {
+        ui.painter()
+            .circle_stroke(center, 7.5, Stroke::new(1.5, color));
+    }
+    let tooltip = match health {
+        Some(health) => format!("{}\n{} · {}", process.name, status, health_label(health)),
+        None => format!("{}\n{}", process.name, status),
+    };
+    response
+        .on_hover_text(tooltip)
+        .on_hover_cursor(egui::CursorIcon::PointingHand)
+}
+
+/// The sidebar's "All" entry above the process list.
+fn draw_combined_logs_row(ui: &mut Ui, selected: bool) -> egui::Response {
+    let (rect, response) =
+        ui.allocate_exact_size(egui::vec2(ui.available_width(), 34.0), egui::Sense::click());
+    let bg_color = if selected {
+        palette().process_row_selected_bg
+    } else if response.hovered() {
+        palette().process_row_hover_bg
+    } else {
+        Color32::TRANSPARENT
+    };
+    if bg_color != Color32::TRANSPARENT {
+        ui.painter().rect_filled(rect, 4.0, bg_color);
+    }
+    if selected {
+        let accent_rect = egui::Rect::from_min_size(
+            rect.min + egui::vec2(2.0, 8.0),
+            egui::vec2(2.0, rect.height() - 16.0),
+        );
+        ui.painter()
+            .rect_filled(accent_rect, 1.0, palette().text_main);
+    }
+
+    let inner_rect = rect.shrink2(egui::vec2(14.0, 0.0));
+    let text_color = if selected {
+        palette().text_main
+    } else {
+        palette().text_muted
+    };
+    ui.painter().text(
+        egui::pos2(inner_rect.min.x + 10.0, rect.center().y),
+        Align2::CENTER_CENTER,
+        "≡",
+        FontId::proportional(13.5),
+        text_color,
+    );
+    ui.painter().text(
+        egui::pos2(inner_rect.min.x + 24.0, rect.center().y),
+        Align2::LEFT_CENTER,
+        "All",
+        FontId::proportional(13.5),
+        text_color,
+    );
+    response
+}
+
+/// `preview` is the latest log line, shown under the name when the sidebar preview is on.
+#[allow(clippy::too_many_arguments)]
 fn draw_process_row(
     ui: &mut Ui,
     process: &ProcessConfig,
     status: &ProcessStatus,
+    health: Option<HealthStatus>,
     resource_usage: Option<ProcessResourceUsage>,
     selected: bool,
     flash_intensity: f32,
+    preview: Option<&str>,
 ) -> egui::Response {
+    let row_height = if preview.is_some() { 46.0 } else { 34.0 };
     let (rect, response) = ui.allocate_exact_size(
-        egui::vec2(ui.available_width(), 34.0),
+        egui::vec2(ui.available_width(), row_height),
         egui::Sense::click_and_drag(),
     );
+    // The name line keeps its usual position; the preview goes underneath it.
+    let line_y = rect.min.y + 17.0;
 
     let is_hovered = response.hovered();
 
     let base_bg_color = if selected {
-        PROCESS_ROW_SELECTED_BG
+        palette().process_row_selected_bg
     } else if is_hovered {
-        PROCESS_ROW_HOVER_BG
+        palette().process_row_hover_bg
     } else {
         Color32::TRANSPARENT
     };
-    let bg_color = blend_color(base_bg_color, DANGER, 0.62 * flash_intensity);
+    let bg_color = blend_color(base_bg_color, palette().danger, 0.62 * flash_intensity);
 
     if bg_color != Color32::TRANSPARENT {
         ui.painter().rect_filled(rect, 4.0, bg_color);
     }
 
     if flash_intensity > 0.0 {
-        let stroke_color = blend_color(SHELL_BG, DANGER, 0.92 * flash_intensity);
+        let stroke_color =
+            blend_color(palette().shell_bg, palette().danger, 0.92 * flash_intensity);
         ui.painter().rect_stroke(
             rect,
             4.0,
@@ -3351,7 +6948,8 @@ fn draw_process_row(
             rect.min + egui::vec2(2.0, 8.0),
             egui::vec2(2.0, rect.height() - 16.0),
         );
-        ui.painter().rect_filled(accent_rect, 1.0, TEXT_MAIN);
+        ui.painter()
+            .rect_filled(accent_rect, 1.0, palette().text_main);
     }
 
     let inner_rect = rect.shrink2(egui::vec2(14.0, 0.0));
@@ -3362,7 +6960,7 @@ fn draw_process_row(
                 .layout_no_wrap(
                     metric_text.to_string(),
                     FontId::proportional(11.0),
-                    TEXT_MUTED,
+                    palette().text_muted,
                 )
                 .size()
                 .x
@@ -3370,12 +6968,30 @@ fn draw_process_row(
     } else {
         0.0
     };
-    let dot_center = egui::pos2(inner_rect.min.x + 10.0, rect.center().y);
+    let dot_center = egui::pos2(inner_rect.min.x + 10.0, line_y);
     ui.painter()
         .circle_filled(dot_center, 4.0, status_color(status, ui.ctx()));
-    let text_pos = egui::pos2(dot_center.x + 14.0, rect.center().y);
+    if let Some([r, g, b]) = process_color(process) {
+        ui.painter().circle_stroke(
+            dot_center,
+            6.5,
+            Stroke::new(1.5, Color32::from_rgb(r, g, b)),
+        );
+    }
+    let mut text_x = dot_center.x + 14.0;
+    if let Some(health) = health {
+        let health_center = egui::pos2(dot_center.x + 10.0, line_y);
+        ui.painter()
+            .circle_filled(health_center, 3.0, health_color(health));
+        text_x += 10.0;
+    }
+    let text_pos = egui::pos2(text_x, line_y);
     let font_id = FontId::proportional(13.5);
-    let text_color = if selected { TEXT_MAIN } else { TEXT_MUTED };
+    let text_color = if selected {
+        palette().text_main
+    } else {
+        palette().text_muted
+    };
     let name_clip_right = (inner_rect.max.x - metric_width).max(text_pos.x + 24.0);
     let name_painter = ui.painter().with_clip_rect(egui::Rect::from_min_max(
         egui::pos2(text_pos.x, rect.min.y),
@@ -3397,7 +7013,11 @@ fn draw_process_row(
                 .size()
                 .x
         });
-        let marker_color = if selected { TEXT_SOFT } else { STOPPED };
+        let marker_color = if selected {
+            palette().text_soft
+        } else {
+            palette().stopped
+        };
         name_painter.text(
             egui::pos2(text_pos.x + name_width, text_pos.y),
             Align2::LEFT_CENTER,
@@ -3407,10 +7027,32 @@ fn draw_process_row(
         );
     }
 
+    if let Some(preview) = preview.filter(|line| !line.is_empty()) {
+        let preview_color = if selected {
+            palette().text_soft
+        } else {
+            palette().stopped
+        };
+        let mut job = egui::text::LayoutJob::simple_singleline(
+            preview.to_string(),
+            FontId::proportional(11.0),
+            preview_color,
+        );
+        job.wrap =
+            egui::text::TextWrapping::truncate_at_width((inner_rect.max.x - text_pos.x).max(24.0));
+        let galley = ui.fonts_mut(|fonts| fonts.layout_job(job));
+        ui.painter()
+            .galley(egui::pos2(text_pos.x, line_y + 9.0), galley, preview_color);
+    }
+
     if let Some(metric_text) = metric_text {
-        let metric_color = if selected { TEXT_SOFT } else { STOPPED };
+        let metric_color = if selected {
+            palette().text_soft
+        } else {
+            palette().stopped
+        };
         ui.painter().text(
-            egui::pos2(inner_rect.max.x, rect.center().y),
+            egui::pos2(inner_rect.max.x, line_y),
             Align2::RIGHT_CENTER,
             metric_text,
             FontId::proportional(11.0),
@@ -3421,6 +7063,75 @@ fn draw_process_row(
     response
 }
 
+fn draw_process_group_header(
+    ui: &mut Ui,
+    name: &str,
+    collapsed: bool,
+    running: usize,
+    total: usize,
+) -> (egui::Response, Option<GroupAction>) {
+    let (rect, response) =
+        ui.allocate_exact_size(egui::vec2(ui.available_width(), 24.0), egui::Sense::click());
+
+    if response.hovered() {
+        ui.painter()
+            .rect_filled(rect, 4.0, palette().process_row_hover_bg);
+    }
+
+    let inner_rect = rect.shrink2(egui::vec2(6.0, 0.0));
+    let arrow = if collapsed { "▶" } else { "▼" };
+    ui.painter().text(
+        egui::pos2(inner_rect.min.x, rect.center().y),
+        Align2::LEFT_CENTER,
+        format!("{arrow}  {}", name.to_uppercase()),
+        FontId::proportional(10.5),
+        palette().text_muted,
+    );
+
+    // Buttons are added after the header row, so they take the click instead of the toggle.
+    let mut action = None;
+    ui.scope_builder(UiBuilder::new().max_rect(inner_rect), |ui| {
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            ui.spacing_mut().item_spacing.x = 2.0;
+            for (label, hint, group_action, color) in [
+                (
+                    "⟳",
+                    "Restart group",
+                    GroupAction::Restart,
+                    palette().toolbar_yellow,
+                ),
+                ("■", "Stop group", GroupAction::Stop, palette().toolbar_gray),
+                (
+                    "▶",
+                    "Start group",
+                    GroupAction::Start,
+                    palette().toolbar_green,
+                ),
+            ] {
+                if chrome_text_button(ui, label, color, Vec2::new(20.0, 20.0), 10.0, false)
+                    .on_hover_text(hint)
+                    .clicked()
+                {
+                    action = Some(group_action);
+                }
+            }
+            ui.add_space(4.0);
+            ui.label(
+                RichText::new(format!("{running}/{total}"))
+                    .color(palette().stopped)
+                    .size(10.5),
+            );
+        });
+    });
+
+    let response = response.on_hover_text(if collapsed {
+        "Expand group"
+    } else {
+        "Collapse group"
+    });
+    (response, action)
+}
+
 fn compact_resource_usage_text(usage: Option<ProcessResourceUsage>) -> Option<String> {
     let usage = usage?;
     if usage.cpu_percent.is_none() && usage.memory_bytes.is_none() {
@@ -3450,6 +7161,64 @@ fn resource_usage_text(
     ))
 }
 
+fn runtime_info_text(info: &ProcessRuntimeInfo) -> Option<String> {
+    let pid = info.pid.map(|pid| format!("PID {}", pid));
+    let exit = match (info.started_at, info.last_exit_code) {
+        (None, Some(code)) => Some(format!("exited {}", code)),
+        _ => None,
+    };
+    let restarts = match info.restart_count {
+        0 => None,
+        1 => Some("1 restart".to_string()),
+        count => Some(format!("{} restarts", count)),
+    };
+    let uptime = info
+        .uptime()
+        .map(|uptime| format!("up {}", format_uptime(uptime)));
+
+    let parts: Vec<String> = [pid, exit, uptime, restarts]
+        .into_iter()
+        .flatten()
+        .collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" · "))
+    }
+}
+
+/// Color of the "last output" age: muted, then amber and red once it passes the configured
+/// thresholds. A threshold of 0 never trips.
+fn silent_output_color(silence: Duration, warning_secs: u64, alert_secs: u64) -> Color32 {
+    let seconds = silence.as_secs();
+    if alert_secs > 0 && seconds >= alert_secs {
+        palette().danger
+    } else if warning_secs > 0 && seconds >= warning_secs {
+        palette().warning
+    } else {
+        palette().text_muted
+    }
+}
+
+fn format_uptime(uptime: Duration) -> String {
+    let seconds = uptime.as_secs();
+    let (days, hours, minutes, seconds) = (
+        seconds / 86_400,
+        seconds / 3_600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+    );
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
 fn format_cpu_percent(value: Option<f32>) -> String {
     match value {
         Some(value) if value < 9.95 => format!("{:.1}%", value.max(0.0)),
@@ -3473,7 +7242,10 @@ fn format_memory_bytes(value: Option<u64>) -> String {
 }
 
 fn field_label(text: &str) -> RichText {
-    RichText::new(text).color(TEXT_SOFT).size(12.0).strong()
+    RichText::new(text)
+        .color(palette().text_soft)
+        .size(12.0)
+        .strong()
 }
 
 fn process_markers(process: &ProcessConfig) -> Option<String> {
@@ -3481,7 +7253,7 @@ fn process_markers(process: &ProcessConfig) -> Option<String> {
     if process.auto_start {
         markers.push("A");
     }
-    if process.auto_restart {
+    if process.restart_policy.is_enabled() {
         markers.push("M");
     }
 
@@ -3505,9 +7277,11 @@ fn draw_log_line(
     style: LogLineStyle,
     selected: bool,
     text_selectable: bool,
+    wrap: bool,
+    highlight: Option<(&[std::ops::Range<usize>], Color32)>,
 ) -> egui::Response {
     let fill = if selected {
-        Color32::from_rgb(42, 58, 82)
+        palette().log_selected_bg
     } else {
         Color32::TRANSPARENT
     };
@@ -3518,13 +7292,19 @@ fn draw_log_line(
         .inner_margin(egui::Margin::symmetric(6, 2))
         .show(ui, |ui| {
             ui.set_width(ui.available_width());
-            let mut label = egui::Label::new(
+            let text: egui::WidgetText = if line.contains('\u{1b}') || highlight.is_some() {
+                ansi_layout_job(line, style.color, highlight).into()
+            } else {
                 RichText::new(line)
                     .color(style.color)
                     .monospace()
-                    .size(12.5),
-            )
-            .selectable(text_selectable);
+                    .size(12.5)
+                    .into()
+            };
+            let mut label = egui::Label::new(text).selectable(text_selectable);
+            if !wrap {
+                label = label.extend();
+            }
 
             if !text_selectable {
                 label = label.sense(egui::Sense::click());
@@ -3532,8 +7312,9 @@ fn draw_log_line(
 
             ui.add(label)
             .on_hover_text(format!(
-                "{}\n{}",
+                "{}, received {}\n{}",
                 style.hover,
+                style.received.format("%H:%M:%S%.3f"),
                 if text_selectable {
                     "Drag to select text; click outside this row to return to row selection."
                 } else {
@@ -3542,13 +7323,170 @@ fn draw_log_line(
             ))
         });
 
-    if text_selectable {
-        shown.response.union(shown.inner)
+    if text_selectable {
+        shown.response.union(shown.inner)
+    } else {
+        shown
+            .response
+            .interact(egui::Sense::click())
+            .union(shown.inner)
+    }
+}
+
+/// Whether a log scroll area is following new output. Scrolling up pauses auto-scroll; a
+/// "Jump to bottom" button over the area makes that visible and reversible.
+fn follow_log_scroll<R>(ui: &mut Ui, output: &mut egui::scroll_area::ScrollAreaOutput<R>) -> bool {
+    let max_offset = (output.content_size.y - output.inner_rect.height()).max(0.0);
+    let distance_from_bottom = (max_offset - output.state.offset.y).max(0.0);
+    if distance_from_bottom <= LOG_STICK_THRESHOLD_PX {
+        return true;
+    }
+
+    let button_area = output.inner_rect.shrink(12.0);
+    let jump = ui
+        .scope_builder(
+            UiBuilder::new()
+                .max_rect(button_area)
+                .layout(Layout::bottom_up(Align::Max)),
+            |ui| {
+                chrome_text_button(
+                    ui,
+                    "⇩ Jump to bottom",
+                    palette().toolbar_text,
+                    Vec2::new(0.0, 26.0),
+                    11.5,
+                    true,
+                )
+                .on_hover_text("Auto-scroll is paused while you read older lines")
+                .clicked()
+            },
+        )
+        .inner;
+    if jump {
+        output.state.offset.y = max_offset;
+        output.state.store(ui.ctx(), output.id);
+        ui.ctx().request_repaint();
+    }
+    jump
+}
+
+/// A line of the "All" view: a `[name]` prefix in the process's color, then the line as the
+/// process view would draw it.
+fn draw_combined_log_line(
+    ui: &mut Ui,
+    line: &CombinedLogEntry,
+    name_color: Color32,
+    wrap: bool,
+) -> egui::Response {
+    let style = log_line_style(&line.entry);
+    let body = ansi_layout_job(&line.entry.text, style.color, None);
+    let mut job = egui::text::LayoutJob::default();
+    job.append(
+        &format!("[{}] ", line.process_name),
+        0.0,
+        egui::TextFormat {
+            font_id: FontId::monospace(12.5),
+            color: name_color,
+            ..Default::default()
+        },
+    );
+    for section in &body.sections {
+        job.append(
+            &body.text[section.byte_range.clone()],
+            0.0,
+            section.format.clone(),
+        );
+    }
+
+    egui::Frame::default()
+        .corner_radius(4.0)
+        .inner_margin(egui::Margin::symmetric(6, 2))
+        .show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            let mut label = egui::Label::new(job);
+            if !wrap {
+                label = label.extend();
+            }
+            ui.add(label).on_hover_text(format!(
+                "{}: {}, received {}",
+                line.process_name,
+                style.hover,
+                style.received.format("%H:%M:%S%.3f")
+            ))
+        })
+        .inner
+}
+
+/// Lay out a log line with its ANSI colors. `highlight` paints a background behind byte
+/// ranges of the escape-free text, such as search matches.
+fn ansi_layout_job(
+    line: &str,
+    default_color: Color32,
+    highlight: Option<(&[std::ops::Range<usize>], Color32)>,
+) -> egui::text::LayoutJob {
+    let (ranges, highlight_color) = highlight.unwrap_or((&[], Color32::TRANSPARENT));
+    let mut job = egui::text::LayoutJob::default();
+    let mut offset = 0;
+    for (ansi_style, text) in ansi_to_segments(line) {
+        let color = match ansi_style.fg {
+            Some(index) => ansi_palette_color(index),
+            None if ansi_style.bold => palette().text_main,
+            None => default_color,
+        };
+        let mut start = 0;
+        while start < text.len() {
+            let position = offset + start;
+            let (end, background) = match ranges.iter().find(|range| range.end > position) {
+                Some(range) if range.start <= position => (range.end - offset, highlight_color),
+                Some(range) => (range.start - offset, Color32::TRANSPARENT),
+                None => (text.len(), Color32::TRANSPARENT),
+            };
+            let end = end.min(text.len());
+            job.append(
+                &text[start..end],
+                0.0,
+                egui::TextFormat {
+                    font_id: FontId::monospace(12.5),
+                    color,
+                    background,
+                    ..Default::default()
+                },
+            );
+            start = end;
+        }
+        offset += text.len();
+    }
+    job
+}
+
+fn ansi_palette_color(index: u8) -> Color32 {
+    let color = ansi_dark_palette_color(index);
+    if palette().is_light {
+        // Terminal palettes assume a dark background; darken them to stay readable.
+        blend_color(color, Color32::BLACK, 0.4)
     } else {
-        shown
-            .response
-            .interact(egui::Sense::click())
-            .union(shown.inner)
+        color
+    }
+}
+
+fn ansi_dark_palette_color(index: u8) -> Color32 {
+    match index {
+        0 => Color32::from_rgb(96, 104, 116),
+        1 => Color32::from_rgb(224, 108, 117),
+        2 => Color32::from_rgb(152, 195, 121),
+        3 => Color32::from_rgb(229, 192, 123),
+        4 => Color32::from_rgb(97, 175, 239),
+        5 => Color32::from_rgb(198, 120, 221),
+        6 => Color32::from_rgb(86, 182, 194),
+        7 => Color32::from_rgb(200, 204, 212),
+        8 => Color32::from_rgb(127, 132, 142),
+        9 => Color32::from_rgb(255, 123, 134),
+        10 => Color32::from_rgb(179, 225, 145),
+        11 => Color32::from_rgb(245, 211, 143),
+        12 => Color32::from_rgb(125, 196, 255),
+        13 => Color32::from_rgb(222, 148, 243),
+        14 => Color32::from_rgb(116, 211, 222),
+        _ => Color32::from_rgb(236, 239, 244),
     }
 }
 
@@ -3569,7 +7507,7 @@ fn draw_sidebar_footer_button(ui: &mut Ui, label: &str) -> egui::Response {
         ui.allocate_exact_size(egui::vec2(ui.available_width(), 32.0), egui::Sense::click());
 
     let bg_color = if response.hovered() {
-        PROCESS_ROW_HOVER_BG
+        palette().process_row_hover_bg
     } else {
         Color32::TRANSPARENT
     };
@@ -3585,30 +7523,52 @@ fn draw_sidebar_footer_button(ui: &mut Ui, label: &str) -> egui::Response {
         Align2::CENTER_CENTER,
         "⚙",
         FontId::proportional(13.0),
-        TEXT_MUTED,
+        palette().text_muted,
     );
     ui.painter().text(
         egui::pos2(icon_pos.x + 14.0, rect.center().y),
         Align2::LEFT_CENTER,
         label,
         FontId::proportional(13.0),
-        TEXT_MUTED,
+        palette().text_muted,
     );
 
     response
 }
 
+fn modal_multiline_edit(ui: &mut Ui, value: &mut String, hint: &str, width: f32) -> egui::Response {
+    ui.scope(|ui| {
+        let visuals = &mut ui.style_mut().visuals;
+        visuals.widgets.inactive.bg_fill = palette().field_bg;
+        visuals.widgets.inactive.bg_stroke = Stroke::new(1.0, palette().field_border);
+        visuals.widgets.hovered.bg_fill = palette().field_bg_hover;
+        visuals.widgets.hovered.bg_stroke = Stroke::new(1.0, palette().field_border_focus);
+        visuals.widgets.active.bg_fill = palette().field_bg_hover;
+        visuals.widgets.active.bg_stroke = Stroke::new(1.0, palette().field_border_focus);
+
+        ui.add(
+            TextEdit::multiline(value)
+                .hint_text(hint)
+                .code_editor()
+                .desired_rows(4)
+                .desired_width(width)
+                .margin(egui::Margin::symmetric(8, 7)),
+        )
+    })
+    .inner
+}
+
 fn modal_text_edit(ui: &mut Ui, value: &mut String, hint: &str, width: f32) -> egui::Response {
     ui.scope(|ui| {
         let visuals = &mut ui.style_mut().visuals;
-        visuals.widgets.inactive.bg_fill = FIELD_BG;
-        visuals.widgets.inactive.bg_stroke = Stroke::new(1.0, FIELD_BORDER);
-        visuals.widgets.hovered.bg_fill = FIELD_BG_HOVER;
-        visuals.widgets.hovered.bg_stroke = Stroke::new(1.0, FIELD_BORDER_FOCUS);
-        visuals.widgets.active.bg_fill = FIELD_BG_HOVER;
-        visuals.widgets.active.bg_stroke = Stroke::new(1.0, FIELD_BORDER_FOCUS);
-        visuals.widgets.open.bg_fill = FIELD_BG_HOVER;
-        visuals.widgets.open.bg_stroke = Stroke::new(1.0, FIELD_BORDER_FOCUS);
+        visuals.widgets.inactive.bg_fill = palette().field_bg;
+        visuals.widgets.inactive.bg_stroke = Stroke::new(1.0, palette().field_border);
+        visuals.widgets.hovered.bg_fill = palette().field_bg_hover;
+        visuals.widgets.hovered.bg_stroke = Stroke::new(1.0, palette().field_border_focus);
+        visuals.widgets.active.bg_fill = palette().field_bg_hover;
+        visuals.widgets.active.bg_stroke = Stroke::new(1.0, palette().field_border_focus);
+        visuals.widgets.open.bg_fill = palette().field_bg_hover;
+        visuals.widgets.open.bg_stroke = Stroke::new(1.0, palette().field_border_focus);
 
         ui.add_sized(
             [width, 34.0],
@@ -3621,12 +7581,34 @@ fn modal_text_edit(ui: &mut Ui, value: &mut String, hint: &str, width: f32) -> e
     .inner
 }
 
+fn inline_text_edit(ui: &mut Ui, value: &mut String, hint: &str, width: f32) -> egui::Response {
+    ui.scope(|ui| {
+        let visuals = &mut ui.style_mut().visuals;
+        visuals.widgets.inactive.bg_fill = palette().field_bg;
+        visuals.widgets.inactive.bg_stroke = Stroke::new(1.0, palette().field_border);
+        visuals.widgets.hovered.bg_fill = palette().field_bg_hover;
+        visuals.widgets.hovered.bg_stroke = Stroke::new(1.0, palette().field_border_focus);
+        visuals.widgets.active.bg_fill = palette().field_bg_hover;
+        visuals.widgets.active.bg_stroke = Stroke::new(1.0, palette().field_border_focus);
+
+        ui.add_sized(
+            [width.min(ui.available_width()), 28.0],
+            TextEdit::singleline(value)
+                .hint_text(hint)
+                .frame(true)
+                .margin(egui::Margin::symmetric(8, 5)),
+        )
+    })
+    .inner
+}
+
 fn modal_disabled_text_edit(ui: &mut Ui, value: &mut String, width: f32) -> egui::Response {
     ui.scope(|ui| {
         let visuals = &mut ui.style_mut().visuals;
-        visuals.widgets.noninteractive.bg_fill = FIELD_BG;
-        visuals.widgets.noninteractive.bg_stroke = Stroke::new(1.0, FIELD_BORDER_DISABLED);
-        visuals.widgets.noninteractive.fg_stroke = Stroke::new(1.0, TEXT_MUTED);
+        visuals.widgets.noninteractive.bg_fill = palette().field_bg;
+        visuals.widgets.noninteractive.bg_stroke =
+            Stroke::new(1.0, palette().field_border_disabled);
+        visuals.widgets.noninteractive.fg_stroke = Stroke::new(1.0, palette().text_muted);
 
         ui.add_enabled_ui(false, |ui| {
             ui.add_sized(
@@ -3634,7 +7616,7 @@ fn modal_disabled_text_edit(ui: &mut Ui, value: &mut String, width: f32) -> egui
                 egui::widgets::TextEdit::singleline(value)
                     .frame(true)
                     .margin(egui::Margin::symmetric(8, 7))
-                    .text_color(TEXT_MUTED),
+                    .text_color(palette().text_muted),
             )
         })
         .inner
@@ -3656,7 +7638,7 @@ fn modal_text_edit_with_button(
         let button_response = chrome_text_button(
             ui,
             button_label,
-            TEXT_MAIN,
+            palette().text_main,
             Vec2::new(BROWSE_BUTTON_WIDTH, MODAL_BUTTON_HEIGHT),
             12.0,
             true,
@@ -3672,14 +7654,83 @@ fn modal_divider(ui: &mut Ui) {
     ui.painter().hline(
         rect.x_range(),
         rect.center().y,
-        Stroke::new(1.0, SHELL_SUBTLE_STROKE),
+        Stroke::new(1.0, palette().shell_subtle_stroke),
     );
 }
 
+fn draw_arg_rows(ui: &mut Ui, args: &mut Vec<String>) {
+    let mut remove_index = None;
+    for (index, arg) in args.iter_mut().enumerate() {
+        ui.push_id(("arg_row", index), |ui| {
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 8.0;
+                modal_text_edit(ui, arg, "argument", MODAL_FORM_WIDTH - 34.0 - 8.0);
+                if chrome_text_button(
+                    ui,
+                    "✕",
+                    palette().toolbar_red,
+                    Vec2::new(34.0, MODAL_BUTTON_HEIGHT),
+                    12.0,
+                    true,
+                )
+                .on_hover_text("Remove argument")
+                .clicked()
+                {
+                    remove_index = Some(index);
+                }
+            });
+        });
+        ui.add_space(4.0);
+    }
+
+    if let Some(index) = remove_index {
+        args.remove(index);
+    }
+
+    if subtle_action_button(ui, "+ Add argument", Some(palette().accent_soft)).clicked() {
+        args.push(String::new());
+    }
+}
+
+fn draw_env_var_rows(ui: &mut Ui, env: &mut Vec<(String, String)>) {
+    let mut remove_index = None;
+    for (index, (key, value)) in env.iter_mut().enumerate() {
+        ui.push_id(("env_var_row", index), |ui| {
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 8.0;
+                modal_text_edit(ui, key, "KEY", 140.0);
+                modal_text_edit(ui, value, "value", MODAL_FORM_WIDTH - 140.0 - 34.0 - 16.0);
+                if chrome_text_button(
+                    ui,
+                    "✕",
+                    palette().toolbar_red,
+                    Vec2::new(34.0, MODAL_BUTTON_HEIGHT),
+                    12.0,
+                    true,
+                )
+                .on_hover_text("Remove variable")
+                .clicked()
+                {
+                    remove_index = Some(index);
+                }
+            });
+        });
+        ui.add_space(4.0);
+    }
+
+    if let Some(index) = remove_index {
+        env.remove(index);
+    }
+
+    if subtle_action_button(ui, "+ Add variable", Some(palette().accent_soft)).clicked() {
+        env.push((String::new(), String::new()));
+    }
+}
+
 fn draw_restart_schedule_summary(ui: &mut Ui, form: &mut ProcessDraft) {
     egui::Frame::default()
         .fill(Color32::TRANSPARENT)
-        .stroke(Stroke::new(1.0, FIELD_BORDER))
+        .stroke(Stroke::new(1.0, palette().field_border))
         .corner_radius(8.0)
         .inner_margin(egui::Margin::symmetric(12, 10))
         .show(ui, |ui| {
@@ -3694,11 +7745,13 @@ fn draw_restart_schedule_summary(ui: &mut Ui, form: &mut ProcessDraft) {
             ui.horizontal(|ui| {
                 ui.label(
                     RichText::new(restart_schedule_summary(&form.restart_schedule))
-                        .color(TEXT_MUTED)
+                        .color(palette().text_muted)
                         .size(11.5),
                 );
                 ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                    if subtle_action_button(ui, "Edit hours...", Some(ACCENT_SOFT)).clicked() {
+                    if subtle_action_button(ui, "Edit hours...", Some(palette().accent_soft))
+                        .clicked()
+                    {
                         form.restart_schedule_editor_open = true;
                     }
                 });
@@ -3709,7 +7762,7 @@ fn draw_restart_schedule_summary(ui: &mut Ui, form: &mut ProcessDraft) {
 fn draw_scheduled_run_summary(ui: &mut Ui, form: &mut ProcessDraft) {
     egui::Frame::default()
         .fill(Color32::TRANSPARENT)
-        .stroke(Stroke::new(1.0, FIELD_BORDER))
+        .stroke(Stroke::new(1.0, palette().field_border))
         .corner_radius(8.0)
         .inner_margin(egui::Margin::symmetric(12, 10))
         .show(ui, |ui| {
@@ -3717,11 +7770,13 @@ fn draw_scheduled_run_summary(ui: &mut Ui, form: &mut ProcessDraft) {
             ui.horizontal(|ui| {
                 ui.label(
                     RichText::new(scheduled_run_summary(form))
-                        .color(TEXT_MUTED)
+                        .color(palette().text_muted)
                         .size(11.5),
                 );
                 ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                    if subtle_action_button(ui, "Edit schedule...", Some(ACCENT_SOFT)).clicked() {
+                    if subtle_action_button(ui, "Edit schedule...", Some(palette().accent_soft))
+                        .clicked()
+                    {
                         form.scheduled_run_editor_open = true;
                     }
                 });
@@ -3732,7 +7787,7 @@ fn draw_scheduled_run_summary(ui: &mut Ui, form: &mut ProcessDraft) {
 fn draw_stack_control_group(ui: &mut Ui, form: &mut ProcessDraft) {
     egui::Frame::default()
         .fill(Color32::TRANSPARENT)
-        .stroke(Stroke::new(1.0, FIELD_BORDER))
+        .stroke(Stroke::new(1.0, palette().field_border))
         .corner_radius(8.0)
         .inner_margin(egui::Margin::symmetric(12, 10))
         .show(ui, |ui| {
@@ -3747,6 +7802,91 @@ fn draw_stack_control_group(ui: &mut Ui, form: &mut ProcessDraft) {
         });
 }
 
+fn draw_health_check_fields(ui: &mut Ui, form: &mut ProcessDraft) {
+    ui.label(field_label("Health URL"));
+    modal_text_edit(
+        ui,
+        &mut form.health_check.url,
+        "http://localhost:3000/health",
+        MODAL_FORM_WIDTH,
+    );
+    ui.add_space(14.0);
+    ui.label(field_label("Expected Status"));
+    modal_text_edit(
+        ui,
+        &mut form.health_check_expected_status,
+        "200",
+        MODAL_FORM_WIDTH,
+    );
+    ui.add_space(14.0);
+    ui.label(field_label("Check Interval (seconds)"));
+    modal_text_edit(
+        ui,
+        &mut form.health_check_interval_secs,
+        "10",
+        MODAL_FORM_WIDTH,
+    );
+    ui.add_space(14.0);
+    ui.label(field_label("Check Timeout (seconds)"));
+    modal_text_edit(
+        ui,
+        &mut form.health_check_timeout_secs,
+        "2",
+        MODAL_FORM_WIDTH,
+    );
+    ui.add_space(14.0);
+    ui.label(field_label("Restart After Failed Checks"));
+    modal_text_edit(
+        ui,
+        &mut form.health_check_restart_after_failures,
+        "0",
+        MODAL_FORM_WIDTH,
+    );
+    ui.add_space(6.0);
+    ui.label(
+        RichText::new(
+            "Only plain http:// URLs are supported. Checks run only while the entry is Running. \
+             A non-zero failure count kills the process after that many failed checks in a row \
+             so its restart policy brings it back; 0 turns this off.",
+        )
+        .color(palette().text_muted)
+        .size(11.5),
+    );
+}
+
+fn draw_dependency_group(ui: &mut Ui, form: &mut ProcessDraft, candidates: &[(String, String)]) {
+    egui::Frame::default()
+        .fill(Color32::TRANSPARENT)
+        .stroke(Stroke::new(1.0, palette().field_border))
+        .corner_radius(8.0)
+        .inner_margin(egui::Margin::symmetric(12, 10))
+        .show(ui, |ui| {
+            ui.set_width(MODAL_FORM_WIDTH - 24.0);
+            ui.label(field_label("Depends on"));
+            ui.add_space(6.0);
+            ui.horizontal_wrapped(|ui| {
+                for (id, name) in candidates {
+                    let mut checked = form.depends_on.contains(id);
+                    if ui.checkbox(&mut checked, name).changed() {
+                        if checked {
+                            form.depends_on.push(id.clone());
+                        } else {
+                            form.depends_on.retain(|dependency| dependency != id);
+                        }
+                    }
+                }
+            });
+            ui.add_space(6.0);
+            ui.label(
+                RichText::new(
+                    "Start All and auto-start wait for these entries to be running before starting this one.",
+                )
+                .color(palette().text_muted)
+                .size(11.5),
+            );
+        });
+}
+
 fn draw_process_schedule_editors(ctx: &Context, form: &mut ProcessDraft) {
     if form.restart_schedule_editor_open {
         let mut open = true;
@@ -3757,8 +7897,8 @@ fn draw_process_schedule_editors(ctx: &Context, form: &mut ProcessDraft) {
             .fixed_size([690.0, 430.0])
             .frame(
                 egui::Frame::window(&ctx.style())
-                    .fill(PANEL_BG)
-                    .stroke(Stroke::new(1.0, BORDER)),
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
             )
             .open(&mut open)
             .show(ctx, |ui| {
@@ -3767,7 +7907,7 @@ fn draw_process_schedule_editors(ctx: &Context, form: &mut ProcessDraft) {
                     RichText::new(
                         "Toggle the hours when managed restart should actively start and repeat this process.",
                     )
-                    .color(TEXT_MUTED)
+                    .color(palette().text_muted)
                     .size(12.0),
                 );
                 ui.add_space(12.0);
@@ -3805,7 +7945,7 @@ fn draw_process_schedule_editors(ctx: &Context, form: &mut ProcessDraft) {
                                 for hour in 0..24 {
                                     ui.label(
                                         RichText::new(format!("{hour:02}"))
-                                            .color(TEXT_MUTED)
+                                            .color(palette().text_muted)
                                             .size(10.5),
                                     );
                                 }
@@ -3814,7 +7954,7 @@ fn draw_process_schedule_editors(ctx: &Context, form: &mut ProcessDraft) {
                                 for day in 0..7 {
                                     ui.label(
                                         RichText::new(day_label(day))
-                                            .color(TEXT_SOFT)
+                                            .color(palette().text_soft)
                                             .size(11.0),
                                     );
                                     for hour in 0..24 {
@@ -3827,16 +7967,16 @@ fn draw_process_schedule_editors(ctx: &Context, form: &mut ProcessDraft) {
                                         let response = ui.add(
                                             Button::new(RichText::new(label).size(9.0))
                                                 .fill(if enabled {
-                                                    TAB_SELECTED_BG
+                                                    palette().tab_selected_bg
                                                 } else {
-                                                    FIELD_BG
+                                                    palette().field_bg
                                                 })
                                                 .stroke(Stroke::new(
                                                     1.0,
                                                     if enabled {
-                                                        TAB_SELECTED_STROKE
+                                                        palette().tab_selected_stroke
                                                     } else {
-                                                        FIELD_BORDER
+                                                        palette().field_border
                                                     },
                                                 ))
                                                 .min_size(Vec2::new(22.0, 22.0)),
@@ -3864,20 +8004,26 @@ fn draw_process_schedule_editors(ctx: &Context, form: &mut ProcessDraft) {
             .fixed_size([430.0, 340.0])
             .frame(
                 egui::Frame::window(&ctx.style())
-                    .fill(PANEL_BG)
-                    .stroke(Stroke::new(1.0, BORDER)),
+                    .fill(palette().panel_bg)
+                    .stroke(Stroke::new(1.0, palette().border)),
             )
             .open(&mut open)
             .show(ctx, |ui| {
                 ui.set_width(430.0);
                 ui.label(
                     RichText::new("Choose when this process should receive a start request.")
-                        .color(TEXT_MUTED)
+                        .color(palette().text_muted)
                         .size(12.0),
                 );
                 ui.add_space(14.0);
                 ui.label(field_label("Cadence"));
                 ui.horizontal_wrapped(|ui| {
+                    modal_tab_button(
+                        ui,
+                        &mut form.scheduled_run.mode,
+                        ScheduledRunMode::EveryNMinutes,
+                        "Every N minutes",
+                    );
                     modal_tab_button(
                         ui,
                         &mut form.scheduled_run.mode,
@@ -3906,10 +8052,27 @@ fn draw_process_schedule_editors(ctx: &Context, form: &mut ProcessDraft) {
 
                 ui.add_space(14.0);
                 match form.scheduled_run.mode {
+                    ScheduledRunMode::EveryNMinutes => {
+                        ui.label(field_label("Every N Minutes"));
+                        modal_text_edit(
+                            ui,
+                            &mut form.scheduled_run_interval_minutes,
+                            "15",
+                            MODAL_FORM_WIDTH,
+                        );
+                        ui.add_space(6.0);
+                        ui.label(
+                            RichText::new(
+                                "Counted from midnight, so 15 runs at :00, :15, :30 and :45.",
+                            )
+                            .color(palette().text_muted)
+                            .size(11.5),
+                        );
+                    }
                     ScheduledRunMode::Hourly => {
                         ui.label(
                             RichText::new("Runs at the top of every hour.")
-                                .color(TEXT_MUTED)
+                                .color(palette().text_muted)
                                 .size(11.5),
                         );
                     }
@@ -3952,37 +8115,45 @@ where
     let response = ui
         .scope(|ui| {
             let visuals = &mut ui.style_mut().visuals;
-            visuals.widgets.inactive.bg_fill = if selected { TAB_SELECTED_BG } else { SHELL_BG };
+            visuals.widgets.inactive.bg_fill = if selected {
+                palette().tab_selected_bg
+            } else {
+                palette().shell_bg
+            };
             visuals.widgets.inactive.bg_stroke = Stroke::new(
                 1.0,
                 if selected {
-                    TAB_SELECTED_STROKE
+                    palette().tab_selected_stroke
                 } else {
-                    SHELL_SUBTLE_STROKE
+                    palette().shell_subtle_stroke
                 },
             );
             visuals.widgets.hovered.bg_fill = if selected {
-                TAB_SELECTED_HOVER_BG
+                palette().tab_selected_hover_bg
             } else {
-                SHELL_HOVER_BG
+                palette().shell_hover_bg
             };
             visuals.widgets.hovered.bg_stroke = Stroke::new(
                 1.0,
                 if selected {
-                    TAB_SELECTED_STROKE
+                    palette().tab_selected_stroke
                 } else {
-                    SHELL_STROKE
+                    palette().shell_stroke
                 },
             );
-            visuals.widgets.active.bg_fill = TAB_SELECTED_ACTIVE_BG;
-            visuals.widgets.active.bg_stroke = Stroke::new(1.0, TAB_SELECTED_STROKE);
-            visuals.widgets.open.bg_fill = TAB_SELECTED_ACTIVE_BG;
-            visuals.widgets.open.bg_stroke = Stroke::new(1.0, TAB_SELECTED_STROKE);
+            visuals.widgets.active.bg_fill = palette().tab_selected_active_bg;
+            visuals.widgets.active.bg_stroke = Stroke::new(1.0, palette().tab_selected_stroke);
+            visuals.widgets.open.bg_fill = palette().tab_selected_active_bg;
+            visuals.widgets.open.bg_stroke = Stroke::new(1.0, palette().tab_selected_stroke);
 
             ui.add(
                 Button::new(
                     RichText::new(label)
-                        .color(if selected { TEXT_MAIN } else { TEXT_MUTED })
+                        .color(if selected {
+                            palette().text_main
+                        } else {
+                            palette().text_muted
+                        })
                         .size(12.0),
                 )
                 .frame(true)
@@ -4012,16 +8183,18 @@ fn modal_checkbox_row(
                 Layout::top_down(Align::Min),
                 |ui| {
                     let visuals = &mut ui.style_mut().visuals;
-                    visuals.widgets.inactive.bg_fill = FIELD_BG;
-                    visuals.widgets.inactive.bg_stroke = Stroke::new(1.0, FIELD_BORDER);
-                    visuals.widgets.hovered.bg_fill = FIELD_BG_HOVER;
-                    visuals.widgets.hovered.bg_stroke = Stroke::new(1.0, FIELD_BORDER_FOCUS);
-                    visuals.widgets.active.bg_fill = FIELD_BG_HOVER;
-                    visuals.widgets.active.bg_stroke = Stroke::new(1.0, FIELD_BORDER_FOCUS);
+                    visuals.widgets.inactive.bg_fill = palette().field_bg;
+                    visuals.widgets.inactive.bg_stroke = Stroke::new(1.0, palette().field_border);
+                    visuals.widgets.hovered.bg_fill = palette().field_bg_hover;
+                    visuals.widgets.hovered.bg_stroke =
+                        Stroke::new(1.0, palette().field_border_focus);
+                    visuals.widgets.active.bg_fill = palette().field_bg_hover;
+                    visuals.widgets.active.bg_stroke =
+                        Stroke::new(1.0, palette().field_border_focus);
 
                     egui::Frame::default()
                         .fill(Color32::TRANSPARENT)
-                        .stroke(Stroke::new(1.0, FIELD_BORDER))
+                        .stroke(Stroke::new(1.0, palette().field_border))
                         .corner_radius(8.0)
                         .inner_margin(egui::Margin::symmetric(12, 10))
                         .show(ui, |ui| {
@@ -4029,7 +8202,11 @@ fn modal_checkbox_row(
                             let response = ui.checkbox(checked, label);
                             if let Some(description) = description {
                                 ui.add_space(4.0);
-                                ui.label(RichText::new(description).color(TEXT_MUTED).size(11.5));
+                                ui.label(
+                                    RichText::new(description)
+                                        .color(palette().text_muted)
+                                        .size(11.5),
+                                );
                             }
                             response
                         })
@@ -4051,7 +8228,7 @@ fn modal_footer(ui: &mut Ui, add_actions: impl FnOnce(&mut Ui)) {
     ui.painter().hline(
         footer_rect.x_range(),
         footer_rect.top(),
-        Stroke::new(1.0, SHELL_SUBTLE_STROKE),
+        Stroke::new(1.0, palette().shell_subtle_stroke),
     );
 
     let actions_rect = footer_rect.shrink2(egui::vec2(12.0, 14.0));
@@ -4062,10 +8239,26 @@ fn modal_footer(ui: &mut Ui, add_actions: impl FnOnce(&mut Ui)) {
 
 fn status_color(status: &ProcessStatus, ctx: &Context) -> Color32 {
     match status {
-        ProcessStatus::Running => RUNNING,
-        ProcessStatus::Stopped => STOPPED,
-        ProcessStatus::Starting | ProcessStatus::Stopping => pulse_color(ctx, WARNING),
-        ProcessStatus::Error(_) => DANGER,
+        ProcessStatus::Running => palette().running,
+        ProcessStatus::Stopped => palette().stopped,
+        ProcessStatus::Starting | ProcessStatus::Stopping => pulse_color(ctx, palette().warning),
+        ProcessStatus::Error(_) => palette().danger,
+    }
+}
+
+fn health_color(health: HealthStatus) -> Color32 {
+    match health {
+        HealthStatus::Healthy => palette().running,
+        HealthStatus::Unhealthy => palette().danger,
+        HealthStatus::Unknown => palette().stopped,
+    }
+}
+
+fn health_label(health: HealthStatus) -> &'static str {
+    match health {
+        HealthStatus::Healthy => "Healthy",
+        HealthStatus::Unhealthy => "Unhealthy",
+        HealthStatus::Unknown => "Health unknown",
     }
 }
 
@@ -4082,49 +8275,111 @@ fn pulse_color(ctx: &Context, base: Color32) -> Color32 {
 struct LogLineStyle {
     color: Color32,
     hover: &'static str,
+    received: chrono::DateTime<chrono::Local>,
 }
 
-fn classify_log_line(line: &str) -> LogLineStyle {
-    let trimmed = line.trim();
-    let content = trimmed
-        .strip_prefix("[stderr] ")
-        .or_else(|| trimmed.strip_prefix("[stderr]"))
-        .unwrap_or(trimmed);
-    let lower = content.to_ascii_lowercase();
-    let is_system = trimmed.starts_with('[') && trimmed.ends_with(']');
-
-    if is_system {
+fn log_line_style(entry: &LogEntry) -> LogLineStyle {
+    let received = chrono::DateTime::<chrono::Local>::from(entry.at);
+    if let Some([r, g, b]) = entry.color {
         return LogLineStyle {
-            color: Color32::from_rgb(126, 147, 172),
-            hover: "System event",
+            color: Color32::from_rgb(r, g, b),
+            hover: "Matched a line color rule",
+            received,
         };
     }
-
-    if contains_error_indicator(content) {
-        return LogLineStyle {
-            color: DANGER,
-            hover: "Likely error output",
-        };
+    let (color, hover) = match entry.kind {
+        LogLineKind::System => (palette().system_log, "System event"),
+        LogLineKind::Error => (palette().danger, "Likely error output"),
+        LogLineKind::Warning => (palette().warning, "Warning output"),
+        LogLineKind::Stderr => (palette().text_soft, "stderr output"),
+        LogLineKind::Stdout => (palette().text_soft, "stdout output"),
+    };
+    LogLineStyle {
+        color,
+        hover,
+        received,
     }
+}
 
-    if lower.contains("warn") {
-        return LogLineStyle {
-            color: WARNING,
-            hover: "Warning output",
-        };
+/// Case-insensitive search over log text. Plain queries match literally.
+fn log_search_regex(query: &str, is_regex: bool) -> Option<Result<Regex, String>> {
+    if query.is_empty() {
+        return None;
     }
+    let pattern = if is_regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    Some(
+        RegexBuilder::new(&pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|err| err.to_string()),
+    )
+}
 
-    if trimmed.starts_with("[stderr]") {
-        return LogLineStyle {
-            color: TEXT_SOFT,
-            hover: "stderr output",
-        };
+/// The match after (or before) `current`, wrapping around the ends.
+fn step_log_search(matches: &[usize], current: Option<usize>, forward: bool) -> Option<usize> {
+    if forward {
+        matches
+            .iter()
+            .copied()
+            .find(|&index| current.is_none_or(|current| index > current))
+            .or_else(|| matches.first().copied())
+    } else {
+        matches
+            .iter()
+            .rev()
+            .copied()
+            .find(|&index| current.is_none_or(|current| index < current))
+            .or_else(|| matches.last().copied())
     }
+}
 
-    LogLineStyle {
-        color: TEXT_SOFT,
-        hover: "stdout output",
+/// The command line an entry runs, for copying: `command` plus quoted `args` for processes,
+/// the container or service name for Docker and Compose entries.
+fn process_command_line(process: &ProcessConfig) -> String {
+    if process.process_type != ProcessType::Process || process.args.is_empty() {
+        return process.command.clone();
+    }
+    std::iter::once(&process.command)
+        .chain(&process.args)
+        .map(|part| {
+            if part.is_empty() || part.chars().any(char::is_whitespace) {
+                format!("\"{}\"", part)
+            } else {
+                part.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Open `path` in Explorer, Finder, or the desktop's default file manager.
+fn open_in_file_manager(path: &str) -> Result<(), String> {
+    #[cfg(windows)]
+    let mut cmd = std::process::Command::new("explorer");
+    #[cfg(target_os = "macos")]
+    let mut cmd = std::process::Command::new("open");
+    #[cfg(not(any(windows, target_os = "macos")))]
+    let mut cmd = std::process::Command::new("xdg-open");
+    cmd.arg(path);
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000);
     }
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|err| format!("Failed to open the file manager: {}", err))?;
+    // Reap the launcher so it doesn't linger as a zombie.
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
 }
 
 fn copy_text_to_clipboard(text: &str) -> Result<(), String> {
@@ -4136,16 +8391,107 @@ fn copy_text_to_clipboard(text: &str) -> Result<(), String> {
 }
 
 fn wait_for_process_stop(manager: &ProcessManager, id: &str) {
-    let start = Instant::now();
-    while start.elapsed() < Duration::from_secs(5) {
-        std::thread::sleep(Duration::from_millis(100));
-        match manager.get_status(id) {
-            Some(ProcessStatus::Stopped | ProcessStatus::Error(_)) | None => break,
-            _ => {}
+    let ids = [id.to_string()];
+    let _ = manager.wait_for_processes_to_stop(&ids, manager.stop_wait_timeout(&ids));
+}
+
+fn build_args(rows: &[String]) -> Vec<String> {
+    rows.iter()
+        .filter(|arg| !arg.trim().is_empty())
+        .cloned()
+        .collect()
+}
+
+fn pattern_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Parse `#RRGGBB pattern` lines into color rules. Colors and patterns are validated when
+/// the rules are compiled.
+fn color_rule_lines(text: &str) -> Result<Vec<LogColorRule>, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.split_once(char::is_whitespace) {
+            Some((color, pattern)) if !pattern.trim().is_empty() => Ok(LogColorRule {
+                color: color.to_string(),
+                pattern: pattern.trim().to_string(),
+            }),
+            _ => Err(format!(
+                "Line color rule '{}' needs a color and a pattern, like '#22c55e GET /api'.",
+                line
+            )),
+        })
+        .collect()
+}
+
+fn duplicate_name_message(name: &str) -> String {
+    format!("A process named \"{}\" already exists.", name.trim())
+}
+
+fn build_color(use_color: bool, [r, g, b]: [u8; 3]) -> Option<String> {
+    use_color.then(|| format!("#{:02x}{:02x}{:02x}", r, g, b))
+}
+
+/// The entry's color label, if it has a valid one.
+fn process_color(process: &ProcessConfig) -> Option<[u8; 3]> {
+    process.color.as_deref().and_then(parse_hex_color)
+}
+
+fn build_group(value: &str) -> Option<String> {
+    let group = value.trim();
+    (!group.is_empty()).then(|| group.to_string())
+}
+
+/// Sidebar sections as (heading, config indices), General first and the rest by name.
+fn process_sections(processes: &[ProcessConfig]) -> Vec<(String, Vec<usize>)> {
+    let mut sections: Vec<(String, Vec<usize>)> = Vec::new();
+    for (index, process) in processes.iter().enumerate() {
+        let group = process.group_name();
+        match sections.iter_mut().find(|(name, _)| name == group) {
+            Some((_, indices)) => indices.push(index),
+            None => sections.push((group.to_string(), vec![index])),
+        }
+    }
+    sections.sort_by_key(|(name, _)| (name != DEFAULT_GROUP_NAME, name.to_lowercase()));
+    sections
+}
+
+/// Reorder one section's rows for display. Ties keep config order.
+fn sort_process_indices(
+    indices: &mut [usize],
+    processes: &[ProcessConfig],
+    sort: ProcessSort,
+    snapshot: &UiRuntimeSnapshot,
+) {
+    match sort {
+        ProcessSort::ConfigOrder => {}
+        ProcessSort::RunningFirst => {
+            indices.sort_by_key(|&index| match snapshot.statuses.get(&processes[index].id) {
+                Some(ProcessStatus::Running) => 0,
+                Some(ProcessStatus::Starting) => 1,
+                Some(ProcessStatus::Stopping) => 2,
+                Some(ProcessStatus::Error(_)) => 3,
+                Some(ProcessStatus::Stopped) | None => 4,
+            })
         }
+        ProcessSort::Name => indices.sort_by_key(|&index| processes[index].name.to_lowercase()),
+        ProcessSort::RecentlyStarted => indices.sort_by_key(|&index| {
+            std::cmp::Reverse(snapshot.started_at.get(&processes[index].id).copied())
+        }),
     }
 }
 
+fn build_env_vars(rows: &[(String, String)]) -> Vec<(String, String)> {
+    let mut env = rows.to_vec();
+    normalize_env_vars(&mut env);
+    env
+}
+
 fn parse_log_rotation_count(value: &str) -> Result<usize, String> {
     match value.trim().parse::<usize>() {
         Ok(count) if count > 0 => Ok(count),
@@ -4160,14 +8506,101 @@ fn parse_startup_delay_seconds(value: &str) -> Result<u64, String> {
     }
 }
 
+fn parse_max_restart_attempts(value: &str) -> Result<u32, String> {
+    match value.trim().parse::<u32>() {
+        Ok(attempts) => Ok(attempts),
+        Err(_) => Err("Max restart attempts must be a whole number.".to_string()),
+    }
+}
+
+fn parse_start_retries(value: &str) -> Result<u32, String> {
+    match value.trim().parse::<u32>() {
+        Ok(retries) if retries <= MAX_START_RETRIES => Ok(retries),
+        _ => Err(format!(
+            "Start retries must be a whole number from 0 to {}.",
+            MAX_START_RETRIES
+        )),
+    }
+}
+
+fn parse_expected_port(value: &str) -> Result<Option<u16>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    match value.parse::<u16>() {
+        Ok(port) if port > 0 => Ok(Some(port)),
+        _ => Err("Expected port must be a number between 1 and 65535.".to_string()),
+    }
+}
+
+fn parse_docker_tail(value: &str) -> Result<Option<u32>, String> {
+    let value = value.trim();
+    if value.is_empty() || value.eq_ignore_ascii_case("all") {
+        return Ok(None);
+    }
+    match value.parse::<u32>() {
+        Ok(lines) => Ok(Some(lines)),
+        Err(_) => {
+            Err("Log tail must be a whole number of lines, or blank for all of them.".to_string())
+        }
+    }
+}
+
+fn parse_stop_timeout_secs(value: &str) -> Result<u64, String> {
+    match value.trim().parse::<u64>() {
+        Ok(seconds) => Ok(seconds),
+        Err(_) => Err("Stop timeout must be a whole number of seconds.".to_string()),
+    }
+}
+
+fn parse_hook_timeout_secs(value: &str) -> Result<u64, String> {
+    match value.trim().parse::<u64>() {
+        Ok(seconds) if seconds > 0 => Ok(seconds),
+        _ => Err("Hook timeout must be a whole number of seconds, at least 1.".to_string()),
+    }
+}
+
 fn build_scheduled_run(form: &ProcessDraft) -> Result<ScheduledRun, String> {
     let mut scheduled_run = form.scheduled_run.clone();
     scheduled_run.hour = parse_hour(&form.scheduled_run_hour)?;
     scheduled_run.interval_hours = parse_interval_hours(&form.scheduled_run_interval_hours)?;
+    scheduled_run.interval_minutes = parse_interval_minutes(&form.scheduled_run_interval_minutes)?;
     normalize_weekdays(&mut scheduled_run.weekdays);
     Ok(scheduled_run)
 }
 
+fn build_health_check(form: &ProcessDraft) -> Result<HealthCheck, String> {
+    let mut health_check = form.health_check.clone();
+    health_check.url = health_check.url.trim().to_string();
+    if !health_check.enabled {
+        return Ok(health_check);
+    }
+    if !health_check.url.starts_with("http://") {
+        return Err("Health check URL must start with http://.".to_string());
+    }
+    health_check.expected_status = match form.health_check_expected_status.trim().parse::<u16>() {
+        Ok(status) if (100..=599).contains(&status) => status,
+        _ => {
+            return Err("Expected status must be an HTTP status code from 100 to 599.".to_string())
+        }
+    };
+    health_check.interval_secs = match form.health_check_interval_secs.trim().parse::<u64>() {
+        Ok(seconds) if seconds > 0 => seconds,
+        _ => return Err("Health check interval must be at least 1 second.".to_string()),
+    };
+    health_check.timeout_secs = match form.health_check_timeout_secs.trim().parse::<u64>() {
+        Ok(seconds) if seconds > 0 => seconds,
+        _ => return Err("Health check timeout must be at least 1 second.".to_string()),
+    };
+    health_check.restart_after_failures = form
+        .health_check_restart_after_failures
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| "Restart after failed checks must be a whole number (0 turns it off).")?;
+    Ok(health_check)
+}
+
 fn normalize_restart_schedule(mut schedule: ManagedRestartSchedule) -> ManagedRestartSchedule {
     normalize_weekly_hours(&mut schedule.hours);
     schedule
@@ -4187,6 +8620,15 @@ fn parse_interval_hours(value: &str) -> Result<u8, String> {
     }
 }
 
+fn parse_interval_minutes(value: &str) -> Result<u8, String> {
+    match value.trim().parse::<u8>() {
+        Ok(minutes) if (1..=60).contains(&minutes) => Ok(minutes),
+        _ => Err(
+            "Scheduled run interval must be a whole number of minutes from 1 to 60.".to_string(),
+        ),
+    }
+}
+
 fn normalize_weekly_hours(hours: &mut Vec<bool>) {
     if hours.len() < WEEKLY_HOUR_COUNT {
         hours.resize(WEEKLY_HOUR_COUNT, false);
@@ -4254,6 +8696,12 @@ fn restart_schedule_summary(schedule: &ManagedRestartSchedule) -> String {
 
 fn scheduled_run_summary(form: &ProcessDraft) -> String {
     match form.scheduled_run.mode {
+        ScheduledRunMode::EveryNMinutes => {
+            format!(
+                "Starts every {} minute(s).",
+                form.scheduled_run_interval_minutes.trim()
+            )
+        }
         ScheduledRunMode::Hourly => "Starts at the top of every hour.".to_string(),
         ScheduledRunMode::EveryNHours => {
             format!(
@@ -4307,6 +8755,51 @@ fn parse_process_error_flash_seconds(value: &str) -> Result<u64, String> {
     }
 }
 
+fn parse_max_log_lines(value: &str) -> Result<usize, String> {
+    match value.trim().parse::<usize>() {
+        Ok(lines) if lines > 0 => Ok(lines),
+        _ => Err("Log buffer must be a whole number of lines, at least 1.".to_string()),
+    }
+}
+
+fn parse_silent_output_secs(value: &str) -> Result<u64, String> {
+    match value.trim().parse::<u64>() {
+        Ok(seconds) => Ok(seconds),
+        Err(_) => Err(
+            "Quiet output thresholds must be whole numbers of seconds (0 turns one off)."
+                .to_string(),
+        ),
+    }
+}
+
+fn parse_max_output_lines_per_second(value: &str) -> Result<usize, String> {
+    match value.trim().parse::<usize>() {
+        Ok(lines) => Ok(lines),
+        Err(_) => Err(
+            "Max output rate must be a whole number of lines per second (0 disables it)."
+                .to_string(),
+        ),
+    }
+}
+
+fn parse_start_all_delay_ms(value: &str) -> Result<u64, String> {
+    match value.trim().parse::<u64>() {
+        Ok(delay_ms) => Ok(delay_ms),
+        Err(_) => Err(
+            "Start All stagger must be a whole number of milliseconds (0 disables it).".to_string(),
+        ),
+    }
+}
+
+fn parse_docker_poll_interval_ms(value: &str) -> Result<u64, String> {
+    match value.trim().parse::<u64>() {
+        Ok(interval_ms) if interval_ms >= 250 => Ok(interval_ms),
+        _ => Err(
+            "Docker status poll must be a whole number of milliseconds, at least 250.".to_string(),
+        ),
+    }
+}
+
 fn normalize_log_directory(value: &str) -> String {
     let trimmed = value.trim();
     if trimmed.is_empty() {
@@ -4480,3 +8973,43 @@ fn load_executable_taskbar_icon_handles() -> (
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{divider_color, set_theme, ProcessDraft};
+    use crate::config::{ProcessConfig, ProcessType, Theme};
+    use eframe::egui::Color32;
+
+    #[test]
+    fn divider_color_follows_the_theme() {
+        set_theme(Theme::Light);
+        assert_eq!(divider_color(40), Color32::from_black_alpha(40));
+        set_theme(Theme::Dark);
+        assert_eq!(divider_color(40), Color32::from_white_alpha(40));
+    }
+
+    #[test]
+    fn drafts_build_the_entry_they_were_loaded_from() {
+        let mut config = ProcessConfig::new(
+            "API".to_string(),
+            "cargo run".to_string(),
+            String::new(),
+            ProcessType::Process,
+        );
+        config.expected_port = Some(8080);
+        let mut draft = ProcessDraft::from_process(&config);
+
+        let built = draft.build().unwrap();
+        assert_ne!(built.id, config.id);
+        assert_eq!(
+            ProcessConfig {
+                id: config.id.clone(),
+                ..built
+            },
+            config
+        );
+
+        draft.expected_port = "http".to_string();
+        assert!(draft.build().is_err());
+    }
+}